@@ -1,5 +1,5 @@
 use crate::utils::{BufferWriter, Deserialize, FixedSizeSerialize, Serialize, Stream};
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 use serde::de::{Unexpected, Visitor};
 use std::fmt::Debug;
 
@@ -14,6 +14,8 @@ pub struct Amount {
 
 impl Amount {
     pub const MAX: Amount = Amount::raw(u128::MAX);
+    /// Number of decimal places of one banano in raw
+    pub const DISPLAY_DECIMALS: u32 = 29;
 
     pub const fn raw(value: u128) -> Self {
         Self { raw: value }
@@ -121,6 +123,70 @@ impl Amount {
         }
     }
 
+    /// Formats the amount in banano with up to `decimals` fractional digits.
+    /// The last digit is rounded half up and trailing zeros are removed.
+    /// Unlike `format_balance` there are no thousands separators, so the
+    /// result can be parsed again with `from_display_string`
+    pub fn to_display_string(&self, decimals: u8) -> String {
+        let decimals = (decimals as u32).min(Self::DISPLAY_DECIMALS);
+        let ratio = Amount::nano(1).number();
+        let mut whole = self.raw / ratio;
+        let unit = 10u128.pow(Self::DISPLAY_DECIMALS - decimals);
+        let remainder = self.raw % ratio;
+        let mut fraction = remainder / unit;
+        if (remainder % unit) * 2 >= unit && unit > 1 {
+            fraction += 1;
+            if fraction == 10u128.pow(decimals) {
+                whole += 1;
+                fraction = 0;
+            }
+        }
+
+        let fraction = format!("{:0width$}", fraction, width = decimals as usize);
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            whole.to_string()
+        } else {
+            format!("{}.{}", whole, fraction)
+        }
+    }
+
+    /// Parses an amount in banano like "1.23". Inputs with more than
+    /// `decimals` fractional digits are rejected
+    pub fn from_display_string(s: impl AsRef<str>, decimals: u8) -> Result<Self> {
+        let s = s.as_ref();
+        let (whole, fraction) = match s.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (s, None),
+        };
+
+        if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+            bail!("invalid amount: {}", s);
+        }
+        let fraction = fraction.unwrap_or_default();
+        if (s.contains('.') && fraction.is_empty()) || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            bail!("invalid amount: {}", s);
+        }
+        if fraction.len() > (decimals as usize).min(Self::DISPLAY_DECIMALS as usize) {
+            bail!("too many decimals: {}", s);
+        }
+
+        let ratio = Amount::nano(1).number();
+        let fraction_raw = if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse::<u128>()? * 10u128.pow(Self::DISPLAY_DECIMALS - fraction.len() as u32)
+        };
+        whole
+            .parse::<u128>()
+            .ok()
+            .and_then(|w| w.checked_mul(ratio))
+            .and_then(|w| w.checked_add(fraction_raw))
+            .map(Amount::raw)
+            .ok_or_else(|| anyhow!("amount too large: {}", s))
+    }
+
     pub fn wrapping_add(&self, other: Amount) -> Amount {
         self.raw.wrapping_add(other.raw).into()
     }
@@ -128,6 +194,19 @@ impl Amount {
     pub fn wrapping_sub(&self, other: Amount) -> Amount {
         self.raw.wrapping_sub(other.raw).into()
     }
+
+    pub fn saturating_add(&self, other: Amount) -> Amount {
+        self.raw.saturating_add(other.raw).into()
+    }
+
+    pub fn saturating_sub(&self, other: Amount) -> Amount {
+        self.raw.saturating_sub(other.raw).into()
+    }
+
+    /// Returns None if the result would be negative
+    pub fn checked_sub(&self, other: Amount) -> Option<Amount> {
+        self.raw.checked_sub(other.raw).map(Amount::raw)
+    }
 }
 
 impl From<u128> for Amount {
@@ -263,6 +342,34 @@ impl<'de> Visitor<'de> for AmountVisitor {
 mod tests {
     use super::*;
 
+    #[test]
+    fn saturating_arithmetic() {
+        assert_eq!(
+            Amount::raw(5).saturating_sub(Amount::raw(3)),
+            Amount::raw(2)
+        );
+        assert_eq!(
+            Amount::raw(3).saturating_sub(Amount::raw(5)),
+            Amount::zero()
+        );
+        assert_eq!(Amount::MAX.saturating_add(Amount::raw(1)), Amount::MAX);
+    }
+
+    #[test]
+    fn wrapping_arithmetic() {
+        assert_eq!(Amount::zero().wrapping_sub(Amount::raw(1)), Amount::MAX);
+        assert_eq!(Amount::MAX.wrapping_add(Amount::raw(1)), Amount::zero());
+    }
+
+    #[test]
+    fn checked_sub() {
+        assert_eq!(
+            Amount::raw(5).checked_sub(Amount::raw(5)),
+            Some(Amount::zero())
+        );
+        assert_eq!(Amount::raw(3).checked_sub(Amount::raw(5)), None);
+    }
+
     #[test]
     fn construct_amount_in_nano() {
         assert_eq!(
@@ -375,6 +482,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_string_round_trip() {
+        let amounts = [
+            Amount::zero(),
+            Amount::raw(1),
+            Amount::micronano(10),
+            Amount::nano(1),
+            Amount::nano(123456789) + Amount::millinano(123),
+            Amount::MAX,
+        ];
+        for amount in amounts {
+            let displayed = amount.to_display_string(29);
+            assert_eq!(
+                Amount::from_display_string(&displayed, 29).unwrap(),
+                amount,
+                "{displayed}"
+            );
+        }
+    }
+
+    #[test]
+    fn to_display_string() {
+        assert_eq!(Amount::zero().to_display_string(2), "0");
+        assert_eq!(Amount::nano(1).to_display_string(2), "1");
+        assert_eq!(Amount::millinano(1234).to_display_string(2), "1.23");
+        assert_eq!(Amount::millinano(1235).to_display_string(2), "1.24");
+        assert_eq!(Amount::millinano(1995).to_display_string(2), "2");
+        assert_eq!(Amount::millinano(1500).to_display_string(0), "2");
+        assert_eq!(
+            Amount::raw(1).to_display_string(29),
+            "0.00000000000000000000000000001"
+        );
+        assert_eq!(
+            Amount::raw(1).to_display_string(255),
+            "0.00000000000000000000000000001"
+        );
+        assert_eq!(Amount::MAX.to_display_string(2), "3402823669.21");
+    }
+
+    #[test]
+    fn from_display_string() {
+        assert_eq!(
+            Amount::from_display_string("1.23", 2).unwrap(),
+            Amount::millinano(1230)
+        );
+        assert_eq!(
+            Amount::from_display_string("007", 0).unwrap(),
+            Amount::nano(7)
+        );
+    }
+
+    #[test]
+    fn reject_malformed_display_strings() {
+        for input in [
+            "", ".", "1.", ".5", "-1", "1.2.3", "1,000", "1e5", " 1", "abc",
+        ] {
+            assert!(
+                Amount::from_display_string(input, 29).is_err(),
+                "accepted {input:?}"
+            );
+        }
+        assert_eq!(
+            Amount::from_display_string("1.234", 2)
+                .unwrap_err()
+                .to_string(),
+            "too many decimals: 1.234"
+        );
+        assert_eq!(
+            Amount::from_display_string("3402823670", 2)
+                .unwrap_err()
+                .to_string(),
+            "amount too large: 3402823670"
+        );
+    }
+
     #[test]
     fn serde_serialize() {
         let serialized = serde_json::to_string_pretty(&Amount::MAX).unwrap();
@@ -0,0 +1,74 @@
+use super::{UnsavedAccountChainBuilder, UnsavedBlockLatticeBuilder};
+use crate::{Amount, Block, BlockHashBuilder, PrivateKey};
+
+/// Creates reproducible blocks for tests. Keys and work are derived from the
+/// seed, so two factories with the same seed create identical blocks when the
+/// same calls are made on them.
+pub struct FixtureBlockFactory {
+    seed: u64,
+    keys_created: u64,
+    lattice: UnsavedBlockLatticeBuilder,
+}
+
+impl FixtureBlockFactory {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            keys_created: 0,
+            lattice: UnsavedBlockLatticeBuilder::with_work_seed(seed),
+        }
+    }
+
+    /// The next key of the deterministic key sequence of this seed
+    pub fn key(&mut self) -> PrivateKey {
+        let hash = BlockHashBuilder::new()
+            .update(self.seed.to_be_bytes())
+            .update(self.keys_created.to_be_bytes())
+            .build();
+        self.keys_created += 1;
+        PrivateKey::from_bytes(hash.as_bytes())
+    }
+
+    pub fn genesis(&mut self) -> UnsavedAccountChainBuilder {
+        self.lattice.genesis()
+    }
+
+    pub fn account<'a>(&'a mut self, key: &'a PrivateKey) -> UnsavedAccountChainBuilder<'a> {
+        self.lattice.account(key)
+    }
+
+    /// Sends `amount` from genesis to a new account and opens it.
+    /// Returns the key of the new account together with the send and the open block
+    pub fn fund_new_account(&mut self, amount: impl Into<Amount>) -> (PrivateKey, [Block; 2]) {
+        let key = self.key();
+        let send = self.lattice.genesis().send(&key, amount);
+        let open = self.lattice.account(&key).receive(&send);
+        (key, [send, open])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_creates_identical_blocks() {
+        assert_eq!(create_blocks(42), create_blocks(42));
+    }
+
+    #[test]
+    fn different_seeds_create_different_blocks() {
+        assert_ne!(create_blocks(1), create_blocks(2));
+    }
+
+    fn create_blocks(seed: u64) -> Vec<Vec<u8>> {
+        let mut factory = FixtureBlockFactory::new(seed);
+        let (key1, [send1, open1]) = factory.fund_new_account(1000);
+        let (_, [send2, open2]) = factory.fund_new_account(2000);
+        let change = factory.account(&key1).change(&key1);
+        [send1, open1, send2, open2, change]
+            .iter()
+            .map(|b| b.to_framed_bytes())
+            .collect()
+    }
+}
@@ -1,4 +1,5 @@
 mod change;
+mod fixture_block_factory;
 mod open;
 mod receive;
 mod saved_account_chain;
@@ -7,6 +8,7 @@ mod state;
 mod unsaved_block_lattice_builder;
 
 pub use change::TestLegacyChangeBlockBuilder;
+pub use fixture_block_factory::FixtureBlockFactory;
 pub use open::TestLegacyOpenBlockBuilder;
 pub use receive::TestLegacyReceiveBlockBuilder;
 pub use saved_account_chain::SavedAccountChain;
@@ -1,6 +1,6 @@
 use crate::{
     epoch_v1_link, epoch_v2_link, Account, AccountInfo, Amount, Block, BlockDetails, BlockHash,
-    BlockSideband, Epoch, PrivateKey, PublicKey, SavedBlock, TestBlockBuilder,
+    BlockHashBuilder, BlockSideband, Epoch, PrivateKey, PublicKey, SavedBlock, TestBlockBuilder,
     TestLegacyChangeBlockBuilder, TestLegacyOpenBlockBuilder, TestLegacyReceiveBlockBuilder,
     TestLegacySendBlockBuilder, TestStateBlockBuilder, DEV_GENESIS_KEY,
 };
@@ -97,6 +97,16 @@ impl SavedAccountChain {
         self.blocks.last().unwrap()
     }
 
+    /// A single hash over all block hashes of the chain. Equal digests mean equal chains
+    pub fn chain_digest(&self) -> BlockHash {
+        self.blocks
+            .iter()
+            .fold(BlockHashBuilder::new(), |builder, block| {
+                builder.update(block.hash().as_bytes())
+            })
+            .build()
+    }
+
     pub fn add_legacy_change(&mut self, representative: impl Into<PublicKey>) -> &SavedBlock {
         let block = self
             .new_legacy_change_block()
@@ -381,6 +391,30 @@ mod tests {
         assert_ne!(chain1.account, chain2.account);
     }
 
+    #[test]
+    fn equal_chains_have_same_digest() {
+        let mut chain1 = SavedAccountChain::with_priv_key(PrivateKey::from(42));
+        chain1.add_random_open_block();
+        chain1.add_state();
+        let mut chain2 = SavedAccountChain::with_priv_key(PrivateKey::from(42));
+        chain2.add_random_open_block();
+        chain2.add_state();
+
+        assert_eq!(chain1.chain_digest(), chain2.chain_digest());
+    }
+
+    #[test]
+    fn divergent_chains_have_different_digests() {
+        let mut chain1 = SavedAccountChain::with_priv_key(PrivateKey::from(42));
+        chain1.add_random_open_block();
+        chain1.add_state();
+        let mut chain2 = SavedAccountChain::with_priv_key(PrivateKey::from(42));
+        chain2.add_random_open_block();
+        chain2.add_legacy_send();
+
+        assert_ne!(chain1.chain_digest(), chain2.chain_digest());
+    }
+
     #[test]
     fn add_legacy_open() {
         let mut genesis = SavedAccountChain::genesis();
@@ -4,7 +4,7 @@ use crate::{
         state_block::EpochBlockArgs,
     },
     dev_epoch1_signer, epoch_v1_link,
-    work::{WorkPool, WorkPoolImpl},
+    work::{WorkPool, WorkPoolImpl, WorkThresholds},
     Account, Amount, Block, BlockHash, ChangeBlockArgs, Epoch, Link, PendingInfo, PendingKey,
     PrivateKey, PublicKey, Root, StateBlockArgs, DEV_GENESIS_BLOCK, DEV_GENESIS_KEY,
 };
@@ -14,6 +14,8 @@ pub struct UnsavedBlockLatticeBuilder {
     accounts: HashMap<Account, Frontier>,
     work_pool: WorkPoolImpl,
     pending_receives: HashMap<PendingKey, PendingInfo>,
+    /// If set, work is searched sequentially from this nonce instead of using the work pool
+    work_seed: Option<u64>,
 }
 
 #[derive(Clone)]
@@ -39,6 +41,15 @@ impl UnsavedBlockLatticeBuilder {
             accounts,
             work_pool,
             pending_receives: Default::default(),
+            work_seed: None,
+        }
+    }
+
+    /// Creates a builder which generates the same work for the same root every time
+    pub fn with_work_seed(seed: u64) -> Self {
+        Self {
+            work_seed: Some(seed),
+            ..Self::new()
         }
     }
 
@@ -65,7 +76,7 @@ impl UnsavedBlockLatticeBuilder {
             representative: PublicKey::zero(),
             balance: Amount::zero(),
             link: epoch_v1_link(),
-            work: self.work_pool.generate_dev2(account.into()).unwrap(),
+            work: self.generate_work(account.into()),
         }
         .into();
 
@@ -81,6 +92,20 @@ impl UnsavedBlockLatticeBuilder {
         receive
     }
 
+    fn generate_work(&self, root: Root) -> u64 {
+        match self.work_seed {
+            Some(seed) => {
+                let thresholds = WorkThresholds::publish_dev();
+                let mut work = seed;
+                while thresholds.difficulty(&root, work) < thresholds.threshold_base() {
+                    work = work.wrapping_add(1);
+                }
+                work
+            }
+            None => self.work_pool.generate_dev2(root).unwrap(),
+        }
+    }
+
     fn pop_pending_receive(
         &mut self,
         receiving_account: impl Into<Account>,
@@ -98,6 +123,7 @@ impl Clone for UnsavedBlockLatticeBuilder {
             accounts: self.accounts.clone(),
             work_pool: WorkPoolImpl::new_dev(),
             pending_receives: self.pending_receives.clone(),
+            work_seed: self.work_seed,
         }
     }
 }
@@ -133,11 +159,7 @@ impl<'a> UnsavedAccountChainBuilder<'a> {
             representative: frontier.representative,
             balance: new_balance,
             link: destination.into(),
-            work: self
-                .lattice
-                .work_pool
-                .generate_dev2(frontier.hash.into())
-                .unwrap(),
+            work: self.lattice.generate_work(frontier.hash.into()),
         }
         .into();
 
@@ -169,11 +191,7 @@ impl<'a> UnsavedAccountChainBuilder<'a> {
         let amount = amount.into();
         let new_balance = frontier.balance - amount;
 
-        let work = self
-            .lattice
-            .work_pool
-            .generate_dev2(frontier.hash.into())
-            .unwrap();
+        let work = self.lattice.generate_work(frontier.hash.into());
 
         let send: Block = SendBlockArgs {
             key: self.key,
@@ -221,7 +239,7 @@ impl<'a> UnsavedAccountChainBuilder<'a> {
 
         let root: Root = self.key.account().into();
 
-        let work = self.lattice.work_pool.generate_dev2(root).unwrap();
+        let work = self.lattice.generate_work(root);
         let receive: Block = OpenBlockArgs {
             key: &self.key,
             source: corresponding_send.hash(),
@@ -258,7 +276,7 @@ impl<'a> UnsavedAccountChainBuilder<'a> {
         let frontier = self.get_frontier();
         let root: Root = frontier.hash.into();
         let new_balance = frontier.balance + amount;
-        let work = self.lattice.work_pool.generate_dev2(root).unwrap();
+        let work = self.lattice.generate_work(root);
 
         let receive: Block = ReceiveBlockArgs {
             key: self.key,
@@ -309,7 +327,7 @@ impl<'a> UnsavedAccountChainBuilder<'a> {
             representative: new_representative.into(),
             balance: new_balance,
             link: corresponding_send.hash().into(),
-            work: self.lattice.work_pool.generate_dev2(root).unwrap(),
+            work: self.lattice.generate_work(root),
         }
         .into();
 
@@ -325,11 +343,7 @@ impl<'a> UnsavedAccountChainBuilder<'a> {
     pub fn legacy_change(&mut self, new_representative: impl Into<PublicKey>) -> Block {
         let frontier = self.get_frontier();
         let new_representative = new_representative.into();
-        let work = self
-            .lattice
-            .work_pool
-            .generate_dev2(frontier.hash.into())
-            .unwrap();
+        let work = self.lattice.generate_work(frontier.hash.into());
 
         let change: Block = ChangeBlockArgs {
             key: self.key,
@@ -357,11 +371,7 @@ impl<'a> UnsavedAccountChainBuilder<'a> {
             representative: new_representative,
             balance: frontier.balance,
             link: Link::zero(),
-            work: self
-                .lattice
-                .work_pool
-                .generate_dev2(frontier.hash.into())
-                .unwrap(),
+            work: self.lattice.generate_work(frontier.hash.into()),
         }
         .into();
 
@@ -383,11 +393,7 @@ impl<'a> UnsavedAccountChainBuilder<'a> {
             representative: frontier.representative,
             balance: frontier.balance,
             link: epoch_v1_link(),
-            work: self
-                .lattice
-                .work_pool
-                .generate_dev2(frontier.hash.into())
-                .unwrap(),
+            work: self.lattice.generate_work(frontier.hash.into()),
         }
         .into();
 
@@ -26,10 +26,13 @@ pub use state_block::{EpochBlockArgs, JsonStateBlock, StateBlock, StateBlockArgs
 mod builders;
 pub use builders::*;
 
+mod signature_verification;
+pub use signature_verification::verify_block_signatures;
+
 use crate::{
-    utils::{BufferWriter, Deserialize, MemoryStream, Stream},
-    Account, Amount, BlockHash, BlockHashBuilder, Epoch, Epochs, FullHash, Link, PrivateKey,
-    PublicKey, QualifiedRoot, Root, Signature,
+    utils::{BufferReader, BufferWriter, Deserialize, MemoryStream, Stream},
+    Account, Amount, BlockHash, BlockHashBuilder, Difficulty, DifficultyV1, Epoch, Epochs,
+    FullHash, Link, PrivateKey, PublicKey, QualifiedRoot, Root, Signature,
 };
 use num::FromPrimitive;
 use std::{
@@ -238,6 +241,16 @@ impl Block {
             .unwrap_or_else(|| self.link_field().unwrap_or_default().into())
     }
 
+    /// Difficulty of the block's work. Uses work_1, which is the work version the
+    /// ledger validates against
+    pub fn work_difficulty(&self) -> u64 {
+        DifficultyV1::default().get_difficulty(&self.root(), self.work())
+    }
+
+    pub fn meets_threshold(&self, threshold: u64) -> bool {
+        self.work_difficulty() >= threshold
+    }
+
     pub fn serialize(&self, stream: &mut dyn BufferWriter) {
         let block_type = self.block_type() as u8;
         stream.write_u8_safe(block_type);
@@ -264,6 +277,44 @@ impl Block {
             BlockType::from_u8(stream.read_u8()?).ok_or_else(|| anyhow!("invalid block type"))?;
         Self::deserialize_block_type(block_type, stream)
     }
+
+    /// Block length (u16 big endian) followed by the block type
+    pub const FRAME_HEADER_SIZE: usize = 3;
+
+    /// Serializes the block prefixed with its length and block type, so that
+    /// blocks can be sent one after another over a byte stream
+    pub fn to_framed_bytes(&self) -> Vec<u8> {
+        let mut stream = MemoryStream::new();
+        self.serialize_without_block_type(&mut stream);
+        let block_bytes = stream.to_vec();
+
+        let mut result = Vec::with_capacity(Self::FRAME_HEADER_SIZE + block_bytes.len());
+        result.extend_from_slice(&(block_bytes.len() as u16).to_be_bytes());
+        result.push(self.block_type() as u8);
+        result.extend_from_slice(&block_bytes);
+        result
+    }
+
+    /// Reads a block that was serialized with `to_framed_bytes`. Returns the block
+    /// and the size of its frame. Bytes after the frame are not read.
+    pub fn from_framed_bytes(bytes: &[u8]) -> anyhow::Result<(Self, usize)> {
+        if bytes.len() < Self::FRAME_HEADER_SIZE {
+            bail!("incomplete block frame header");
+        }
+        let len = u16::from_be_bytes([bytes[0], bytes[1]]) as usize;
+        let block_type = BlockType::try_from(bytes[2])?;
+        let frame_size = Self::FRAME_HEADER_SIZE + len;
+        let Some(block_bytes) = bytes.get(Self::FRAME_HEADER_SIZE..frame_size) else {
+            bail!("incomplete block frame");
+        };
+
+        let mut stream = BufferReader::new(block_bytes);
+        let block = Self::deserialize_block_type(block_type, &mut stream)?;
+        if !stream.remaining().is_empty() {
+            bail!("block frame length doesn't match the block size");
+        }
+        Ok((block, frame_size))
+    }
 }
 
 impl From<Block> for serde_json::Value {
@@ -702,6 +753,14 @@ mod tests {
         assert_serializable(block);
     }
 
+    #[test]
+    fn work_difficulty() {
+        let block = Block::new_test_instance();
+        assert_eq!(block.work_difficulty(), 9665579333895977632);
+        assert!(block.meets_threshold(9665579333895977632));
+        assert!(!block.meets_threshold(9665579333895977633));
+    }
+
     #[test]
     fn serialize_legacy_send() {
         let block = TestBlockBuilder::legacy_send().build();
@@ -720,6 +779,35 @@ mod tests {
         assert_serializable(block);
     }
 
+    #[test]
+    fn framed_bytes_round_trip() {
+        let blocks: [Block; 5] = [
+            TestBlockBuilder::legacy_send().build(),
+            TestBlockBuilder::legacy_receive().build(),
+            TestBlockBuilder::legacy_open().build(),
+            TestBlockBuilder::legacy_change().build(),
+            TestBlockBuilder::state().build(),
+        ];
+        for block in blocks {
+            let mut bytes = block.to_framed_bytes();
+            let frame_size = bytes.len();
+            // data of the next frame must not be read
+            bytes.extend_from_slice(&[1, 2, 3]);
+
+            let (deserialized, size) = Block::from_framed_bytes(&bytes).unwrap();
+
+            assert_eq!(deserialized, block);
+            assert_eq!(size, frame_size);
+        }
+    }
+
+    #[test]
+    fn incomplete_frame() {
+        let bytes = TestBlockBuilder::state().build().to_framed_bytes();
+        assert!(Block::from_framed_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Block::from_framed_bytes(&bytes[..2]).is_err());
+    }
+
     fn assert_serializable(block: Block) {
         let mut buffer = MemoryStream::new();
         block.serialize(&mut buffer);
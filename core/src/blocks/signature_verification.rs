@@ -0,0 +1,88 @@
+use super::Block;
+use crate::PublicKey;
+use std::thread;
+
+/// Slices smaller than this are verified on the calling thread
+const MIN_PARALLEL_BATCH: usize = 64;
+
+/// Verifies the signatures of many blocks at once. Each block is paired with the
+/// key that is expected to have signed it, because legacy and epoch blocks don't
+/// contain their signer. The result contains one entry per block, in the same order.
+/// Large slices are split across all available cores.
+pub fn verify_block_signatures(blocks: &[(&Block, PublicKey)]) -> Vec<bool> {
+    let threads = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+
+    if blocks.len() < MIN_PARALLEL_BATCH || threads == 1 {
+        return verify_sequentially(blocks);
+    }
+
+    let chunk_size = blocks.len().div_ceil(threads);
+    thread::scope(|s| {
+        let handles: Vec<_> = blocks
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(|| verify_sequentially(chunk)))
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect()
+    })
+}
+
+fn verify_sequentially(blocks: &[(&Block, PublicKey)]) -> Vec<bool> {
+    blocks
+        .iter()
+        .map(|(block, signer)| {
+            signer
+                .verify(block.hash().as_bytes(), block.signature())
+                .is_ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PrivateKey, Signature};
+
+    #[test]
+    fn mixed_valid_and_invalid_signatures() {
+        let key = PrivateKey::from(42);
+        let valid = Block::new_test_instance_with_key(key.clone());
+        let mut invalid = Block::new_test_instance_with_key(key.clone());
+        invalid.set_signature(&Signature::from_bytes([1; 64]));
+        let other_key = PrivateKey::from(43);
+
+        let result = verify_block_signatures(&[
+            (&valid, key.public_key()),
+            (&invalid, key.public_key()),
+            (&valid, other_key.public_key()),
+            (&valid, key.public_key()),
+        ]);
+
+        assert_eq!(result, [true, false, false, true]);
+    }
+
+    #[test]
+    fn keep_order_when_verifying_in_parallel() {
+        let key = PrivateKey::from(42);
+        let valid = Block::new_test_instance_with_key(key.clone());
+        let mut invalid = Block::new_test_instance_with_key(key.clone());
+        invalid.set_signature(&Signature::from_bytes([1; 64]));
+
+        let blocks: Vec<_> = (0..MIN_PARALLEL_BATCH * 2)
+            .map(|i| {
+                let block = if i % 3 == 0 { &invalid } else { &valid };
+                (block, key.public_key())
+            })
+            .collect();
+
+        let result = verify_block_signatures(&blocks);
+
+        let expected: Vec<_> = (0..blocks.len()).map(|i| i % 3 != 0).collect();
+        assert_eq!(result, expected);
+    }
+}
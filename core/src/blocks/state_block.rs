@@ -21,6 +21,14 @@ impl StateBlock {
             .verify(self.hash().as_bytes(), self.signature())
     }
 
+    /// Returns a copy of this block with a different work nonce.
+    /// The work is not part of the hash, so the signature stays valid.
+    pub fn with_work(&self, work: u64) -> StateBlock {
+        let mut block = self.clone();
+        block.work = work;
+        block
+    }
+
     pub fn account(&self) -> Account {
         self.hashables.account
     }
@@ -331,6 +339,21 @@ mod tests {
         assert_eq!(block1, Block::State(block2));
     }
 
+    #[test]
+    fn with_work() {
+        let Block::State(block) = TestBlockBuilder::state().work(5).build() else {
+            panic!("not a state block");
+        };
+
+        let reworked = block.with_work(42);
+
+        assert_eq!(reworked.work, 42);
+        assert_eq!(reworked.hash(), block.hash());
+        assert_eq!(reworked.hashables, block.hashables);
+        assert_eq!(reworked.signature, block.signature);
+        assert!(reworked.verify_signature().is_ok());
+    }
+
     #[test]
     fn hashing() {
         let key = PrivateKey::from(42);
@@ -43,6 +43,7 @@ impl<'a> BlockInserter<'a> {
         let sideband = self.instructions.set_sideband.clone();
         let saved_block = SavedBlock::new(self.block.clone(), sideband);
         self.ledger.store.block.put(self.txn, &saved_block);
+        self.ledger.missing_blocks.erase(&saved_block.hash());
         self.update_account();
         self.delete_old_pending_info();
         self.insert_new_pending_info();
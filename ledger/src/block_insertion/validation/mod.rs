@@ -30,6 +30,16 @@ pub(crate) struct BlockValidator<'a> {
 
 impl<'a> BlockValidator<'a> {
     pub(crate) fn validate(&self) -> Result<BlockInsertInstructions, BlockStatus> {
+        self.run_checks(true)?;
+        Ok(self.create_instructions())
+    }
+
+    /// Returns the reason why the block would be rejected, without checking the proof of work
+    pub(crate) fn quick_reject_reason(&self) -> Option<BlockStatus> {
+        self.run_checks(false).err()
+    }
+
+    fn run_checks(&self, check_work: bool) -> Result<(), BlockStatus> {
         self.epoch_block_pre_checks()?;
         self.ensure_block_does_not_exist_yet()?;
         self.ensure_valid_predecessor()?;
@@ -41,11 +51,11 @@ impl<'a> BlockValidator<'a> {
         self.ensure_open_block_has_link()?;
         self.ensure_no_reveive_balance_change_without_link()?;
         self.ensure_pending_receive_is_correct()?;
-        self.ensure_sufficient_work()?;
+        if check_work {
+            self.ensure_sufficient_work()?;
+        }
         self.ensure_no_negative_amount_send()?;
-        self.ensure_valid_epoch_block()?;
-
-        Ok(self.create_instructions())
+        self.ensure_valid_epoch_block()
     }
 
     fn create_instructions(&self) -> BlockInsertInstructions {
@@ -1,7 +1,7 @@
 use rsban_core::{utils::seconds_since_epoch, Account, Block, PendingKey, SavedBlock};
 use rsban_store_lmdb::Transaction;
 
-use crate::Ledger;
+use crate::{BlockStatus, Ledger};
 
 use super::BlockValidator;
 
@@ -53,6 +53,10 @@ impl<'a> BlockValidatorFactory<'a> {
         }
     }
 
+    pub(crate) fn quick_reject_reason(&self) -> Option<BlockStatus> {
+        self.create_validator().quick_reject_reason()
+    }
+
     fn get_account(&self, previous: &Option<SavedBlock>) -> Option<Account> {
         match self.block.account_field() {
             Some(account) => Some(account),
@@ -1,6 +1,6 @@
 use super::rollback_planner::RollbackInstructions;
 use crate::Ledger;
-use rsban_core::{Amount, PublicKey};
+use rsban_core::PublicKey;
 use rsban_store_lmdb::LmdbWriteTransaction;
 use std::sync::atomic::Ordering;
 
@@ -24,11 +24,13 @@ impl<'a> RollbackInstructionsExecutor<'a> {
         }
     }
 
-    pub(crate) fn execute(&mut self) {
+    /// Fails without changing the ledger if the representative weights are inconsistent
+    pub(crate) fn execute(&mut self) -> anyhow::Result<()> {
+        // Weights are checked first, so that nothing is written if they are inconsistent
+        self.roll_back_representative_cache()?;
         self.update_pending_table();
         self.update_account_table();
         self.update_block_table();
-        self.roll_back_representative_cache();
         self.ledger
             .store
             .cache
@@ -38,6 +40,7 @@ impl<'a> RollbackInstructionsExecutor<'a> {
         self.ledger
             .observer
             .block_rolled_back(self.instructions.block_sub_type);
+        Ok(())
     }
 
     fn update_block_table(&mut self) {
@@ -69,29 +72,32 @@ impl<'a> RollbackInstructionsExecutor<'a> {
         }
     }
 
-    fn roll_back_representative_cache(&mut self) {
+    fn roll_back_representative_cache(&mut self) -> anyhow::Result<()> {
         if let Some(previous_rep) = &self.instructions.new_representative {
-            self.roll_back_change_in_representative_cache(previous_rep);
+            self.roll_back_change_in_representative_cache(previous_rep)
         } else {
             self.roll_back_receive_in_representative_cache()
         }
     }
 
-    fn roll_back_change_in_representative_cache(&mut self, previous_representative: &PublicKey) {
-        self.ledger.rep_weights_updater.representation_add_dual(
+    fn roll_back_change_in_representative_cache(
+        &mut self,
+        previous_representative: &PublicKey,
+    ) -> anyhow::Result<()> {
+        self.ledger.rep_weights_updater.representation_move(
             self.txn,
             self.instructions.old_account_info.representative,
-            Amount::zero().wrapping_sub(self.instructions.old_account_info.balance),
+            self.instructions.old_account_info.balance,
             *previous_representative,
             self.instructions.new_balance,
-        );
+        )
     }
 
-    fn roll_back_receive_in_representative_cache(&mut self) {
-        self.ledger.rep_weights_updater.representation_add(
+    fn roll_back_receive_in_representative_cache(&mut self) -> anyhow::Result<()> {
+        self.ledger.rep_weights_updater.representation_sub(
             self.txn,
             self.instructions.old_account_info.representative,
-            Amount::zero().wrapping_sub(self.instructions.old_account_info.balance),
-        );
+            self.instructions.old_account_info.balance,
+        )
     }
 }
@@ -12,6 +12,7 @@ pub(crate) struct BlockRollbackPerformer<'a> {
     ledger: &'a Ledger,
     pub txn: &'a mut LmdbWriteTransaction,
     pub rolled_back: Vec<SavedBlock>,
+    max_depth: usize,
 }
 
 impl<'a> BlockRollbackPerformer<'a> {
@@ -20,6 +21,7 @@ impl<'a> BlockRollbackPerformer<'a> {
             ledger,
             txn,
             rolled_back: Vec::new(),
+            max_depth: ledger.rollback_max_depth(),
         }
     }
 
@@ -28,33 +30,48 @@ impl<'a> BlockRollbackPerformer<'a> {
         Ok(self.rolled_back)
     }
 
+    /// Rolls back the target block and all of its successors. Blocks that
+    /// depend on a block being rolled back are put on a stack and get rolled
+    /// back first, so that deep dependency chains don't overflow the call stack
     fn roll_back_block_and_successors(&mut self, block_hash: &BlockHash) -> anyhow::Result<()> {
-        let block = self.load_block(block_hash)?;
-        while self.block_exists(block_hash) {
+        self.load_block(block_hash)?;
+        let mut targets = vec![*block_hash];
+        while let Some(target) = targets.last().copied() {
+            if !self.block_exists(&target) {
+                targets.pop();
+                continue;
+            }
+
+            let block = self.load_block(&target)?;
             let head_block = self.load_account_head(&block)?;
-            self.roll_back_head_block(head_block)?;
+            if let Some(dependency) = self.roll_back_head_block(head_block)? {
+                if targets.len() >= self.max_depth {
+                    bail!(
+                        "rollback aborted, because the dependency depth exceeds {}",
+                        self.max_depth
+                    );
+                }
+                targets.push(dependency);
+            }
         }
         Ok(())
     }
 
-    fn roll_back_head_block(&mut self, head_block: SavedBlock) -> Result<(), anyhow::Error> {
+    /// Rolls back the head block or returns the hash of a block which needs to be rolled back first
+    fn roll_back_head_block(
+        &mut self,
+        head_block: SavedBlock,
+    ) -> anyhow::Result<Option<BlockHash>> {
         let planner =
             RollbackPlannerFactory::new(self.ledger, self.txn, &head_block).create_planner()?;
-        let step = planner.roll_back_head_block()?;
-        self.execute(step, head_block)?;
-        Ok(())
-    }
-
-    fn execute(&mut self, step: RollbackStep, head_block: SavedBlock) -> Result<(), anyhow::Error> {
-        match step {
+        match planner.roll_back_head_block()? {
             RollbackStep::RollBackBlock(instructions) => {
-                RollbackInstructionsExecutor::new(self.ledger, self.txn, &instructions).execute();
+                RollbackInstructionsExecutor::new(self.ledger, self.txn, &instructions)
+                    .execute()?;
                 self.rolled_back.push(head_block);
-                Ok(())
-            }
-            RollbackStep::RequestDependencyRollback(dependency_hash) => {
-                self.roll_back_block_and_successors(&dependency_hash)
+                Ok(None)
             }
+            RollbackStep::RequestDependencyRollback(dependency_hash) => Ok(Some(dependency_hash)),
         }
     }
 
@@ -3,14 +3,15 @@ use crate::{
     block_cementer::BlockCementer,
     block_insertion::{BlockInserter, BlockValidatorFactory},
     ledger_set_confirmed::LedgerSetConfirmed,
-    BlockRollbackPerformer, GenerateCacheFlags, LedgerConstants, LedgerSetAny, RepWeightCache,
-    RepWeightsUpdater, RepresentativeBlockFinder, WriteGuard, WriteQueue,
+    BlockRollbackPerformer, GenerateCacheFlags, LedgerConstants, LedgerSetAny, MissingBlockCache,
+    RepWeightCache, RepWeightsUpdater, RepresentativeBlockFinder, WriteGuard, WriteQueue,
 };
 use rand::{thread_rng, Rng};
 use rsban_core::{
     utils::{seconds_since_epoch, ContainerInfo},
-    Account, AccountInfo, Amount, Block, BlockHash, BlockSubType, ConfirmationHeightInfo,
-    DependentBlocks, Epoch, Link, PendingInfo, PendingKey, PublicKey, Root, SavedBlock,
+    Account, AccountInfo, Amount, Block, BlockHash, BlockHashBuilder, BlockSubType,
+    ConfirmationHeightInfo, DependentBlocks, Epoch, Link, PendingInfo, PendingKey, PublicKey, Root,
+    SavedBlock,
 };
 use rsban_store_lmdb::{
     ConfiguredAccountDatabaseBuilder, ConfiguredBlockDatabaseBuilder,
@@ -24,12 +25,15 @@ use std::{
     collections::HashMap,
     net::SocketAddrV6,
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc,
     },
-    time::{Duration, SystemTime},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
+/// Upper bound for the number of blocks returned by `Ledger::blocks_in_range`
+pub const MAX_BLOCKS_IN_RANGE: u64 = 1000;
+
 #[derive(PartialEq, Eq, Debug, Clone, Copy, FromPrimitive)]
 #[repr(u8)]
 pub enum BlockStatus {
@@ -85,6 +89,13 @@ pub trait LedgerObserver: Send + Sync {
     fn block_rolled_back2(&self, _block: &Block, _is_epoch: bool) {}
     fn block_added(&self, _block: &Block, _is_epoch: bool) {}
     fn dependent_unconfirmed(&self) {}
+    fn representative_weight_changed(
+        &self,
+        _representative: &PublicKey,
+        _old_weight: Amount,
+        _new_weight: Amount,
+    ) {
+    }
 }
 
 pub struct NullLedgerObserver {}
@@ -104,7 +115,9 @@ pub struct Ledger {
     pub constants: LedgerConstants,
     pub observer: Arc<dyn LedgerObserver>,
     pruning: AtomicBool,
+    rollback_max_depth: AtomicUsize,
     pub write_queue: Arc<WriteQueue>,
+    pub missing_blocks: MissingBlockCache,
 }
 
 pub struct NullLedgerBuilder {
@@ -206,6 +219,8 @@ impl NullLedgerBuilder {
 }
 
 impl Ledger {
+    pub const DEFAULT_ROLLBACK_MAX_DEPTH: usize = 100_000;
+
     pub fn new_null() -> Self {
         Self::new(
             Arc::new(LmdbStore::new_null()),
@@ -236,7 +251,9 @@ impl Ledger {
             constants,
             observer: Arc::new(NullLedgerObserver::new()),
             pruning: AtomicBool::new(false),
+            rollback_max_depth: AtomicUsize::new(Self::DEFAULT_ROLLBACK_MAX_DEPTH),
             write_queue: Arc::new(WriteQueue::new()),
+            missing_blocks: MissingBlockCache::default(),
         };
 
         ledger.initialize(&GenerateCacheFlags::new())?;
@@ -245,6 +262,7 @@ impl Ledger {
     }
 
     pub fn set_observer(&mut self, observer: Arc<dyn LedgerObserver>) {
+        self.rep_weights_updater.set_observer(observer.clone());
         self.observer = observer;
     }
 
@@ -361,7 +379,7 @@ impl Ledger {
     }
 
     pub fn any(&self) -> LedgerSetAny {
-        LedgerSetAny::new(&self.store)
+        LedgerSetAny::with_missing_block_cache(&self.store, &self.missing_blocks)
     }
 
     pub fn confirmed(&self) -> LedgerSetConfirmed {
@@ -376,6 +394,16 @@ impl Ledger {
         self.pruning.store(true, Ordering::SeqCst);
     }
 
+    /// The maximum number of dependent rollbacks that may be pending at once.
+    /// Deeper rollbacks are aborted
+    pub fn rollback_max_depth(&self) -> usize {
+        self.rollback_max_depth.load(Ordering::Relaxed)
+    }
+
+    pub fn set_rollback_max_depth(&self, max_depth: usize) {
+        self.rollback_max_depth.store(max_depth, Ordering::Relaxed);
+    }
+
     pub fn bootstrap_weight_max_blocks(&self) -> u64 {
         self.rep_weights.bootstrap_weight_max_blocks()
     }
@@ -467,6 +495,16 @@ impl Ledger {
             .unwrap_or(Epoch::Epoch0)
     }
 
+    /// Number of blocks per epoch version. This scans the whole block table,
+    /// so it is meant for occasional reporting only
+    pub fn block_count_by_epoch(&self, txn: &dyn Transaction) -> HashMap<Epoch, u64> {
+        let mut counts = HashMap::new();
+        for (_, block) in self.store.block.iter(txn) {
+            *counts.entry(block.epoch()).or_default() += 1;
+        }
+        counts
+    }
+
     pub fn is_epoch_link(&self, link: &Link) -> bool {
         self.constants.epochs.is_epoch_link(link)
     }
@@ -505,6 +543,11 @@ impl Ledger {
         self.constants.epochs.link(epoch).cloned()
     }
 
+    /// Returns the epoch which is signaled by the given link, if any
+    pub fn epoch_for_link(&self, link: &Link) -> Option<Epoch> {
+        self.constants.epochs.epoch(link)
+    }
+
     pub fn update_account(
         &self,
         txn: &mut LmdbWriteTransaction,
@@ -623,10 +666,91 @@ impl Ledger {
         Ok(inserted)
     }
 
+    /// Runs all checks of `process` without inserting the block
+    pub fn validate(&self, txn: &dyn Transaction, block: &Block) -> Result<(), BlockStatus> {
+        BlockValidatorFactory::new(self, txn, block)
+            .create_validator()
+            .validate()
+            .map(|_| ())
+    }
+
+    /// Checks whether the block would be rejected by the ledger without inserting it.
+    /// The proof of work is not validated.
+    pub fn quick_reject_reason(&self, txn: &dyn Transaction, block: &Block) -> Option<BlockStatus> {
+        BlockValidatorFactory::new(self, txn, block).quick_reject_reason()
+    }
+
     pub fn get_block(&self, txn: &dyn Transaction, hash: &BlockHash) -> Option<SavedBlock> {
         self.store.block.get(txn, hash)
     }
 
+    /// Hash of the block following the given block in its account chain.
+    /// Returns None at the chain tip. Only the sideband is read, not the whole block
+    pub fn successor(&self, txn: &dyn Transaction, hash: &BlockHash) -> Option<BlockHash> {
+        self.store.block.successor(txn, hash)
+    }
+
+    /// Blocks of the account chain with a height in `from_height..=to_height`, ordered by height.
+    /// At most `MAX_BLOCKS_IN_RANGE` blocks are returned. The chain is walked from the open
+    /// block or from the frontier, whichever is closer to the requested range
+    pub fn blocks_in_range(
+        &self,
+        txn: &dyn Transaction,
+        account: &Account,
+        from_height: u64,
+        to_height: u64,
+    ) -> Vec<SavedBlock> {
+        let Some(info) = self.account_info(txn, account) else {
+            return Vec::new();
+        };
+        let from_height = from_height.max(1);
+        let to_height = to_height
+            .min(info.block_count)
+            .min(from_height.saturating_add(MAX_BLOCKS_IN_RANGE - 1));
+        if from_height > to_height {
+            return Vec::new();
+        }
+
+        let mut result = Vec::with_capacity((to_height - from_height + 1) as usize);
+        if from_height - 1 <= info.block_count - to_height {
+            // Skip to the start of the range by reading the successors only
+            let mut current = Some(info.open_block);
+            for _ in 1..from_height {
+                current = current.and_then(|hash| self.successor(txn, &hash));
+            }
+            while let Some(block) = current.and_then(|hash| self.get_block(txn, &hash)) {
+                if block.height() > to_height {
+                    break;
+                }
+                current = block.successor();
+                result.push(block);
+            }
+        } else {
+            let mut current = Some(info.head);
+            while let Some(block) = current.and_then(|hash| self.get_block(txn, &hash)) {
+                if block.height() < from_height {
+                    break;
+                }
+                current = Some(block.previous());
+                if block.height() <= to_height {
+                    result.push(block);
+                }
+            }
+            result.reverse();
+        }
+        result
+    }
+
+    /// Checks if the incoming block competes with a block that is already in the ledger.
+    /// Returns the hash of the existing block and the hash of the incoming block
+    pub fn fork_at(&self, txn: &dyn Transaction, block: &Block) -> Option<(BlockHash, BlockHash)> {
+        let existing = self
+            .any()
+            .block_successor_by_qualified_root(txn, &block.qualified_root())?;
+        let incoming = block.hash();
+        (existing != incoming).then_some((existing, incoming))
+    }
+
     pub fn account_info(
         &self,
         transaction: &dyn Transaction,
@@ -635,6 +759,64 @@ impl Ledger {
         self.store.account.get(transaction, account)
     }
 
+    /// Time of the last change to the account chain
+    pub fn account_last_modified(
+        &self,
+        txn: &dyn Transaction,
+        account: &Account,
+    ) -> Option<SystemTime> {
+        self.account_info(txn, account)
+            .map(|info| UNIX_EPOCH + Duration::from_secs(info.modified))
+    }
+
+    /// Accounts whose chain wasn't changed since `cutoff`. This scans the whole
+    /// account table, so it is meant for housekeeping tasks only
+    pub fn accounts_modified_before<'txn>(
+        &self,
+        txn: &'txn dyn Transaction,
+        cutoff: SystemTime,
+    ) -> impl Iterator<Item = (Account, AccountInfo)> + 'txn {
+        let cutoff = cutoff
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.store
+            .account
+            .iter(txn)
+            .filter(move |(_, info)| info.modified < cutoff)
+    }
+
+    /// Looks up the pending info of many keys in one go. The result is aligned by index with `keys`
+    pub fn pending_info_many(
+        &self,
+        txn: &dyn Transaction,
+        keys: &[PendingKey],
+    ) -> Vec<Option<PendingInfo>> {
+        self.store.pending.get_many(txn, keys)
+    }
+
+    /// Order independent checksum over the frontiers of all accounts in `start..=end`.
+    /// Two ledgers with the same frontiers in that range have the same checksum, so
+    /// only ranges with differing checksums need to be compared in detail
+    pub fn frontier_checksum(
+        &self,
+        txn: &dyn Transaction,
+        start: Account,
+        end: Account,
+    ) -> [u8; 32] {
+        let mut checksum = [0; 32];
+        for (account, info) in self.store.account.iter_range(txn, start..=end) {
+            let hash = BlockHashBuilder::new()
+                .update(account.as_bytes())
+                .update(info.head.as_bytes())
+                .build();
+            for (c, h) in checksum.iter_mut().zip(hash.as_bytes()) {
+                *c ^= h;
+            }
+        }
+        checksum
+    }
+
     pub fn get_confirmation_height(
         &self,
         txn: &dyn Transaction,
@@ -643,6 +825,12 @@ impl Ledger {
         self.store.confirmation_height.get(txn, account)
     }
 
+    /// Balance of the account at its confirmation height frontier.
+    /// Unlike the latest balance this ignores blocks that are not confirmed yet
+    pub fn confirmed_balance(&self, txn: &dyn Transaction, account: &Account) -> Option<Amount> {
+        self.confirmed().account_balance(txn, account)
+    }
+
     pub fn confirm(&self, txn: &mut LmdbWriteTransaction, hash: BlockHash) -> Vec<SavedBlock> {
         self.confirm_max(txn, hash, 1024 * 128)
     }
@@ -681,6 +869,7 @@ impl Ledger {
     pub fn container_info(&self) -> ContainerInfo {
         ContainerInfo::builder()
             .node("rep_weights", self.rep_weights.container_info())
+            .node("missing_blocks", self.missing_blocks.container_info())
             .finish()
     }
 }
@@ -1,17 +1,34 @@
+use crate::MissingBlockCache;
 use rsban_core::{
     utils::{BufferReader, Deserialize},
     Account, AccountInfo, Amount, BlockHash, PendingInfo, PendingKey, QualifiedRoot, SavedBlock,
 };
-use rsban_store_lmdb::{LmdbIterator, LmdbPendingStore, LmdbStore, Transaction};
+use rsban_store_lmdb::{
+    LmdbIterator, LmdbPendingStore, LmdbStore, LmdbWriteTransaction, Transaction,
+};
 use std::ops::{Deref, RangeBounds};
 
 pub struct LedgerSetAny<'a> {
     store: &'a LmdbStore,
+    missing_blocks: Option<&'a MissingBlockCache>,
 }
 
 impl<'a> LedgerSetAny<'a> {
     pub fn new(store: &'a LmdbStore) -> Self {
-        Self { store }
+        Self {
+            store,
+            missing_blocks: None,
+        }
+    }
+
+    pub fn with_missing_block_cache(
+        store: &'a LmdbStore,
+        missing_blocks: &'a MissingBlockCache,
+    ) -> Self {
+        Self {
+            store,
+            missing_blocks: Some(missing_blocks),
+        }
     }
 
     pub fn get_block(&self, tx: &dyn Transaction, hash: &BlockHash) -> Option<SavedBlock> {
@@ -76,11 +93,25 @@ impl<'a> LedgerSetAny<'a> {
     }
 
     pub fn block_exists_or_pruned(&self, tx: &dyn Transaction, hash: &BlockHash) -> bool {
-        if self.store.pruned.exists(tx, hash) {
-            true
-        } else {
-            self.store.block.exists(tx, hash)
+        if let Some(missing) = self.missing_blocks {
+            if missing.contains(hash) {
+                return false;
+            }
+        }
+
+        let exists = self.store.pruned.exists(tx, hash) || self.store.block.exists(tx, hash);
+
+        if !exists {
+            if let Some(missing) = self.missing_blocks {
+                // Only write transactions see the latest ledger state. A read transaction
+                // could cache a block as missing that was inserted after it was started.
+                if tx.as_any().is::<LmdbWriteTransaction>() {
+                    missing.insert(*hash);
+                }
+            }
         }
+
+        exists
     }
 
     pub fn block_height(&self, tx: &dyn Transaction, hash: &BlockHash) -> u64 {
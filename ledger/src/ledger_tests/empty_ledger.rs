@@ -3,7 +3,9 @@ use crate::{
     ledger_constants::{DEV_GENESIS_PUB_KEY, LEDGER_CONSTANTS_STUB},
     DEV_GENESIS_ACCOUNT, DEV_GENESIS_HASH,
 };
-use rsban_core::{utils::seconds_since_epoch, Account, BlockType};
+use rsban_core::{
+    epoch_v1_link, epoch_v2_link, utils::seconds_since_epoch, Account, BlockType, Epoch, Link,
+};
 
 #[test]
 fn account_balance_is_none_for_unknown_account() {
@@ -103,3 +105,16 @@ fn latest_empty() {
     let txn = ctx.ledger.read_txn();
     assert_eq!(ctx.ledger.any().account_head(&txn, &Account::from(1)), None);
 }
+
+#[test]
+fn epoch_links_round_trip() {
+    let ctx = LedgerContext::empty();
+
+    assert_eq!(ctx.ledger.epoch_link(Epoch::Epoch1), Some(epoch_v1_link()));
+    assert_eq!(ctx.ledger.epoch_link(Epoch::Epoch2), Some(epoch_v2_link()));
+    for epoch in [Epoch::Epoch1, Epoch::Epoch2] {
+        let link = ctx.ledger.epoch_link(epoch).unwrap();
+        assert_eq!(ctx.ledger.epoch_for_link(&link), Some(epoch));
+    }
+    assert_eq!(ctx.ledger.epoch_for_link(&Link::from(42)), None);
+}
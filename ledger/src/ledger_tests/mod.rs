@@ -3,12 +3,16 @@ pub mod helpers;
 use crate::{
     ledger_constants::{DEV_GENESIS_BLOCK, DEV_GENESIS_PUB_KEY, LEDGER_CONSTANTS_STUB},
     ledger_tests::helpers::{setup_legacy_open_block, setup_open_block, AccountBlockFactory},
-    Ledger, LedgerContext, RepWeightCache, DEV_GENESIS_ACCOUNT, DEV_GENESIS_HASH,
+    BlockStatus, Ledger, LedgerContext, RepWeightCache, DEV_GENESIS_ACCOUNT, DEV_GENESIS_HASH,
 };
 use rsban_core::{
     utils::{new_test_timestamp, TEST_ENDPOINT_1},
-    Account, Amount, BlockHash, PublicKey, QualifiedRoot, Root, SavedAccountChain,
-    TestBlockBuilder, DEV_GENESIS_KEY,
+    Account, AccountInfo, Amount, BlockHash, Epoch, PendingInfo, PendingKey, PublicKey,
+    QualifiedRoot, Root, SavedAccountChain, TestBlockBuilder, DEV_GENESIS_KEY,
+};
+use std::{
+    collections::HashMap,
+    time::{Duration, UNIX_EPOCH},
 };
 
 mod empty_ledger;
@@ -81,6 +85,161 @@ fn latest_root() {
     );
 }
 
+#[test]
+fn block_count_by_epoch() {
+    let mut chain = SavedAccountChain::new_opened_chain();
+    chain.add_state();
+    chain.add_epoch_v1();
+    chain.add_state();
+    chain.add_state();
+    chain.add_epoch_v2();
+
+    let ledger = Ledger::new_null_builder()
+        .blocks(chain.blocks())
+        .account_info(&chain.account(), &chain.account_info())
+        .finish();
+    let txn = ledger.read_txn();
+
+    assert_eq!(
+        ledger.block_count_by_epoch(&txn),
+        HashMap::from([(Epoch::Epoch0, 2), (Epoch::Epoch1, 3), (Epoch::Epoch2, 1)])
+    );
+}
+
+#[test]
+fn successor() {
+    let ctx = LedgerContext::empty();
+    let mut txn = ctx.ledger.rw_txn();
+    let genesis = ctx.genesis_block_factory();
+
+    let mut send = genesis.send(&txn).build();
+    ctx.ledger.process(&mut txn, &mut send).unwrap();
+
+    assert_eq!(
+        ctx.ledger.successor(&txn, &DEV_GENESIS_HASH),
+        Some(send.hash())
+    );
+    assert_eq!(ctx.ledger.successor(&txn, &send.hash()), None);
+}
+
+#[test]
+fn fork_at() {
+    let ctx = LedgerContext::empty();
+    let mut txn = ctx.ledger.rw_txn();
+    let genesis = ctx.genesis_block_factory();
+
+    let mut send1 = genesis.send(&txn).build();
+    let send2 = genesis.send(&txn).link(Account::from(2)).build();
+    ctx.ledger.process(&mut txn, &mut send1).unwrap();
+
+    assert_eq!(
+        ctx.ledger.fork_at(&txn, &send2),
+        Some((send1.hash(), send2.hash()))
+    );
+    assert_eq!(ctx.ledger.fork_at(&txn, &send1), None);
+}
+
+#[test]
+fn account_last_modified() {
+    let stale = AccountInfo {
+        modified: 1000,
+        ..AccountInfo::new_test_instance()
+    };
+    let recent = AccountInfo {
+        modified: 2000,
+        ..AccountInfo::new_test_instance()
+    };
+    let ledger = Ledger::new_null_builder()
+        .account_info(&Account::from(1), &stale)
+        .account_info(&Account::from(2), &recent)
+        .finish();
+    let txn = ledger.read_txn();
+
+    assert_eq!(
+        ledger.account_last_modified(&txn, &Account::from(1)),
+        Some(UNIX_EPOCH + Duration::from_secs(1000))
+    );
+    assert_eq!(ledger.account_last_modified(&txn, &Account::from(3)), None);
+
+    let stale_accounts: Vec<_> = ledger
+        .accounts_modified_before(&txn, UNIX_EPOCH + Duration::from_secs(2000))
+        .map(|(account, _)| account)
+        .collect();
+    assert_eq!(stale_accounts, [Account::from(1)]);
+}
+
+#[test]
+fn frontier_checksum() {
+    let info = |head: u64| AccountInfo {
+        head: BlockHash::from(head),
+        ..AccountInfo::new_test_instance()
+    };
+    let ledger_a = Ledger::new_null_builder()
+        .account_info(&Account::from(1), &info(100))
+        .account_info(&Account::from(2), &info(200))
+        .account_info(&Account::from(5), &info(500))
+        .finish();
+    let ledger_b = Ledger::new_null_builder()
+        .account_info(&Account::from(1), &info(100))
+        .account_info(&Account::from(2), &info(200))
+        .account_info(&Account::from(5), &info(999))
+        .finish();
+    let txn_a = ledger_a.read_txn();
+    let txn_b = ledger_b.read_txn();
+
+    let checksum_a = ledger_a.frontier_checksum(&txn_a, Account::from(1), Account::from(2));
+    let checksum_b = ledger_b.frontier_checksum(&txn_b, Account::from(1), Account::from(2));
+    assert_eq!(checksum_a, checksum_b);
+    assert_ne!(checksum_a, [0; 32]);
+
+    let checksum_a = ledger_a.frontier_checksum(&txn_a, Account::from(1), Account::from(5));
+    let checksum_b = ledger_b.frontier_checksum(&txn_b, Account::from(1), Account::from(5));
+    assert_ne!(checksum_a, checksum_b);
+}
+
+#[test]
+fn pending_info_many() {
+    let key1 = PendingKey::new(Account::from(1), BlockHash::from(10));
+    let key2 = PendingKey::new(Account::from(2), BlockHash::from(20));
+    let missing1 = PendingKey::new(Account::from(1), BlockHash::from(11));
+    let missing2 = PendingKey::new(Account::from(9), BlockHash::from(90));
+    let info1 = PendingInfo::new(Account::from(100), Amount::raw(1), Epoch::Epoch0);
+    let info2 = PendingInfo::new(Account::from(200), Amount::raw(2), Epoch::Epoch1);
+    let ledger = Ledger::new_null_builder()
+        .pending(&key1, &info1)
+        .pending(&key2, &info2)
+        .finish();
+    let txn = ledger.read_txn();
+
+    let result = ledger.pending_info_many(&txn, &[key2, missing1, key1, missing2]);
+
+    assert_eq!(result, [Some(info2), None, Some(info1), None]);
+}
+
+#[test]
+fn blocks_in_range() {
+    let mut chain = SavedAccountChain::new_opened_chain();
+    for _ in 0..4 {
+        chain.add_legacy_send();
+    }
+    let ledger = Ledger::new_null_builder()
+        .blocks(chain.blocks())
+        .account_info(&chain.account(), &chain.account_info())
+        .finish();
+    let txn = ledger.read_txn();
+
+    // walks forward from the open block
+    let blocks = ledger.blocks_in_range(&txn, &chain.account(), 2, 3);
+    assert_eq!(blocks, &chain.blocks()[1..3]);
+
+    // walks backward from the frontier
+    let blocks = ledger.blocks_in_range(&txn, &chain.account(), 3, 5);
+    assert_eq!(blocks, &chain.blocks()[2..5]);
+
+    let blocks = ledger.blocks_in_range(&txn, &chain.account(), 4, 100);
+    assert_eq!(blocks, &chain.blocks()[3..5]);
+}
+
 #[test]
 fn send_open_receive_vote_weight() {
     let ctx = LedgerContext::empty();
@@ -765,3 +924,74 @@ fn configured_peers_response() {
     let tx = ledger.read_txn();
     assert_eq!(ledger.store.peer.iter(&tx).next().unwrap(), (endpoint, now));
 }
+
+#[test]
+fn confirmed_balance() {
+    let ctx = LedgerContext::empty();
+    let mut txn = ctx.ledger.rw_txn();
+    let genesis_balance = ctx
+        .ledger
+        .any()
+        .account_balance(&txn, &DEV_GENESIS_ACCOUNT)
+        .unwrap();
+
+    let mut send = ctx.genesis_block_factory().send(&txn).build();
+    ctx.ledger.process(&mut txn, &mut send).unwrap();
+
+    let latest = ctx
+        .ledger
+        .any()
+        .account_balance(&txn, &DEV_GENESIS_ACCOUNT)
+        .unwrap();
+    let confirmed = ctx
+        .ledger
+        .confirmed_balance(&txn, &DEV_GENESIS_ACCOUNT)
+        .unwrap();
+    assert_eq!(confirmed, genesis_balance);
+    assert!(confirmed > latest);
+
+    ctx.ledger.confirm(&mut txn, send.hash());
+    assert_eq!(
+        ctx.ledger.confirmed_balance(&txn, &DEV_GENESIS_ACCOUNT),
+        Some(latest)
+    );
+}
+
+#[test]
+fn missing_block_cache() {
+    let ctx = LedgerContext::empty();
+    let mut txn = ctx.ledger.rw_txn();
+    let mut send = ctx.genesis_block_factory().send(&txn).build();
+
+    assert!(!ctx.ledger.any().block_exists_or_pruned(&txn, &send.hash()));
+    assert!(ctx.ledger.missing_blocks.contains(&send.hash()));
+    // Repeated lookups are answered by the cache
+    assert!(!ctx.ledger.any().block_exists_or_pruned(&txn, &send.hash()));
+    assert_eq!(ctx.ledger.missing_blocks.len(), 1);
+
+    ctx.ledger.process(&mut txn, &mut send).unwrap();
+
+    assert!(!ctx.ledger.missing_blocks.contains(&send.hash()));
+    assert!(ctx.ledger.any().block_exists_or_pruned(&txn, &send.hash()));
+}
+
+#[test]
+fn quick_reject_reason() {
+    let ctx = LedgerContext::empty();
+    let txn = ctx.ledger.read_txn();
+
+    // Proof of work is not checked
+    let send = ctx.genesis_block_factory().send(&txn).work(0).build();
+    assert_eq!(ctx.ledger.quick_reject_reason(&txn, &send), None);
+
+    let gap = ctx
+        .genesis_block_factory()
+        .send(&txn)
+        .previous(BlockHash::from(42))
+        .build();
+    assert_eq!(
+        ctx.ledger.quick_reject_reason(&txn, &gap),
+        Some(BlockStatus::GapPrevious)
+    );
+    assert!(!ctx.ledger.any().block_exists(&txn, &send.hash()));
+}
@@ -4,7 +4,8 @@ use crate::{
     ledger_tests::AccountBlockFactory,
     DEV_GENESIS_ACCOUNT, DEV_GENESIS_HASH,
 };
-use rsban_core::{Amount, Epoch, PendingInfo, PendingKey, PublicKey};
+use rsban_core::{Amount, BlockHash, Epoch, PendingInfo, PendingKey, PublicKey};
+use rsban_store_lmdb::LmdbWriteTransaction;
 
 #[test]
 fn rollback_send() {
@@ -119,6 +120,58 @@ fn rollback_received_send() {
     assert_eq!(ctx.ledger.store.account.count(&txn), 1);
 }
 
+#[test]
+fn rollback_deep_dependency_chain() {
+    let ctx = LedgerContext::empty();
+    let mut txn = ctx.ledger.rw_txn();
+    let (first_send, opens) = create_dependency_chain(&ctx, &mut txn, 5);
+
+    let rolled_back = ctx.ledger.rollback(&mut txn, &first_send).unwrap();
+
+    assert_eq!(rolled_back.len(), 10);
+    for open in opens {
+        assert_eq!(ctx.ledger.store.block.exists(&txn, &open), false);
+    }
+    assert_eq!(ctx.ledger.store.account.count(&txn), 1);
+}
+
+#[test]
+fn abort_rollback_when_max_depth_exceeded() {
+    let ctx = LedgerContext::empty();
+    let mut txn = ctx.ledger.rw_txn();
+    let (first_send, _) = create_dependency_chain(&ctx, &mut txn, 5);
+
+    ctx.ledger.set_rollback_max_depth(3);
+
+    let result = ctx.ledger.rollback(&mut txn, &first_send);
+
+    assert!(result.is_err());
+}
+
+/// Each account sends to a new account, which opens with that send. Rolling back
+/// the first send requires rolling back all accounts of the chain
+fn create_dependency_chain(
+    ctx: &LedgerContext,
+    txn: &mut LmdbWriteTransaction,
+    len: usize,
+) -> (BlockHash, Vec<BlockHash>) {
+    let mut sender = ctx.genesis_block_factory();
+    let mut first_send = None;
+    let mut opens = Vec::new();
+    for _ in 0..len {
+        let destination = AccountBlockFactory::new(&ctx.ledger);
+        let mut send = sender.send(&*txn).link(destination.account()).build();
+        ctx.ledger.process(txn, &mut send).unwrap();
+        first_send.get_or_insert(send.hash());
+
+        let mut open = destination.open(&*txn, send.hash()).build();
+        ctx.ledger.process(txn, &mut open).unwrap();
+        opens.push(open.hash());
+        sender = destination;
+    }
+    (first_send.unwrap(), opens)
+}
+
 #[test]
 fn rollback_rep_change() {
     let ctx = LedgerContext::empty();
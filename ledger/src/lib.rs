@@ -14,6 +14,7 @@ mod ledger_constants;
 mod ledger_context;
 mod ledger_set_any;
 mod ledger_set_confirmed;
+mod missing_block_cache;
 mod rep_weight_cache;
 mod rep_weights_updater;
 mod representative_block_finder;
@@ -32,6 +33,7 @@ pub use ledger_constants::{
 pub use ledger_context::LedgerContext;
 pub use ledger_set_any::*;
 pub use ledger_set_confirmed::*;
+pub use missing_block_cache::MissingBlockCache;
 pub use rep_weight_cache::*;
 pub use rep_weights_updater::*;
 pub(crate) use representative_block_finder::RepresentativeBlockFinder;
@@ -0,0 +1,147 @@
+use rsban_core::{utils::ContainerInfo, BlockHash};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+};
+
+/// Bounded LRU cache of block hashes that were recently found to be missing in the ledger.
+/// It allows skipping the database lookup for blocks that are checked repeatedly, for
+/// example gap sources. Entries must be erased when the block gets inserted.
+pub struct MissingBlockCache {
+    mutex: Mutex<MissingBlockCacheImpl>,
+    max_len: usize,
+}
+
+impl MissingBlockCache {
+    pub const DEFAULT_MAX_LEN: usize = 1024 * 16;
+
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            mutex: Mutex::new(MissingBlockCacheImpl {
+                by_hash: HashMap::new(),
+                by_usage: BTreeMap::new(),
+                next_usage: 0,
+            }),
+            max_len,
+        }
+    }
+
+    /// Returns true if the block is known to be missing and marks it as recently used
+    pub fn contains(&self, hash: &BlockHash) -> bool {
+        let mut guard = self.mutex.lock().unwrap();
+        if guard.by_hash.contains_key(hash) {
+            guard.touch(*hash);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn insert(&self, hash: BlockHash) {
+        if self.max_len == 0 {
+            return;
+        }
+        let mut guard = self.mutex.lock().unwrap();
+        guard.touch(hash);
+        while guard.by_hash.len() > self.max_len {
+            guard.evict_least_recently_used();
+        }
+    }
+
+    pub fn erase(&self, hash: &BlockHash) {
+        let mut guard = self.mutex.lock().unwrap();
+        if let Some(usage) = guard.by_hash.remove(hash) {
+            guard.by_usage.remove(&usage);
+        }
+    }
+
+    pub fn clear(&self) {
+        let mut guard = self.mutex.lock().unwrap();
+        guard.by_hash.clear();
+        guard.by_usage.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.mutex.lock().unwrap().by_hash.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn container_info(&self) -> ContainerInfo {
+        [(
+            "missing",
+            self.len(),
+            std::mem::size_of::<BlockHash>() * 2 + std::mem::size_of::<u64>() * 2,
+        )]
+        .into()
+    }
+}
+
+impl Default for MissingBlockCache {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_LEN)
+    }
+}
+
+struct MissingBlockCacheImpl {
+    by_hash: HashMap<BlockHash, u64>,
+    by_usage: BTreeMap<u64, BlockHash>,
+    next_usage: u64,
+}
+
+impl MissingBlockCacheImpl {
+    fn touch(&mut self, hash: BlockHash) {
+        let usage = self.next_usage;
+        self.next_usage += 1;
+        if let Some(old_usage) = self.by_hash.insert(hash, usage) {
+            self.by_usage.remove(&old_usage);
+        }
+        self.by_usage.insert(usage, hash);
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        if let Some((_, hash)) = self.by_usage.pop_first() {
+            self.by_hash.remove(&hash);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let cache = MissingBlockCache::new(2);
+        assert!(cache.is_empty());
+        assert!(!cache.contains(&BlockHash::from(1)));
+    }
+
+    #[test]
+    fn insert_and_erase() {
+        let cache = MissingBlockCache::new(2);
+        cache.insert(BlockHash::from(1));
+        assert!(cache.contains(&BlockHash::from(1)));
+
+        cache.erase(&BlockHash::from(1));
+        assert!(!cache.contains(&BlockHash::from(1)));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn evict_least_recently_used() {
+        let cache = MissingBlockCache::new(2);
+        cache.insert(BlockHash::from(1));
+        cache.insert(BlockHash::from(2));
+        // Mark 1 as recently used, so that 2 gets evicted
+        assert!(cache.contains(&BlockHash::from(1)));
+        cache.insert(BlockHash::from(3));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(&BlockHash::from(1)));
+        assert!(!cache.contains(&BlockHash::from(2)));
+        assert!(cache.contains(&BlockHash::from(3)));
+    }
+}
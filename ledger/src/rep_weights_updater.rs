@@ -1,4 +1,4 @@
-use crate::RepWeightCache;
+use crate::{LedgerObserver, NullLedgerObserver, RepWeightCache};
 use rsban_core::{Amount, PublicKey};
 use rsban_store_lmdb::{LmdbRepWeightStore, LmdbWriteTransaction};
 use std::collections::HashMap;
@@ -9,6 +9,7 @@ pub struct RepWeightsUpdater {
     weight_cache: Arc<RwLock<HashMap<PublicKey, Amount>>>,
     store: Arc<LmdbRepWeightStore>,
     min_weight: Amount,
+    observer: Arc<dyn LedgerObserver>,
 }
 
 impl RepWeightsUpdater {
@@ -17,9 +18,14 @@ impl RepWeightsUpdater {
             weight_cache: cache.inner(),
             store,
             min_weight,
+            observer: Arc::new(NullLedgerObserver::new()),
         }
     }
 
+    pub fn set_observer(&mut self, observer: Arc<dyn LedgerObserver>) {
+        self.observer = observer;
+    }
+
     /// Only use this method when loading rep weights from the database table
     pub fn copy_from(&self, other: &HashMap<PublicKey, Amount>) {
         let mut guard_this = self.weight_cache.write().unwrap();
@@ -41,9 +47,42 @@ impl RepWeightsUpdater {
     ) {
         let previous_weight = self.store.get(tx, &representative).unwrap_or_default();
         let new_weight = previous_weight.wrapping_add(amount);
+        self.set_weight(tx, representative, previous_weight, new_weight);
+    }
+
+    /// Fails without changing anything if the weight of the representative would become negative
+    pub fn representation_sub(
+        &self,
+        tx: &mut LmdbWriteTransaction,
+        representative: PublicKey,
+        amount: Amount,
+    ) -> anyhow::Result<()> {
+        let previous_weight = self.store.get(tx, &representative).unwrap_or_default();
+        let new_weight = checked_weight_sub(&representative, previous_weight, amount)?;
+        self.set_weight(tx, representative, previous_weight, new_weight);
+        Ok(())
+    }
+
+    fn set_weight(
+        &self,
+        tx: &mut LmdbWriteTransaction,
+        representative: PublicKey,
+        previous_weight: Amount,
+        new_weight: Amount,
+    ) {
         self.put_store(tx, representative, previous_weight, new_weight);
-        let mut guard = self.weight_cache.write().unwrap();
-        self.put_cache(&mut guard, representative, new_weight);
+        {
+            let mut guard = self.weight_cache.write().unwrap();
+            self.put_cache(&mut guard, representative, new_weight);
+        }
+        self.notify_change(&representative, previous_weight, new_weight);
+    }
+
+    fn notify_change(&self, representative: &PublicKey, old_weight: Amount, new_weight: Amount) {
+        if old_weight != new_weight {
+            self.observer
+                .representative_weight_changed(representative, old_weight, new_weight);
+        }
     }
 
     fn put_cache(
@@ -94,21 +133,87 @@ impl RepWeightsUpdater {
             let previous_weight_2 = self.store.get(tx, &rep_2).unwrap_or_default();
             let new_weight_1 = previous_weight_1.wrapping_add(amount_1);
             let new_weight_2 = previous_weight_2.wrapping_add(amount_2);
-            self.put_store(tx, rep_1, previous_weight_1, new_weight_1);
-            self.put_store(tx, rep_2, previous_weight_2, new_weight_2);
+            self.set_weights_dual(
+                tx,
+                (rep_1, previous_weight_1, new_weight_1),
+                (rep_2, previous_weight_2, new_weight_2),
+            );
+        } else {
+            self.representation_add(tx, rep_1, amount_1.wrapping_add(amount_2));
+        }
+    }
+
+    /// Removes `old_amount` from `old_rep` and adds `new_amount` to `new_rep`.
+    /// Fails without changing anything if the weight of `old_rep` would become negative
+    pub fn representation_move(
+        &self,
+        tx: &mut LmdbWriteTransaction,
+        old_rep: PublicKey,
+        old_amount: Amount,
+        new_rep: PublicKey,
+        new_amount: Amount,
+    ) -> anyhow::Result<()> {
+        if old_rep != new_rep {
+            let previous_weight_1 = self.store.get(tx, &old_rep).unwrap_or_default();
+            let previous_weight_2 = self.store.get(tx, &new_rep).unwrap_or_default();
+            let new_weight_1 = checked_weight_sub(&old_rep, previous_weight_1, old_amount)?;
+            let new_weight_2 = previous_weight_2.wrapping_add(new_amount);
+            self.set_weights_dual(
+                tx,
+                (old_rep, previous_weight_1, new_weight_1),
+                (new_rep, previous_weight_2, new_weight_2),
+            );
+        } else {
+            let previous_weight = self.store.get(tx, &old_rep).unwrap_or_default();
+            let new_weight = checked_weight_sub(
+                &old_rep,
+                previous_weight.wrapping_add(new_amount),
+                old_amount,
+            )?;
+            self.set_weight(tx, old_rep, previous_weight, new_weight);
+        }
+        Ok(())
+    }
+
+    /// Each update is (representative, previous weight, new weight)
+    fn set_weights_dual(
+        &self,
+        tx: &mut LmdbWriteTransaction,
+        update_1: (PublicKey, Amount, Amount),
+        update_2: (PublicKey, Amount, Amount),
+    ) {
+        let (rep_1, previous_weight_1, new_weight_1) = update_1;
+        let (rep_2, previous_weight_2, new_weight_2) = update_2;
+        self.put_store(tx, rep_1, previous_weight_1, new_weight_1);
+        self.put_store(tx, rep_2, previous_weight_2, new_weight_2);
+        {
             let mut guard = self.weight_cache.write().unwrap();
             self.put_cache(&mut guard, rep_1, new_weight_1);
             self.put_cache(&mut guard, rep_2, new_weight_2);
-        } else {
-            self.representation_add(tx, rep_1, amount_1.wrapping_add(amount_2));
         }
+        self.notify_change(&rep_1, previous_weight_1, new_weight_1);
+        self.notify_change(&rep_2, previous_weight_2, new_weight_2);
     }
 }
 
+fn checked_weight_sub(
+    representative: &PublicKey,
+    weight: Amount,
+    amount: Amount,
+) -> anyhow::Result<Amount> {
+    weight.checked_sub(amount).ok_or_else(|| {
+        anyhow!(
+            "weight of representative {} would become negative",
+            representative.as_account().encode_account()
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rsban_store_lmdb::{ConfiguredRepWeightDatabaseBuilder, LmdbEnv};
+    use std::sync::Mutex;
 
     #[test]
     fn representation_changes() {
@@ -238,4 +343,108 @@ mod tests {
         assert_eq!(rep_weights.len(), 0);
         assert_eq!(put_tracker.output(), vec![(representative, 9.into())]);
     }
+
+    #[test]
+    fn notify_both_representatives_of_change() {
+        let old_rep = PublicKey::from(1);
+        let new_rep = PublicKey::from(2);
+        let balance = Amount::from(100);
+        let env = Arc::new(
+            LmdbEnv::new_null_with()
+                .configured_database(ConfiguredRepWeightDatabaseBuilder::create(vec![(
+                    old_rep, balance,
+                )]))
+                .build(),
+        );
+        let store = Arc::new(LmdbRepWeightStore::new(Arc::clone(&env)).unwrap());
+        let rep_weights = RepWeightCache::new();
+        let mut rep_weights_updater = RepWeightsUpdater::new(store, Amount::zero(), &rep_weights);
+        let observer = Arc::new(WeightChangeRecorder::default());
+        rep_weights_updater.set_observer(observer.clone());
+        let mut txn = env.tx_begin_write();
+
+        // Move the account's balance from the old to the new representative
+        rep_weights_updater.representation_add_dual(
+            &mut txn,
+            old_rep,
+            Amount::zero().wrapping_sub(balance),
+            new_rep,
+            balance,
+        );
+
+        assert_eq!(
+            *observer.changes.lock().unwrap(),
+            vec![
+                (old_rep, balance, Amount::zero()),
+                (new_rep, Amount::zero(), balance)
+            ]
+        );
+    }
+
+    #[test]
+    fn move_representation() {
+        let old_rep = PublicKey::from(1);
+        let new_rep = PublicKey::from(2);
+        let env = Arc::new(
+            LmdbEnv::new_null_with()
+                .configured_database(ConfiguredRepWeightDatabaseBuilder::create(vec![(
+                    old_rep,
+                    Amount::from(100),
+                )]))
+                .build(),
+        );
+        let store = Arc::new(LmdbRepWeightStore::new(Arc::clone(&env)).unwrap());
+        let put_tracker = store.track_puts();
+        let rep_weights = RepWeightCache::new();
+        let rep_weights_updater = RepWeightsUpdater::new(store, Amount::zero(), &rep_weights);
+        let mut txn = env.tx_begin_write();
+
+        rep_weights_updater
+            .representation_move(
+                &mut txn,
+                old_rep,
+                Amount::from(30),
+                new_rep,
+                Amount::from(20),
+            )
+            .unwrap();
+
+        assert_eq!(
+            put_tracker.output(),
+            vec![(old_rep, 70.into()), (new_rep, 20.into())]
+        );
+    }
+
+    #[test]
+    fn fail_when_weight_becomes_negative() {
+        let env = Arc::new(LmdbEnv::new_null());
+        let store = Arc::new(LmdbRepWeightStore::new(Arc::clone(&env)).unwrap());
+        let rep_weights = RepWeightCache::new();
+        let rep_weights_updater = RepWeightsUpdater::new(store, Amount::zero(), &rep_weights);
+        let mut txn = env.tx_begin_write();
+
+        let result =
+            rep_weights_updater.representation_sub(&mut txn, PublicKey::from(1), Amount::from(1));
+
+        assert!(result.is_err());
+    }
+
+    #[derive(Default)]
+    struct WeightChangeRecorder {
+        changes: Mutex<Vec<(PublicKey, Amount, Amount)>>,
+    }
+
+    impl LedgerObserver for WeightChangeRecorder {
+        fn representative_weight_changed(
+            &self,
+            representative: &PublicKey,
+            old_weight: Amount,
+            new_weight: Amount,
+        ) {
+            self.changes
+                .lock()
+                .unwrap()
+                .push((*representative, old_weight, new_weight));
+        }
+    }
 }
@@ -174,6 +174,17 @@ impl Display for AscPullReq {
 }
 
 impl AscPullReq {
+    /// Request for the account info of an account or of the account which owns the given block
+    pub fn account_info(id: u64, target: impl Into<HashOrAccount>, target_type: HashType) -> Self {
+        Self {
+            id,
+            req_type: AscPullReqType::AccountInfo(AccountInfoReqPayload {
+                target: target.into(),
+                target_type,
+            }),
+        }
+    }
+
     pub fn new_test_instance_blocks() -> Self {
         Self {
             id: 12345,
@@ -250,6 +261,7 @@ impl MessageVariant for AscPullReq {
 mod tests {
     use super::*;
     use crate::{assert_deserializable, Message};
+    use rsban_core::BlockHash;
 
     #[test]
     fn serialize_blocks() {
@@ -278,6 +290,20 @@ mod tests {
         assert_deserializable(&original);
     }
 
+    #[test]
+    fn account_info_request() {
+        let request = AscPullReq::account_info(7, BlockHash::from(123), HashType::Block);
+
+        assert_eq!(
+            request.req_type,
+            AscPullReqType::AccountInfo(AccountInfoReqPayload {
+                target: HashOrAccount::from(123),
+                target_type: HashType::Block,
+            })
+        );
+        assert_deserializable(&Message::AscPullReq(request));
+    }
+
     #[test]
     fn serialize_frontiers() {
         let original = Message::AscPullReq(AscPullReq {
@@ -111,11 +111,48 @@ impl Display for BulkPull {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{assert_deserializable, Message};
+    use crate::{assert_deserializable, Message, MessageHeader, MessageSerializer};
 
     #[test]
     fn bulk_pull_serialization() {
         let message = Message::BulkPull(BulkPull::new_test_instance());
         assert_deserializable(&message);
     }
+
+    #[test]
+    fn bounded_count() {
+        let request = BulkPull {
+            count: 10,
+            ..BulkPull::new_test_instance()
+        };
+
+        let header = serialized_header(&request);
+
+        assert!(header.extensions[BulkPull::COUNT_PRESENT_FLAG]);
+        assert_eq!(
+            header.payload_length(),
+            64 + BulkPull::EXTENDED_PARAMETERS_SIZE
+        );
+        assert_deserializable(&Message::BulkPull(request));
+    }
+
+    #[test]
+    fn unbounded_count() {
+        let request = BulkPull {
+            count: 0,
+            ..BulkPull::new_test_instance()
+        };
+
+        let header = serialized_header(&request);
+
+        assert!(!header.extensions[BulkPull::COUNT_PRESENT_FLAG]);
+        assert_eq!(header.payload_length(), 64);
+        assert_deserializable(&Message::BulkPull(request));
+    }
+
+    fn serialized_header(request: &BulkPull) -> MessageHeader {
+        let mut serializer = MessageSerializer::default();
+        let serialized = serializer.serialize(&Message::BulkPull(request.clone()));
+        MessageHeader::deserialize_slice(serialized).unwrap()
+    }
 }
@@ -2,7 +2,7 @@ use super::{ConfirmReq, MessageVariant};
 use bitvec::prelude::BitArray;
 use rsban_core::{
     utils::{BufferWriter, Serialize, Stream},
-    Vote,
+    BlockHash, Vote,
 };
 use std::fmt::{Debug, Display};
 /*
@@ -50,6 +50,14 @@ impl ConfirmAck {
         }
     }
 
+    /// Splits the hashes into batches that fit into a single confirm_ack
+    pub fn split_hashes(hashes: &[BlockHash]) -> Vec<Vec<BlockHash>> {
+        hashes
+            .chunks(Self::HASHES_MAX)
+            .map(|chunk| chunk.to_vec())
+            .collect()
+    }
+
     pub fn vote(&self) -> &Vote {
         &self.vote
     }
@@ -120,7 +128,7 @@ impl Display for ConfirmAck {
 mod tests {
     use super::*;
     use crate::{assert_deserializable, Message};
-    use rsban_core::{utils::MemoryStream, BlockHash, PrivateKey};
+    use rsban_core::{utils::MemoryStream, PrivateKey};
 
     #[test]
     fn serialize_v1() {
@@ -154,6 +162,24 @@ mod tests {
         Message::ConfirmAck(ConfirmAck::new_with_own_vote(vote));
     }
 
+    #[test]
+    fn split_hashes() {
+        let hashes: Vec<_> = (0..ConfirmAck::HASHES_MAX as u64 + 1)
+            .map(BlockHash::from)
+            .collect();
+
+        let batches = ConfirmAck::split_hashes(&hashes);
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].len(), ConfirmAck::HASHES_MAX);
+        assert_eq!(batches[1], [BlockHash::from(ConfirmAck::HASHES_MAX as u64)]);
+    }
+
+    #[test]
+    fn split_empty_hashes() {
+        assert!(ConfirmAck::split_hashes(&[]).is_empty());
+    }
+
     #[test]
     fn rebroadcasted_vote() {
         let ack = ConfirmAck::new_with_rebroadcasted_vote(Vote::new_test_instance());
@@ -5,7 +5,7 @@ use num_traits::FromPrimitive;
 use rsban_core::{
     serialized_block_size,
     utils::{BufferWriter, Deserialize, FixedSizeSerialize, Serialize, Stream},
-    BlockHash, BlockType, Root,
+    Block, BlockHash, BlockType, Root,
 };
 use serde::ser::{SerializeSeq, SerializeStruct};
 use std::fmt::{Debug, Display, Write};
@@ -13,6 +13,7 @@ use std::fmt::{Debug, Display, Write};
 /*
  * Binary Format:
  * [message_header] Common message header
+ * [block] Only present in hybrid requests. The block type is determined by the header's block type bits.
  * [N x (32 bytes (block hash) + 32 bytes (root))] Pairs of (block_hash, root)
  * - The count is determined by the header's count bits.
  *
@@ -23,10 +24,13 @@ use std::fmt::{Debug, Display, Write};
  * - [0xf000 (high), 0x00f0 (low)] Count V2 (for V2 protocol)
  * - [0x0001] Confirm V2 flag
  * - [0x0002] Reserved for V3+ versioning
+ * - [0x0004] Hybrid flag: a block precedes the roots hashes
  */
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct ConfirmReq {
     pub roots_hashes: Vec<(BlockHash, Root)>,
+    /// Block that should be confirmed in addition to the roots hashes
+    pub block: Option<Block>,
 }
 
 impl ConfirmReq {
@@ -41,13 +45,25 @@ impl ConfirmReq {
     const COUNT_LOW_MASK: u16 = 0b0000_0000_1111_0000;
     const COUNT_LOW_SHIFT: u16 = 4;
     const V2_FLAG: u16 = 0b0000_0000_0000_0001;
+    const HYBRID_FLAG: u16 = 0b0000_0000_0000_0100;
     // ----------------------
 
     pub fn new(roots_hashes: Vec<(BlockHash, Root)>) -> Self {
         if roots_hashes.len() > u8::MAX as usize {
             panic!("roots_hashes too big");
         }
-        Self { roots_hashes }
+        Self {
+            roots_hashes,
+            block: None,
+        }
+    }
+
+    /// Requests the confirmation of a block together with additional roots hashes
+    pub fn new_hybrid(block: Block, roots_hashes: Vec<(BlockHash, Root)>) -> Self {
+        Self {
+            block: Some(block),
+            ..Self::new(roots_hashes)
+        }
     }
 
     pub fn new_test_instance() -> Self {
@@ -72,12 +88,21 @@ impl ConfirmReq {
     }
 
     pub fn deserialize(stream: &mut impl Stream, extensions: BitArray<u16>) -> Option<Self> {
-        Some(Self::new(Self::deserialize_roots(stream, extensions).ok()?))
+        if Self::has_hybrid_flag(extensions) {
+            let block = Block::deserialize_block_type(Self::block_type(extensions), stream).ok()?;
+            let roots_hashes = Self::deserialize_roots(stream, extensions, true).ok()?;
+            Some(Self::new_hybrid(block, roots_hashes))
+        } else {
+            Some(Self::new(
+                Self::deserialize_roots(stream, extensions, false).ok()?,
+            ))
+        }
     }
 
     fn deserialize_roots(
         stream: &mut impl Stream,
         extensions: BitArray<u16>,
+        allow_empty: bool,
     ) -> Result<Vec<(BlockHash, Root)>> {
         let count = Self::count(extensions) as usize;
         let mut roots_hashes = Vec::with_capacity(count);
@@ -89,7 +114,7 @@ impl ConfirmReq {
             }
         }
 
-        if roots_hashes.is_empty() || roots_hashes.len() != count {
+        if (roots_hashes.is_empty() && !allow_empty) || roots_hashes.len() != count {
             bail!("roots hashes empty or incorrect count");
         }
 
@@ -108,7 +133,10 @@ impl ConfirmReq {
         let count = Self::count(extensions);
         let mut result = 0;
         let block_type = Self::block_type(extensions);
-        if block_type != BlockType::Invalid && block_type != BlockType::NotABlock {
+        if Self::has_hybrid_flag(extensions) {
+            result = serialized_block_size(block_type)
+                + count as usize * (BlockHash::serialized_size() + Root::serialized_size());
+        } else if block_type != BlockType::Invalid && block_type != BlockType::NotABlock {
             result = serialized_block_size(block_type);
         } else if block_type == BlockType::NotABlock {
             result = count as usize * (BlockHash::serialized_size() + Root::serialized_size());
@@ -153,6 +181,10 @@ impl ConfirmReq {
         bits.data & Self::V2_FLAG == Self::V2_FLAG
     }
 
+    fn has_hybrid_flag(bits: BitArray<u16>) -> bool {
+        bits.data & Self::HYBRID_FLAG == Self::HYBRID_FLAG
+    }
+
     fn v1_count(bits: BitArray<u16>) -> u8 {
         ((bits.data & Self::COUNT_HIGH_MASK) >> Self::COUNT_HIGH_SHIFT) as u8
     }
@@ -166,6 +198,9 @@ impl ConfirmReq {
 
 impl Serialize for ConfirmReq {
     fn serialize(&self, writer: &mut dyn BufferWriter) {
+        if let Some(block) = &self.block {
+            block.serialize_without_block_type(writer);
+        }
         for (hash, root) in &self.roots_hashes {
             writer.write_bytes_safe(hash.as_bytes());
             writer.write_bytes_safe(root.as_bytes());
@@ -208,7 +243,12 @@ impl serde::Serialize for ConfirmReq {
         S: serde::Serializer,
     {
         let mut state = serializer.serialize_struct("ConfirmReq", 6)?;
-        state.serialize_field("confirm_type", "roots_hashes")?;
+        if let Some(block) = &self.block {
+            state.serialize_field("confirm_type", "hybrid")?;
+            state.serialize_field("block", block)?;
+        } else {
+            state.serialize_field("confirm_type", "roots_hashes")?;
+        }
         state.serialize_field("roots_hashes", &SerializableRootsHashes(&self.roots_hashes))?;
         state.end()
     }
@@ -218,15 +258,23 @@ impl MessageVariant for ConfirmReq {
     fn header_extensions(&self, _payload_len: u16) -> BitArray<u16> {
         let mut extensions = BitArray::default();
         extensions |= Self::count_bits(self.roots_hashes.len() as u8);
-        // Set NotABlock (1) block type for hashes + roots request
-        // This is needed to keep compatibility with previous protocol versions (<= V25.1)
-        extensions |= BitArray::new((BlockType::NotABlock as u16) << Self::BLOCK_TYPE_SHIFT);
+        if let Some(block) = &self.block {
+            extensions |= BitArray::new((block.block_type() as u16) << Self::BLOCK_TYPE_SHIFT);
+            extensions |= BitArray::new(Self::HYBRID_FLAG);
+        } else {
+            // Set NotABlock (1) block type for hashes + roots request
+            // This is needed to keep compatibility with previous protocol versions (<= V25.1)
+            extensions |= BitArray::new((BlockType::NotABlock as u16) << Self::BLOCK_TYPE_SHIFT);
+        }
         extensions
     }
 }
 
 impl Display for ConfirmReq {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(block) = &self.block {
+            write!(f, "\nblock: {}", block.hash())?;
+        }
         for (hash, root) in &self.roots_hashes {
             write!(f, "\n{}:{}", hash, root)?;
         }
@@ -332,6 +380,45 @@ mod tests {
         assert_deserializable(&confirm_req);
     }
 
+    #[test]
+    fn serialize_hybrid() {
+        let roots_hashes = vec![
+            (BlockHash::from(1), Root::from(2)),
+            (BlockHash::from(3), Root::from(4)),
+        ];
+        let confirm_req = Message::ConfirmReq(ConfirmReq::new_hybrid(
+            Block::new_test_instance(),
+            roots_hashes,
+        ));
+        assert_deserializable(&confirm_req);
+    }
+
+    #[test]
+    fn serialize_hybrid_without_roots_hashes() {
+        let confirm_req = Message::ConfirmReq(ConfirmReq::new_hybrid(
+            Block::new_test_instance(),
+            Vec::new(),
+        ));
+        assert_deserializable(&confirm_req);
+    }
+
+    #[test]
+    fn hybrid_extensions() {
+        let block = Block::new_test_instance();
+        let block_type = block.block_type();
+        let confirm_req = ConfirmReq::new_hybrid(block, vec![(BlockHash::from(1), Root::from(2))]);
+        let extensions = confirm_req.header_extensions(0);
+        assert!(ConfirmReq::has_hybrid_flag(extensions));
+        assert_eq!(ConfirmReq::block_type(extensions), block_type);
+        assert_eq!(ConfirmReq::count(extensions), 1);
+    }
+
+    #[test]
+    fn roots_hashes_request_has_no_hybrid_flag() {
+        let extensions = ConfirmReq::new_test_instance().header_extensions(0);
+        assert!(!ConfirmReq::has_hybrid_flag(extensions));
+    }
+
     #[test]
     #[should_panic]
     fn panics_when_roots_hashes_are_too_big() {
@@ -49,6 +49,17 @@ impl Keepalive {
     pub fn serialized_size() -> usize {
         8 * (16 + 2)
     }
+
+    /// All peers which are not the null endpoint `[::]`
+    pub fn non_null_peers(&self) -> impl Iterator<Item = &SocketAddrV6> {
+        self.peers.iter().filter(|p| !p.ip().is_unspecified())
+    }
+
+    /// JSON array of the non null peers
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        let peers: Vec<String> = self.non_null_peers().map(|p| p.to_string()).collect();
+        serde_json::to_string(&peers)
+    }
 }
 
 impl Default for Keepalive {
@@ -75,7 +86,7 @@ impl Serialize for Keepalive {
 
 impl Display for Keepalive {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for peer in &self.peers {
+        for peer in self.non_null_peers() {
             write!(f, "\n{}", peer)?;
         }
         Ok(())
@@ -110,8 +121,7 @@ mod tests {
     #[test]
     fn keepalive_with_no_peers_to_string() {
         let keepalive = Message::Keepalive(Default::default());
-        let expected = "\n[::]:0\n[::]:0\n[::]:0\n[::]:0\n[::]:0\n[::]:0\n[::]:0\n[::]:0";
-        assert_eq!(keepalive.to_string(), expected);
+        assert_eq!(keepalive.to_string(), "");
     }
 
     #[test]
@@ -135,10 +145,8 @@ mod tests {
             SocketAddrV6::new(Ipv6Addr::from_str("::ffff:1.2.3.4").unwrap(), 1234, 0, 0);
 
         let mut expected = String::new();
-        expected.push_str("\n[::]:0");
         expected.push_str("\n[::1]:45");
         expected.push_str("\n[2001:db8:85a3:8d3:1319:8a2e:370:7348]:0");
-        expected.push_str("\n[::]:65535");
         expected.push_str("\n[::ffff:1.2.3.4]:1234");
         expected.push_str("\n[::ffff:1.2.3.4]:1234");
         expected.push_str("\n[::ffff:1.2.3.4]:1234");
@@ -146,4 +154,22 @@ mod tests {
 
         assert_eq!(keepalive.to_string(), expected);
     }
+
+    #[test]
+    fn to_json_omits_null_peers() {
+        let mut keepalive = Keepalive::default();
+        keepalive.peers[1] = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 45, 0, 0);
+        keepalive.peers[5] =
+            SocketAddrV6::new(Ipv6Addr::from_str("::ffff:1.2.3.4").unwrap(), 1234, 0, 0);
+
+        assert_eq!(
+            keepalive.to_json().unwrap(),
+            r#"["[::1]:45","[::ffff:1.2.3.4]:1234"]"#
+        );
+    }
+
+    #[test]
+    fn empty_keepalive_to_json() {
+        assert_eq!(Keepalive::default().to_json().unwrap(), "[]");
+    }
 }
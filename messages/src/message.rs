@@ -1,7 +1,7 @@
 use super::*;
 use bitvec::prelude::BitArray;
 use rsban_core::utils::{BufferReader, BufferWriter, Serialize};
-use std::fmt::Display;
+use std::fmt::{Display, Write};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Message {
@@ -146,6 +146,49 @@ impl Message {
 
         Some(msg)
     }
+
+    /// Annotated hex view of the serialized message, one header field per line
+    /// followed by the payload in rows of 16 bytes. The header is serialized
+    /// with the default protocol info
+    pub fn hex_dump(&self) -> String {
+        let mut serializer = MessageSerializer::default();
+        let bytes = serializer.serialize(self);
+        let type_label = format!("message_type ({})", self.message_type().as_str());
+        let header_fields = [
+            (0, 2, "network"),
+            (2, 1, "version_max"),
+            (3, 1, "version_using"),
+            (4, 1, "version_min"),
+            (5, 1, type_label.as_str()),
+            (6, 2, "extensions"),
+        ];
+
+        let mut dump = String::new();
+        for (offset, len, label) in header_fields {
+            write_hex_line(&mut dump, offset, &bytes[offset..offset + len], label);
+        }
+        let payload = &bytes[MessageHeader::SERIALIZED_SIZE..];
+        for (i, row) in payload.chunks(16).enumerate() {
+            let label = if i == 0 { "payload" } else { "" };
+            write_hex_line(
+                &mut dump,
+                MessageHeader::SERIALIZED_SIZE + i * 16,
+                row,
+                label,
+            );
+        }
+        dump
+    }
+}
+
+fn write_hex_line(dump: &mut String, offset: usize, bytes: &[u8], label: &str) {
+    let hex = bytes
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let line = format!("{:04X}  {:<47}  {}", offset, hex, label);
+    let _ = writeln!(dump, "{}", line.trim_end());
 }
 
 impl Display for Message {
@@ -179,6 +222,22 @@ mod tests {
     use super::*;
     use rsban_core::{TestBlockBuilder, Vote};
 
+    #[test]
+    fn hex_dump() {
+        let message = Message::Keepalive(Keepalive::default());
+
+        let dump = message.hex_dump();
+
+        let lines: Vec<_> = dump.lines().collect();
+        let network = (ProtocolInfo::default().network as u16).to_be_bytes();
+        assert!(lines[0].starts_with(&format!("0000  {:02X} {:02X} ", network[0], network[1])));
+        assert!(lines[0].ends_with("network"));
+        assert!(lines[4].starts_with("0005  02 "));
+        assert!(lines[4].ends_with("message_type (keepalive)"));
+        assert!(lines[6].starts_with("0008  "));
+        assert!(lines[6].ends_with("payload"));
+    }
+
     #[test]
     fn exact_confirm_ack() {
         let message = Message::ConfirmAck(ConfirmAck::new_with_own_vote(Vote::new_test_instance()));
@@ -37,12 +37,69 @@ impl MessageSerializer {
             header.extensions = message.header_extensions(payload_len as u16);
             header.serialize(&mut header_writer);
         }
-        &self.buffer[..MessageHeader::SERIALIZED_SIZE + payload_len]
+        let serialized = &self.buffer[..MessageHeader::SERIALIZED_SIZE + payload_len];
+        #[cfg(all(debug_assertions, any(test, feature = "verify_serialization")))]
+        verify_round_trip(message, serialized);
+        serialized
     }
 }
 
+/// Parses a serialized message again and panics if the result differs from
+/// the original. This catches asymmetric serialize/deserialize implementations
+#[cfg(any(test, all(debug_assertions, feature = "verify_serialization")))]
+fn verify_round_trip(original: &Message, serialized: &[u8]) {
+    use rsban_core::utils::BufferReader;
+
+    let mut reader = BufferReader::new(serialized);
+    let header = MessageHeader::deserialize(&mut reader)
+        .unwrap_or_else(|e| panic!("could not parse serialized header: {e:?}"));
+    let payload = reader.remaining();
+    assert_eq!(
+        header.payload_length(),
+        payload.len(),
+        "serialized {:?} message has incorrect payload length",
+        original.message_type()
+    );
+    let parsed = Message::deserialize(payload, &header, 0).unwrap_or_else(|| {
+        panic!(
+            "could not parse serialized {:?} message",
+            original.message_type()
+        )
+    });
+    assert_eq!(
+        parsed, *original,
+        "serialized message differs from original after round trip"
+    );
+}
+
 impl Default for MessageSerializer {
     fn default() -> Self {
         Self::new(ProtocolInfo::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Keepalive;
+
+    #[test]
+    fn round_trip_succeeds() {
+        let message = Message::Keepalive(Keepalive::new_test_instance());
+        let mut serializer = MessageSerializer::default();
+        let serialized = serializer.serialize(&message).to_vec();
+        verify_round_trip(&message, &serialized);
+    }
+
+    #[test]
+    #[should_panic(expected = "differs from original")]
+    fn round_trip_fails_for_broken_serialization() {
+        let message = Message::Keepalive(Keepalive::new_test_instance());
+        let mut serializer = MessageSerializer::default();
+        let mut serialized = serializer.serialize(&message).to_vec();
+        // Simulate a broken encoder that writes a wrong port
+        let last = serialized.len() - 1;
+        serialized[last] ^= 0xff;
+        verify_round_trip(&message, &serialized);
+    }
+}
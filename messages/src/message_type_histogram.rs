@@ -0,0 +1,62 @@
+use super::{Message, MessageType, MessageVisitor};
+use std::collections::HashMap;
+
+/// Counts how many messages of each type were received
+#[derive(Default)]
+pub struct MessageTypeHistogramVisitor {
+    counts: HashMap<MessageType, u64>,
+}
+
+impl MessageTypeHistogramVisitor {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn histogram(&self) -> HashMap<MessageType, u64> {
+        self.counts.clone()
+    }
+
+    pub fn count(&self, message_type: MessageType) -> u64 {
+        self.counts.get(&message_type).cloned().unwrap_or_default()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+}
+
+impl MessageVisitor for MessageTypeHistogramVisitor {
+    fn received(&mut self, message: &Message) {
+        *self.counts.entry(message.message_type()).or_default() += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Keepalive;
+
+    #[test]
+    fn empty() {
+        let visitor = MessageTypeHistogramVisitor::new();
+        assert!(visitor.histogram().is_empty());
+        assert_eq!(visitor.total(), 0);
+    }
+
+    #[test]
+    fn count_message_types() {
+        let mut visitor = MessageTypeHistogramVisitor::new();
+        visitor.received(&Message::Keepalive(Keepalive::default()));
+        visitor.received(&Message::BulkPush);
+        visitor.received(&Message::Keepalive(Keepalive::default()));
+        visitor.received(&Message::TelemetryReq);
+
+        let histogram = visitor.histogram();
+        assert_eq!(histogram.len(), 3);
+        assert_eq!(histogram.get(&MessageType::Keepalive), Some(&2));
+        assert_eq!(histogram.get(&MessageType::BulkPush), Some(&1));
+        assert_eq!(histogram.get(&MessageType::TelemetryReq), Some(&1));
+        assert_eq!(visitor.count(MessageType::Publish), 0);
+        assert_eq!(visitor.total(), 4);
+    }
+}
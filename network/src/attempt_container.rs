@@ -79,6 +79,10 @@ impl AttemptContainer {
         }
     }
 
+    pub fn contains(&self, endpoint: &SocketAddrV6) -> bool {
+        self.by_endpoint.contains_key(endpoint)
+    }
+
     pub fn count_by_subnetwork(&self, subnet: &Ipv6Addr) -> usize {
         // TODO use map_address_to_subnetwork
         match self.by_subnetwork.get(subnet) {
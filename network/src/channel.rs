@@ -3,7 +3,7 @@ use crate::{
     utils::into_ipv6_socket_address,
     write_queue::{WriteQueue, WriteQueueReceiver},
     AsyncBufferReader, ChannelDirection, ChannelId, ChannelInfo, DropPolicy, NetworkObserver,
-    NullNetworkObserver, TrafficType, WriteQueueAdapter,
+    NullNetworkObserver, TrafficPolicyConfig, TrafficType, WriteQueueAdapter,
 };
 use async_trait::async_trait;
 use rsban_core::utils::{TEST_ENDPOINT_1, TEST_ENDPOINT_2};
@@ -12,7 +12,7 @@ use rsban_nullable_tcp::TcpStream;
 use std::{
     fmt::Display,
     net::{Ipv6Addr, SocketAddrV6},
-    sync::{Arc, Weak},
+    sync::{Arc, RwLock, Weak},
     time::Duration,
 };
 use tokio::{select, time::sleep};
@@ -23,6 +23,7 @@ pub struct Channel {
     channel_id: ChannelId,
     pub info: Arc<ChannelInfo>,
     limiter: Arc<BandwidthLimiter>,
+    traffic_policy: Arc<RwLock<TrafficPolicyConfig>>,
     write_queue: Arc<WriteQueue>,
     stream: Weak<TcpStream>,
     clock: Arc<SteadyClock>,
@@ -32,11 +33,13 @@ pub struct Channel {
 
 impl Channel {
     const MAX_QUEUE_SIZE: usize = 128;
+    const SEND_RETRY_INTERVAL: Duration = Duration::from_millis(20);
 
     fn new(
         channel_info: Arc<ChannelInfo>,
         stream: Weak<TcpStream>,
         limiter: Arc<BandwidthLimiter>,
+        traffic_policy: Arc<RwLock<TrafficPolicyConfig>>,
         clock: Arc<SteadyClock>,
         observer: Arc<dyn NetworkObserver>,
         cancel_token: CancellationToken,
@@ -47,6 +50,7 @@ impl Channel {
             channel_id: channel_info.channel_id(),
             info: channel_info,
             limiter,
+            traffic_policy,
             write_queue: Arc::new(write_queue),
             stream,
             clock,
@@ -74,6 +78,7 @@ impl Channel {
             )),
             Arc::downgrade(&Arc::new(TcpStream::new_null())),
             Arc::new(BandwidthLimiter::default()),
+            Arc::new(RwLock::new(TrafficPolicyConfig::default())),
             Arc::new(SteadyClock::new_null()),
             Arc::new(NullNetworkObserver::new()),
             CancellationToken::new(),
@@ -85,6 +90,7 @@ impl Channel {
         channel_info: Arc<ChannelInfo>,
         stream: TcpStream,
         limiter: Arc<BandwidthLimiter>,
+        traffic_policy: Arc<RwLock<TrafficPolicyConfig>>,
         clock: Arc<SteadyClock>,
         observer: Arc<dyn NetworkObserver>,
         handle: &tokio::runtime::Handle,
@@ -97,6 +103,7 @@ impl Channel {
             channel_info,
             Arc::downgrade(&stream),
             limiter,
+            traffic_policy,
             clock.clone(),
             observer.clone(),
             cancel_token.clone(),
@@ -133,6 +140,7 @@ impl Channel {
                                     written += n;
                                     if written >= buffer.len() {
                                         observer.send_succeeded(written);
+                                        info.add_bytes_sent(written as u64);
                                         info.set_last_activity(clock.now());
                                         break;
                                     }
@@ -191,14 +199,15 @@ impl Channel {
             bail!("socket closed");
         }
 
+        let mut waited = Duration::ZERO;
         while self.info.is_queue_full(traffic_type) {
             // TODO: better implementation
-            sleep(Duration::from_millis(20)).await;
+            self.wait_for_send(&mut waited).await?;
         }
 
         while !self.limiter.should_pass(buffer.len(), traffic_type) {
             // TODO: better implementation
-            sleep(Duration::from_millis(20)).await;
+            self.wait_for_send(&mut waited).await?;
         }
 
         if self.info.is_closed() {
@@ -213,6 +222,7 @@ impl Channel {
             .await;
 
         if result.is_ok() {
+            self.info.reset_send_failures();
             self.observer.send_succeeded(buf_size);
             self.info.set_last_activity(self.clock.now());
         } else {
@@ -225,6 +235,25 @@ impl Channel {
         Ok(())
     }
 
+    /// Sleeps before the next send attempt. Fails if the total wait time
+    /// exceeds the send timeout and closes the channel if that happened
+    /// more than `max_send_failures` times in a row
+    async fn wait_for_send(&self, waited: &mut Duration) -> anyhow::Result<()> {
+        if *waited >= self.info.send_timeout() {
+            let failures = self.info.add_send_failure();
+            self.observer.send_failed();
+            let max_failures = self.info.max_send_failures();
+            if max_failures > 0 && failures >= max_failures {
+                debug!(channel_id = %self.channel_id(), remote_addr = ?self.info.peer_addr(), failures, "Closing channel after repeated send timeouts");
+                self.info.close();
+            }
+            bail!("send timed out");
+        }
+        sleep(Self::SEND_RETRY_INTERVAL).await;
+        *waited += Self::SEND_RETRY_INTERVAL;
+        Ok(())
+    }
+
     pub fn try_send_buffer(
         &self,
         buffer: &[u8],
@@ -235,12 +264,19 @@ impl Channel {
             return false;
         }
 
+        let drop_policy = match drop_policy {
+            DropPolicy::Default => self.traffic_policy.read().unwrap().policy_for(traffic_type),
+            policy => policy,
+        };
+
         if drop_policy == DropPolicy::CanDrop && self.info.is_queue_full(traffic_type) {
             return false;
         }
 
         let should_pass = self.limiter.should_pass(buffer.len(), traffic_type);
+        self.info.set_throttled(!should_pass);
         if !should_pass && drop_policy == DropPolicy::CanDrop {
+            self.info.inc_limiter_drops();
             return false;
         } else {
             // TODO notify bandwidth limiter that we are sending it anyway
@@ -326,6 +362,7 @@ impl AsyncBufferReader for Channel {
                             read += n;
                             if read >= count {
                                 self.observer.read_succeeded(count);
+                                self.info.add_bytes_received(count as u64);
                                 self.info.set_last_activity(self.clock.now());
                                 return Ok(());
                             }
@@ -382,3 +419,93 @@ impl WriteQueueAdapter for WriteQueueAdapterImpl {
         self.cancel_token.cancel();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bandwidth_limiter::BandwidthLimiterConfig;
+
+    #[tokio::test]
+    async fn send_times_out_when_limiter_never_passes() {
+        let (channel, _receiver) = create_throttled_channel();
+
+        let result = channel.send_buffer(&[0; 100], TrafficType::Generic).await;
+
+        assert!(result.is_err());
+        assert_eq!(channel.info.send_failures(), 1);
+        assert!(channel.info.is_alive());
+    }
+
+    #[tokio::test]
+    async fn close_after_repeated_send_timeouts() {
+        let (channel, _receiver) = create_throttled_channel();
+        channel.info.set_max_send_failures(3);
+
+        for _ in 0..3 {
+            let _ = channel.send_buffer(&[0; 100], TrafficType::Generic).await;
+        }
+
+        assert_eq!(channel.info.send_failures(), 3);
+        assert!(channel.info.is_closed());
+    }
+
+    #[tokio::test]
+    async fn dont_close_after_send_timeouts_by_default() {
+        let (channel, _receiver) = create_throttled_channel();
+
+        for _ in 0..3 {
+            let _ = channel.send_buffer(&[0; 100], TrafficType::Generic).await;
+        }
+
+        assert_eq!(channel.info.send_failures(), 3);
+        assert!(channel.info.is_alive());
+    }
+
+    #[test]
+    fn count_limiter_drops() {
+        let (channel, _receiver) = create_throttled_channel();
+
+        for _ in 0..3 {
+            let sent =
+                channel.try_send_buffer(&[0; 100], DropPolicy::CanDrop, TrafficType::Generic);
+            assert!(!sent);
+        }
+
+        assert!(channel.info.is_throttled());
+        assert_eq!(channel.info.limiter_drops(), 3);
+    }
+
+    #[test]
+    fn resolve_default_drop_policy() {
+        let (channel, _receiver) = create_throttled_channel();
+        *channel.traffic_policy.write().unwrap() = TrafficPolicyConfig {
+            generic: DropPolicy::CanDrop,
+            bootstrap: DropPolicy::CanDrop,
+        };
+
+        let sent = channel.try_send_buffer(&[0; 100], DropPolicy::Default, TrafficType::Generic);
+
+        assert!(!sent);
+        assert_eq!(channel.info.limiter_drops(), 1);
+    }
+
+    /// Creates a channel whose bandwidth limiter never lets a 100 byte buffer pass
+    fn create_throttled_channel() -> (Channel, WriteQueueReceiver) {
+        let limiter = BandwidthLimiter::new(BandwidthLimiterConfig {
+            generic_limit: 1,
+            generic_burst_ratio: 1.0,
+            ..Default::default()
+        });
+        let (channel, receiver) = Channel::new(
+            Arc::new(ChannelInfo::new_test_instance()),
+            Arc::downgrade(&Arc::new(TcpStream::new_null())),
+            Arc::new(limiter),
+            Arc::new(RwLock::new(TrafficPolicyConfig::default())),
+            Arc::new(SteadyClock::new_null()),
+            Arc::new(NullNetworkObserver::new()),
+            CancellationToken::new(),
+        );
+        channel.info.set_send_timeout(Duration::from_millis(50));
+        (channel, receiver)
+    }
+}
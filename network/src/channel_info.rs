@@ -7,7 +7,7 @@ use rsban_nullable_clock::Timestamp;
 use std::{
     net::{Ipv6Addr, SocketAddrV6},
     sync::{
-        atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicU8, Ordering},
+        atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicU64, AtomicU8, Ordering},
         Mutex,
     },
     time::Duration,
@@ -15,7 +15,7 @@ use std::{
 
 use crate::{
     utils::{ipv4_address_or_ipv6_subnet, map_address_to_subnetwork},
-    ChannelDirection, ChannelId, ChannelMode, TrafficType,
+    ChannelDirection, ChannelId, ChannelMode, NetworkConfig, TrafficType,
 };
 
 /// Default timeout in seconds
@@ -46,6 +46,20 @@ pub struct ChannelInfo {
     closed: AtomicBool,
 
     socket_type: AtomicU8,
+
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+
+    /// Maximum duration in milliseconds a send may wait before it fails
+    send_timeout_ms: AtomicU64,
+    /// Number of sends in a row that failed because they waited too long
+    send_failures: AtomicU32,
+    /// The channel gets closed after this many sends in a row timed out. 0 means never
+    max_send_failures: AtomicU32,
+    /// Result of the last bandwidth limiter check was "don't pass"
+    throttled: AtomicBool,
+    /// Number of buffers that were dropped because of the bandwidth limiter
+    limiter_drops: AtomicU64,
 }
 
 impl ChannelInfo {
@@ -70,9 +84,19 @@ impl ChannelInfo {
             timed_out: AtomicBool::new(false),
             socket_type: AtomicU8::new(ChannelMode::Undefined as u8),
             closed: AtomicBool::new(false),
+            bytes_sent: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+            send_timeout_ms: AtomicU64::new(NetworkConfig::DEFAULT_SEND_TIMEOUT.as_millis() as u64),
+            send_failures: AtomicU32::new(0),
+            max_send_failures: AtomicU32::new(0),
+            throttled: AtomicBool::new(false),
+            limiter_drops: AtomicU64::new(0),
             data: Mutex::new(ChannelInfoData {
                 node_id: None,
                 write_queue: None,
+                rtt_probe_sent: None,
+                rtt: None,
+                average_rtt: None,
                 peering_addr: if direction == ChannelDirection::Outbound {
                     Some(peer_addr)
                 } else {
@@ -125,6 +149,12 @@ impl ChannelInfo {
         self.data.lock().unwrap().peering_addr.clone()
     }
 
+    /// Inbound channels only know the peering address after the peer
+    /// told us its listening port
+    pub fn has_peering_addr(&self) -> bool {
+        self.data.lock().unwrap().peering_addr.is_some()
+    }
+
     pub fn peering_addr_or_peer_addr(&self) -> SocketAddrV6 {
         self.data
             .lock()
@@ -159,6 +189,73 @@ impl ChannelInfo {
         self.last_activity.store(now.into(), Ordering::Relaxed);
     }
 
+    /// Total number of bytes written to the socket
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent.load(Ordering::Relaxed)
+    }
+
+    pub fn add_bytes_sent(&self, count: u64) {
+        self.bytes_sent.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Total number of bytes read from the socket
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received.load(Ordering::Relaxed)
+    }
+
+    pub fn add_bytes_received(&self, count: u64) {
+        self.bytes_received.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// True if the bandwidth limiter didn't let the last buffer pass
+    pub fn is_throttled(&self) -> bool {
+        self.throttled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_throttled(&self, throttled: bool) {
+        self.throttled.store(throttled, Ordering::Relaxed);
+    }
+
+    /// Number of buffers that were dropped by the bandwidth limiter
+    pub fn limiter_drops(&self) -> u64 {
+        self.limiter_drops.load(Ordering::Relaxed)
+    }
+
+    pub fn inc_limiter_drops(&self) {
+        self.limiter_drops.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn send_timeout(&self) -> Duration {
+        Duration::from_millis(self.send_timeout_ms.load(Ordering::Relaxed))
+    }
+
+    pub fn set_send_timeout(&self, value: Duration) {
+        self.send_timeout_ms
+            .store(value.as_millis() as u64, Ordering::Relaxed)
+    }
+
+    /// Number of consecutive sends that timed out
+    pub fn send_failures(&self) -> u32 {
+        self.send_failures.load(Ordering::Relaxed)
+    }
+
+    /// Increments the consecutive send failures and returns the new count
+    pub fn add_send_failure(&self) -> u32 {
+        self.send_failures.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn reset_send_failures(&self) {
+        self.send_failures.store(0, Ordering::Relaxed);
+    }
+
+    pub fn max_send_failures(&self) -> u32 {
+        self.max_send_failures.load(Ordering::Relaxed)
+    }
+
+    pub fn set_max_send_failures(&self, value: u32) {
+        self.max_send_failures.store(value, Ordering::Relaxed);
+    }
+
     pub fn timeout(&self) -> Duration {
         Duration::from_secs(self.timeout_seconds.load(Ordering::Relaxed))
     }
@@ -193,6 +290,42 @@ impl ChannelInfo {
         }
     }
 
+    /// The last measured round trip time
+    pub fn rtt(&self) -> Option<Duration> {
+        self.data.lock().unwrap().rtt
+    }
+
+    /// Exponential moving average of the measured round trip times
+    pub fn average_rtt(&self) -> Option<Duration> {
+        self.data.lock().unwrap().average_rtt
+    }
+
+    pub fn set_rtt(&self, rtt: Duration) {
+        let mut guard = self.data.lock().unwrap();
+        guard.rtt = Some(rtt);
+        guard.average_rtt = Some(match guard.average_rtt {
+            Some(average) => (average * 7 + rtt) / 8,
+            None => rtt,
+        });
+    }
+
+    /// Remembers when a request was sent that the peer is expected to answer.
+    /// If there is already an unanswered request, its send time is kept.
+    pub fn rtt_probe_sent(&self, now: Timestamp) {
+        let mut guard = self.data.lock().unwrap();
+        if guard.rtt_probe_sent.is_none() {
+            guard.rtt_probe_sent = Some(now);
+        }
+    }
+
+    /// Measures the round trip time if there is an unanswered request
+    pub fn rtt_probe_answered(&self, now: Timestamp) {
+        let sent = self.data.lock().unwrap().rtt_probe_sent.take();
+        if let Some(sent) = sent {
+            self.set_rtt(now - sent);
+        }
+    }
+
     pub fn set_node_id(&self, node_id: NodeId) {
         self.data.lock().unwrap().node_id = Some(node_id);
     }
@@ -231,9 +364,50 @@ struct ChannelInfoData {
     node_id: Option<NodeId>,
     peering_addr: Option<SocketAddrV6>,
     write_queue: Option<Box<dyn WriteQueueAdapter>>,
+    /// Time at which an unanswered round trip probe was sent
+    rtt_probe_sent: Option<Timestamp>,
+    rtt: Option<Duration>,
+    average_rtt: Option<Duration>,
 }
 
 pub(crate) trait WriteQueueAdapter: Send + Sync {
     fn is_queue_full(&self, traffic_type: TrafficType) -> bool;
     fn close(&self);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_rtt_by_default() {
+        let channel = ChannelInfo::new_test_instance();
+        assert_eq!(channel.rtt(), None);
+        assert_eq!(channel.average_rtt(), None);
+    }
+
+    #[test]
+    fn set_rtt() {
+        let channel = ChannelInfo::new_test_instance();
+        channel.set_rtt(Duration::from_millis(100));
+        channel.set_rtt(Duration::from_millis(20));
+        assert_eq!(channel.rtt(), Some(Duration::from_millis(20)));
+        assert_eq!(channel.average_rtt(), Some(Duration::from_millis(90)));
+    }
+
+    #[test]
+    fn measure_rtt_with_probe() {
+        let channel = ChannelInfo::new_test_instance();
+        let now = Timestamp::new_test_instance();
+        channel.rtt_probe_sent(now);
+        channel.rtt_probe_answered(now + Duration::from_millis(50));
+        assert_eq!(channel.rtt(), Some(Duration::from_millis(50)));
+    }
+
+    #[test]
+    fn ignore_unsolicited_answer() {
+        let channel = ChannelInfo::new_test_instance();
+        channel.rtt_probe_answered(Timestamp::new_test_instance());
+        assert_eq!(channel.rtt(), None);
+    }
+}
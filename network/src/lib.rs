@@ -10,6 +10,7 @@ mod peer_connector;
 pub mod peer_exclusion;
 mod response_server_spawner;
 mod tcp_listener;
+mod tcp_socket_options;
 pub mod token_bucket;
 pub mod utils;
 pub mod write_queue;
@@ -26,6 +27,7 @@ pub use peer_connector::*;
 pub use response_server_spawner::*;
 use std::fmt::{Debug, Display};
 pub use tcp_listener::*;
+pub use tcp_socket_options::*;
 
 #[macro_use]
 extern crate anyhow;
@@ -104,6 +106,35 @@ pub enum DropPolicy {
     /// Should not be dropped by bandwidth limiter,
     /// but it can still be dropped if the write queue is full
     ShouldNotDrop,
+    /// Use the policy which is configured for the traffic type in `TrafficPolicyConfig`
+    Default,
+}
+
+/// Maps each traffic type to the drop policy which is used when a buffer
+/// is sent with `DropPolicy::Default`
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct TrafficPolicyConfig {
+    pub generic: DropPolicy,
+    pub bootstrap: DropPolicy,
+}
+
+impl TrafficPolicyConfig {
+    pub fn policy_for(&self, traffic_type: TrafficType) -> DropPolicy {
+        match traffic_type {
+            TrafficType::Generic => self.generic,
+            TrafficType::Bootstrap => self.bootstrap,
+        }
+    }
+}
+
+impl Default for TrafficPolicyConfig {
+    fn default() -> Self {
+        Self {
+            // Votes and blocks are generic traffic
+            generic: DropPolicy::ShouldNotDrop,
+            bootstrap: DropPolicy::CanDrop,
+        }
+    }
 }
 
 #[async_trait]
@@ -2,14 +2,17 @@ use crate::{
     bandwidth_limiter::{BandwidthLimiter, BandwidthLimiterConfig},
     utils::into_ipv6_socket_address,
     Channel, ChannelDirection, ChannelId, ChannelMode, DeadChannelCleanupStep, DropPolicy,
-    NetworkInfo, NetworkObserver, NullNetworkObserver, TrafficType,
+    NetworkInfo, NetworkObserver, NullNetworkObserver, TrafficPolicyConfig, TrafficType,
 };
 use rsban_core::utils::NULL_ENDPOINT;
 use rsban_nullable_clock::SteadyClock;
 use rsban_nullable_tcp::TcpStream;
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex, RwLock},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, RwLock,
+    },
     time::{Duration, Instant},
 };
 use tracing::{debug, warn};
@@ -18,9 +21,11 @@ pub struct Network {
     channels: Mutex<HashMap<ChannelId, Arc<Channel>>>,
     pub info: Arc<RwLock<NetworkInfo>>,
     limiter: Arc<BandwidthLimiter>,
+    traffic_policy: Arc<RwLock<TrafficPolicyConfig>>,
     clock: Arc<SteadyClock>,
     observer: Arc<dyn NetworkObserver>,
     handle: tokio::runtime::Handle,
+    at_inbound_capacity: AtomicBool,
 }
 
 impl Network {
@@ -33,10 +38,12 @@ impl Network {
         Self {
             channels: Mutex::new(HashMap::new()),
             limiter: Arc::new(BandwidthLimiter::new(limiter_config)),
+            traffic_policy: Arc::new(RwLock::new(TrafficPolicyConfig::default())),
             clock,
             info: network_info,
             observer: Arc::new(NullNetworkObserver::new()),
             handle,
+            at_inbound_capacity: AtomicBool::new(false),
         }
     }
 
@@ -44,16 +51,32 @@ impl Network {
         self.observer = observer;
     }
 
+    /// Changes the policies which are used for `DropPolicy::Default`. This also
+    /// applies to channels which are already open
+    pub fn set_traffic_policy(&self, traffic_policy: TrafficPolicyConfig) {
+        *self.traffic_policy.write().unwrap() = traffic_policy;
+    }
+
     pub async fn wait_for_available_inbound_slot(&self) {
         let last_log = Instant::now();
         let log_interval = Duration::from_secs(15);
         while self.should_wait_for_inbound_slot() {
+            if !self.at_inbound_capacity.swap(true, Ordering::SeqCst) {
+                self.observer.inbound_capacity_reached();
+            }
+
             if last_log.elapsed() >= log_interval {
                 warn!("Waiting for available slots to accept new connections");
             }
 
             tokio::time::sleep(Duration::from_millis(100)).await;
         }
+
+        if self.at_inbound_capacity.swap(false, Ordering::SeqCst)
+            && !self.info.read().unwrap().is_stopped()
+        {
+            self.observer.inbound_slot_available();
+        }
     }
 
     fn should_wait_for_inbound_slot(&self) -> bool {
@@ -100,6 +123,7 @@ impl Network {
             channel_info,
             stream,
             self.limiter.clone(),
+            self.traffic_policy.clone(),
             self.clock.clone(),
             self.observer.clone(),
             &self.handle,
@@ -174,3 +198,122 @@ impl DeadChannelCleanupStep for NetworkCleanup {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NetworkConfig;
+    use rsban_core::{
+        utils::{TEST_ENDPOINT_1, TEST_ENDPOINT_2},
+        Networks,
+    };
+    use rsban_nullable_clock::Timestamp;
+
+    #[tokio::test]
+    async fn notify_when_inbound_capacity_reached_and_freed() {
+        let mut config = NetworkConfig::default_for(Networks::BananoDevNetwork);
+        config.max_inbound_connections = 1;
+        let info = Arc::new(RwLock::new(NetworkInfo::new(config)));
+        let channel = info
+            .write()
+            .unwrap()
+            .add(
+                TEST_ENDPOINT_1,
+                TEST_ENDPOINT_2,
+                ChannelDirection::Inbound,
+                ChannelMode::Realtime,
+                Timestamp::new_test_instance(),
+            )
+            .unwrap();
+
+        let mut network = Network::new(
+            Default::default(),
+            info.clone(),
+            Arc::new(SteadyClock::new_null()),
+            tokio::runtime::Handle::current(),
+        );
+        let observer = Arc::new(CapacityRecorder::default());
+        network.set_observer(observer.clone());
+        let network = Arc::new(network);
+
+        let network_l = network.clone();
+        let waiting =
+            tokio::spawn(async move { network_l.wait_for_available_inbound_slot().await });
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(*observer.events.lock().unwrap(), ["at capacity"]);
+
+        channel.close();
+        info.write()
+            .unwrap()
+            .purge(Timestamp::new_test_instance(), Duration::from_secs(300));
+        waiting.await.unwrap();
+
+        assert_eq!(
+            *observer.events.lock().unwrap(),
+            ["at capacity", "slot available"]
+        );
+    }
+
+    #[test]
+    fn apply_traffic_policy_for_default_drop_policy() {
+        // The runtime is never driven, so the write queue doesn't get drained
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let network = Network::new_null(runtime.handle().clone());
+        let channel = network
+            .add(
+                TcpStream::new_null(),
+                ChannelDirection::Outbound,
+                ChannelMode::Realtime,
+            )
+            .unwrap();
+        // Inverts the default policies after the channel was added
+        network.set_traffic_policy(TrafficPolicyConfig {
+            generic: DropPolicy::CanDrop,
+            bootstrap: DropPolicy::ShouldNotDrop,
+        });
+        let channel_id = channel.channel_id();
+        for traffic_type in [TrafficType::Generic, TrafficType::Bootstrap] {
+            while !channel.info.is_queue_full(traffic_type) {
+                assert!(network.try_send_buffer(
+                    channel_id,
+                    &[0; 10],
+                    DropPolicy::ShouldNotDrop,
+                    traffic_type
+                ));
+            }
+        }
+
+        let bootstrap_sent = network.try_send_buffer(
+            channel_id,
+            &[0; 10],
+            DropPolicy::Default,
+            TrafficType::Bootstrap,
+        );
+        let vote_sent = network.try_send_buffer(
+            channel_id,
+            &[0; 10],
+            DropPolicy::Default,
+            TrafficType::Generic,
+        );
+
+        assert!(bootstrap_sent);
+        assert!(!vote_sent);
+    }
+
+    #[derive(Default)]
+    struct CapacityRecorder {
+        events: Mutex<Vec<&'static str>>,
+    }
+
+    impl NetworkObserver for CapacityRecorder {
+        fn inbound_capacity_reached(&self) {
+            self.events.lock().unwrap().push("at capacity");
+        }
+
+        fn inbound_slot_available(&self) {
+            self.events.lock().unwrap().push("slot available");
+        }
+    }
+}
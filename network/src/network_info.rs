@@ -29,13 +29,21 @@ pub struct NetworkConfig {
 
     pub allow_local_peers: bool,
     pub min_protocol_version: u8,
+    /// Channels with a lower protocol version are not upgraded to realtime channels
+    pub min_realtime_protocol_version: u8,
     pub disable_max_peers_per_ip: bool,         // For testing only
     pub disable_max_peers_per_subnetwork: bool, // For testing only
     pub disable_network: bool,
     pub listening_port: u16,
+    /// Maximum time a send may wait for the write queue or the bandwidth limiter
+    pub send_timeout: Duration,
+    /// A channel gets closed after this many sends in a row timed out. 0 means never
+    pub max_send_failures: u32,
 }
 
 impl NetworkConfig {
+    pub const DEFAULT_SEND_TIMEOUT: Duration = Duration::from_secs(5);
+
     pub fn default_for(network: Networks) -> Self {
         let is_dev = network == Networks::BananoDevNetwork;
         Self {
@@ -52,6 +60,7 @@ impl NetworkConfig {
             },
             max_attempts_per_ip: if is_dev { 128 } else { 1 },
             min_protocol_version: 0x12, //TODO don't hard code
+            min_realtime_protocol_version: 0x12,
             disable_max_peers_per_ip: false,
             disable_max_peers_per_subnetwork: false,
             disable_network: false,
@@ -61,6 +70,8 @@ impl NetworkConfig {
                 Networks::BananoTestNetwork => 17019,
                 _ => 7072,
             },
+            send_timeout: Self::DEFAULT_SEND_TIMEOUT,
+            max_send_failures: 0,
         }
     }
 }
@@ -75,6 +86,17 @@ pub enum NetworkError {
     InvalidIp,
     /// We are already connected to that peer and we tried to connect a second time
     DuplicateConnection,
+    /// Another connection attempt to that peer is already running
+    AttemptInProgress,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RealtimeUpgradeError {
+    Stopped,
+    UnknownChannel,
+    /// Another channel from the same IP is already connected to that node
+    DuplicateNodeId,
+    ProtocolVersionTooLow,
 }
 
 pub struct NetworkInfo {
@@ -82,6 +104,7 @@ pub struct NetworkInfo {
     channels: HashMap<ChannelId, Arc<ChannelInfo>>,
     stopped: bool,
     new_realtime_channel_observers: Vec<Arc<dyn Fn(Arc<ChannelInfo>) + Send + Sync>>,
+    channel_removed_observers: Vec<Arc<dyn Fn(ChannelId, SocketAddrV6) + Send + Sync>>,
     attempts: AttemptContainer,
     network_config: NetworkConfig,
     excluded_peers: PeerExclusion,
@@ -94,6 +117,7 @@ impl NetworkInfo {
             channels: HashMap::new(),
             stopped: false,
             new_realtime_channel_observers: Vec::new(),
+            channel_removed_observers: Vec::new(),
             attempts: Default::default(),
             network_config,
             excluded_peers: PeerExclusion::new(),
@@ -118,6 +142,21 @@ impl NetworkInfo {
         self.new_realtime_channel_observers.clone()
     }
 
+    /// The callback gets the ID and peer address of every removed channel.
+    /// It is called while NetworkInfo is locked, so it must not lock it again!
+    pub fn on_channel_removed(
+        &mut self,
+        callback: Arc<dyn Fn(ChannelId, SocketAddrV6) + Send + Sync>,
+    ) {
+        self.channel_removed_observers.push(callback);
+    }
+
+    fn notify_channel_removed(&self, channel: &ChannelInfo) {
+        for observer in &self.channel_removed_observers {
+            observer(channel.channel_id(), channel.peer_addr());
+        }
+    }
+
     pub fn is_inbound_slot_available(&self) -> bool {
         self.count_by_direction(ChannelDirection::Inbound)
             < self.network_config.max_inbound_connections
@@ -138,6 +177,11 @@ impl NetworkInfo {
         planned_mode: ChannelMode,
         now: Timestamp,
     ) -> Result<(), NetworkError> {
+        // Reserving the endpoint under the same lock guarantees that only one of
+        // several concurrent dials to the same peer proceeds
+        if self.attempts.contains(&peer) {
+            return Err(NetworkError::AttemptInProgress);
+        }
         self.validate_new_connection(&peer, ChannelDirection::Outbound, planned_mode, now)?;
         self.attempts.insert(peer, ChannelDirection::Outbound, now);
         Ok(())
@@ -147,6 +191,17 @@ impl NetworkInfo {
         self.attempts.remove(&remote);
     }
 
+    /// Number of connection attempts which are still in progress
+    pub fn attempt_count(&self) -> usize {
+        self.attempts.len()
+    }
+
+    /// Number of connection attempts in progress to the given subnetwork,
+    /// as returned by `map_address_to_subnetwork`
+    pub fn attempts_for_subnet(&self, subnet: &Ipv6Addr) -> usize {
+        self.attempts.count_by_subnetwork(subnet)
+    }
+
     pub fn add(
         &mut self,
         local_addr: SocketAddrV6,
@@ -165,6 +220,8 @@ impl NetworkInfo {
             self.network_config.min_protocol_version,
             now,
         ));
+        channel_info.set_send_timeout(self.network_config.send_timeout);
+        channel_info.set_max_send_failures(self.network_config.max_send_failures);
         self.channels.insert(channel_id, channel_info.clone());
         Ok(channel_info)
     }
@@ -188,7 +245,9 @@ impl NetworkInfo {
     }
 
     pub fn remove(&mut self, channel_id: ChannelId) {
-        self.channels.remove(&channel_id);
+        if let Some(channel) = self.channels.remove(&channel_id) {
+            self.notify_channel_removed(&channel);
+        }
     }
 
     pub fn set_node_id(&self, channel_id: ChannelId, node_id: NodeId) {
@@ -328,6 +387,7 @@ impl NetworkInfo {
         for channel in &dead_channels {
             debug!("Removing dead channel: {}", channel.peer_addr());
             self.channels.remove(&channel.channel_id());
+            self.notify_channel_removed(channel);
         }
 
         dead_channels
@@ -570,10 +630,16 @@ impl NetworkInfo {
         }
     }
 
-    pub fn random_fill_realtime(&self, endpoints: &mut [SocketAddrV6]) {
+    pub fn random_fill_realtime(&self, endpoints: &mut [SocketAddrV6], now: Timestamp) {
         let mut peers = self.list_realtime(0);
         // Don't include channels with ephemeral remote ports
-        peers.retain(|c| c.peering_addr().is_some());
+        peers.retain(|c| c.has_peering_addr());
+        // Don't gossip peers that are currently excluded
+        peers.retain(|c| {
+            !self
+                .excluded_peers
+                .is_currently_excluded(&c.peering_addr_or_peer_addr(), now)
+        });
         let mut rng = thread_rng();
         peers.shuffle(&mut rng);
         peers.truncate(endpoints.len());
@@ -596,27 +662,49 @@ impl NetworkInfo {
         }
     }
 
+    /// Sets the protocol versions of multiple channels at once
+    pub fn set_protocol_versions(&self, versions: &[(ChannelId, u8)]) {
+        for (channel_id, protocol_version) in versions {
+            self.set_protocol_version(*channel_id, *protocol_version);
+        }
+    }
+
+    pub fn min_realtime_protocol_version(&self) -> u8 {
+        self.network_config.min_realtime_protocol_version
+    }
+
+    pub fn set_min_realtime_protocol_version(&mut self, version: u8) {
+        self.network_config.min_realtime_protocol_version = version;
+    }
+
     pub fn upgrade_to_realtime_connection(
         &self,
         channel_id: ChannelId,
         node_id: NodeId,
-    ) -> Option<(
-        Arc<ChannelInfo>,
-        Vec<Arc<dyn Fn(Arc<ChannelInfo>) + Send + Sync>>,
-    )> {
+    ) -> Result<
+        (
+            Arc<ChannelInfo>,
+            Vec<Arc<dyn Fn(Arc<ChannelInfo>) + Send + Sync>>,
+        ),
+        RealtimeUpgradeError,
+    > {
         if self.is_stopped() {
-            return None;
+            return Err(RealtimeUpgradeError::Stopped);
         }
 
         let Some(channel) = self.channels.get(&channel_id) else {
-            return None;
+            return Err(RealtimeUpgradeError::UnknownChannel);
         };
 
+        if channel.protocol_version() < self.network_config.min_realtime_protocol_version {
+            return Err(RealtimeUpgradeError::ProtocolVersionTooLow);
+        }
+
         if let Some(other) = self.find_node_id(&node_id) {
             if other.ipv4_address_or_ipv6_subnet() == channel.ipv4_address_or_ipv6_subnet() {
                 // We already have a connection to that node. We allow duplicate node ids, but
                 // only if they come from different IP addresses
-                return None;
+                return Err(RealtimeUpgradeError::DuplicateNodeId);
             }
         }
 
@@ -625,7 +713,7 @@ impl NetworkInfo {
 
         let observers = self.new_realtime_channel_observers();
         let channel = channel.clone();
-        Some((channel, observers))
+        Ok((channel, observers))
     }
 
     pub fn idle_channels(&self, min_idle_time: Duration, now: Timestamp) -> Vec<ChannelId> {
@@ -658,6 +746,40 @@ impl NetworkInfo {
         info
     }
 
+    /// Ids of all channels with the given mode, ordered by channel id
+    pub fn channels_by_mode(&self, mode: ChannelMode) -> Vec<ChannelId> {
+        let mut result: Vec<_> = self
+            .channels
+            .values()
+            .filter(|c| c.mode() == mode)
+            .map(|c| c.channel_id())
+            .collect();
+        result.sort();
+        result
+    }
+
+    /// Returns a snapshot of all channels ordered by channel id
+    pub fn channel_snapshot(&self) -> Vec<ChannelSnapshot> {
+        let mut result: Vec<_> = self
+            .channels
+            .values()
+            .map(|c| ChannelSnapshot {
+                channel_id: c.channel_id(),
+                peer_addr: c.peer_addr(),
+                mode: c.mode(),
+                direction: c.direction(),
+                protocol_version: c.protocol_version(),
+                bytes_sent: c.bytes_sent(),
+                bytes_received: c.bytes_received(),
+                average_rtt: c.average_rtt(),
+                throttled: c.is_throttled(),
+                limiter_drops: c.limiter_drops(),
+            })
+            .collect();
+        result.sort_by_key(|c| c.channel_id);
+        result
+    }
+
     pub fn len(&self) -> usize {
         self.channels.len()
     }
@@ -698,11 +820,69 @@ pub struct ChannelsInfo {
     pub outbound: usize,
 }
 
+#[derive(Clone)]
+pub struct ChannelSnapshot {
+    pub channel_id: ChannelId,
+    pub peer_addr: SocketAddrV6,
+    pub mode: ChannelMode,
+    pub direction: ChannelDirection,
+    pub protocol_version: u8,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub average_rtt: Option<Duration>,
+    pub throttled: bool,
+    pub limiter_drops: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rsban_core::utils::{NULL_ENDPOINT, TEST_ENDPOINT_1, TEST_ENDPOINT_2, TEST_ENDPOINT_3};
 
+    #[test]
+    fn count_attempts() {
+        let mut network = NetworkInfo::new_test_instance();
+        let peer1: SocketAddrV6 = "[2a01:1::1]:7071".parse().unwrap();
+        let peer2: SocketAddrV6 = "[2a01:1::2]:7071".parse().unwrap();
+        let peer3: SocketAddrV6 = "[2a02:1::1]:7071".parse().unwrap();
+        let subnet = map_address_to_subnetwork(peer1.ip());
+        let now = Timestamp::new_test_instance();
+
+        for peer in [peer1, peer2, peer3] {
+            network
+                .add_outbound_attempt(peer, ChannelMode::Realtime, now)
+                .unwrap();
+        }
+
+        assert_eq!(network.attempt_count(), 3);
+        assert_eq!(network.attempts_for_subnet(&subnet), 2);
+
+        network.remove_attempt(&peer1);
+
+        assert_eq!(network.attempt_count(), 2);
+        assert_eq!(network.attempts_for_subnet(&subnet), 1);
+    }
+
+    #[test]
+    fn reject_second_attempt_to_same_peer() {
+        let mut network = NetworkInfo::new_test_instance();
+        let now = Timestamp::new_test_instance();
+
+        assert!(network
+            .add_outbound_attempt(TEST_ENDPOINT_1, ChannelMode::Realtime, now)
+            .is_ok());
+        assert!(matches!(
+            network.add_outbound_attempt(TEST_ENDPOINT_1, ChannelMode::Bootstrap, now),
+            Err(NetworkError::AttemptInProgress)
+        ));
+        assert_eq!(network.attempt_count(), 1);
+
+        network.remove_attempt(&TEST_ENDPOINT_1);
+        assert!(network
+            .add_outbound_attempt(TEST_ENDPOINT_1, ChannelMode::Realtime, now)
+            .is_ok());
+    }
+
     #[test]
     fn newly_added_channel_is_not_a_realtime_channel() {
         let mut network = NetworkInfo::new_test_instance();
@@ -718,6 +898,140 @@ mod tests {
         assert_eq!(network.list_realtime_channels(0).len(), 0);
     }
 
+    #[test]
+    fn notify_when_channel_removed() {
+        let mut network = NetworkInfo::new_test_instance();
+        let removed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let removed_l = removed.clone();
+        network.on_channel_removed(Arc::new(move |channel_id, peer_addr| {
+            removed_l.lock().unwrap().push((channel_id, peer_addr));
+        }));
+        let channel = network
+            .add(
+                TEST_ENDPOINT_1,
+                TEST_ENDPOINT_2,
+                ChannelDirection::Inbound,
+                ChannelMode::Realtime,
+                Timestamp::new_test_instance(),
+            )
+            .unwrap();
+
+        network.remove(channel.channel_id());
+        network.remove(channel.channel_id());
+
+        assert_eq!(
+            *removed.lock().unwrap(),
+            [(channel.channel_id(), TEST_ENDPOINT_2)]
+        );
+    }
+
+    #[test]
+    fn channels_by_mode() {
+        let mut network = NetworkInfo::new_test_instance();
+        let modes = [
+            ChannelMode::Realtime,
+            ChannelMode::Bootstrap,
+            ChannelMode::Realtime,
+            ChannelMode::Undefined,
+            ChannelMode::Realtime,
+        ];
+        let mut channel_ids = Vec::new();
+        for (i, mode) in modes.iter().enumerate() {
+            let channel = network
+                .add(
+                    TEST_ENDPOINT_1,
+                    SocketAddrV6::new(*TEST_ENDPOINT_2.ip(), 5000 + i as u16, 0, 0),
+                    ChannelDirection::Outbound,
+                    *mode,
+                    Timestamp::new_test_instance(),
+                )
+                .unwrap();
+            channel.set_mode(*mode);
+            channel_ids.push(channel.channel_id());
+        }
+
+        assert_eq!(
+            network.channels_by_mode(ChannelMode::Realtime),
+            [channel_ids[0], channel_ids[2], channel_ids[4]]
+        );
+        assert_eq!(
+            network.channels_by_mode(ChannelMode::Bootstrap),
+            [channel_ids[1]]
+        );
+    }
+
+    #[test]
+    fn channel_snapshot() {
+        let mut network = NetworkInfo::new_test_instance();
+        let channel1 = network
+            .add(
+                TEST_ENDPOINT_1,
+                TEST_ENDPOINT_2,
+                ChannelDirection::Inbound,
+                ChannelMode::Realtime,
+                Timestamp::new_test_instance(),
+            )
+            .unwrap();
+        network
+            .add(
+                TEST_ENDPOINT_1,
+                TEST_ENDPOINT_3,
+                ChannelDirection::Outbound,
+                ChannelMode::Realtime,
+                Timestamp::new_test_instance(),
+            )
+            .unwrap();
+        channel1.set_mode(ChannelMode::Realtime);
+        channel1.set_protocol_version(20);
+        channel1.add_bytes_sent(100);
+        channel1.add_bytes_received(200);
+        channel1.set_rtt(Duration::from_millis(80));
+
+        let snapshot = network.channel_snapshot();
+
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].peer_addr, TEST_ENDPOINT_2);
+        assert_eq!(snapshot[0].mode, ChannelMode::Realtime);
+        assert_eq!(snapshot[0].direction, ChannelDirection::Inbound);
+        assert_eq!(snapshot[0].protocol_version, 20);
+        assert_eq!(snapshot[0].bytes_sent, 100);
+        assert_eq!(snapshot[0].bytes_received, 200);
+        assert_eq!(snapshot[0].average_rtt, Some(Duration::from_millis(80)));
+        assert_eq!(snapshot[1].peer_addr, TEST_ENDPOINT_3);
+        assert_eq!(snapshot[1].mode, ChannelMode::Undefined);
+        assert_eq!(snapshot[1].direction, ChannelDirection::Outbound);
+        assert_eq!(snapshot[1].average_rtt, None);
+    }
+
+    #[test]
+    fn set_protocol_versions() {
+        let mut network = NetworkInfo::new_test_instance();
+        let channels: Vec<_> = [TEST_ENDPOINT_1, TEST_ENDPOINT_2, TEST_ENDPOINT_3]
+            .iter()
+            .map(|peer| {
+                network
+                    .add(
+                        NULL_ENDPOINT,
+                        *peer,
+                        ChannelDirection::Inbound,
+                        ChannelMode::Realtime,
+                        Timestamp::new_test_instance(),
+                    )
+                    .unwrap()
+            })
+            .collect();
+
+        network.set_protocol_versions(&[
+            (channels[0].channel_id(), 18),
+            (channels[1].channel_id(), 19),
+            (channels[2].channel_id(), 20),
+        ]);
+
+        assert_eq!(channels[0].protocol_version(), 18);
+        assert_eq!(channels[1].protocol_version(), 19);
+        assert_eq!(channels[2].protocol_version(), 20);
+    }
+
     #[test]
     fn reserved_ip_is_not_a_peer() {
         let network = NetworkInfo::new_test_instance();
@@ -757,15 +1071,60 @@ mod tests {
 
         assert!(network
             .upgrade_to_realtime_connection(channel.channel_id(), NodeId::from(456))
-            .is_some());
+            .is_ok());
         assert_eq!(network.list_realtime_channels(0).len(), 1);
     }
 
+    #[test]
+    fn dont_upgrade_channel_with_outdated_protocol_version() {
+        let mut network = NetworkInfo::new_test_instance();
+        network.set_min_realtime_protocol_version(0x13);
+        let channel = network
+            .add(
+                TEST_ENDPOINT_1,
+                TEST_ENDPOINT_2,
+                ChannelDirection::Inbound,
+                ChannelMode::Undefined,
+                Timestamp::new_test_instance(),
+            )
+            .unwrap();
+        channel.set_protocol_version(0x12);
+
+        let result =
+            network.upgrade_to_realtime_connection(channel.channel_id(), NodeId::from(456));
+
+        assert_eq!(
+            result.err(),
+            Some(RealtimeUpgradeError::ProtocolVersionTooLow)
+        );
+        assert_eq!(channel.mode(), ChannelMode::Undefined);
+    }
+
+    #[test]
+    fn set_peering_addr_of_inbound_channel() {
+        let mut network = NetworkInfo::new_test_instance();
+        let channel = network
+            .add(
+                TEST_ENDPOINT_1,
+                TEST_ENDPOINT_2,
+                ChannelDirection::Inbound,
+                ChannelMode::Realtime,
+                Timestamp::new_test_instance(),
+            )
+            .unwrap();
+        assert!(!channel.has_peering_addr());
+
+        network.set_peering_addr(channel.channel_id(), TEST_ENDPOINT_3);
+
+        assert!(channel.has_peering_addr());
+        assert_eq!(channel.peering_addr(), Some(TEST_ENDPOINT_3));
+    }
+
     #[test]
     fn random_fill_peering_endpoints_empty() {
         let network = NetworkInfo::new_test_instance();
         let mut endpoints = [NULL_ENDPOINT; 3];
-        network.random_fill_realtime(&mut endpoints);
+        network.random_fill_realtime(&mut endpoints, Timestamp::new_test_instance());
         assert_eq!(endpoints, [NULL_ENDPOINT; 3]);
     }
 
@@ -775,7 +1134,7 @@ mod tests {
         add_realtime_channel_with_peering_addr(&mut network, TEST_ENDPOINT_1);
         add_realtime_channel_with_peering_addr(&mut network, TEST_ENDPOINT_2);
         let mut endpoints = [NULL_ENDPOINT; 3];
-        network.random_fill_realtime(&mut endpoints);
+        network.random_fill_realtime(&mut endpoints, Timestamp::new_test_instance());
         assert!(endpoints.contains(&TEST_ENDPOINT_1));
         assert!(endpoints.contains(&TEST_ENDPOINT_2));
         assert_eq!(endpoints[2], NULL_ENDPOINT);
@@ -788,12 +1147,26 @@ mod tests {
         add_realtime_channel_with_peering_addr(&mut network, TEST_ENDPOINT_2);
         add_realtime_channel_with_peering_addr(&mut network, TEST_ENDPOINT_3);
         let mut endpoints = [NULL_ENDPOINT; 3];
-        network.random_fill_realtime(&mut endpoints);
+        network.random_fill_realtime(&mut endpoints, Timestamp::new_test_instance());
         assert!(endpoints.contains(&TEST_ENDPOINT_1));
         assert!(endpoints.contains(&TEST_ENDPOINT_2));
         assert!(endpoints.contains(&TEST_ENDPOINT_3));
     }
 
+    #[test]
+    fn random_fill_omits_excluded_peers() {
+        let mut network = NetworkInfo::new_test_instance();
+        add_realtime_channel_with_peering_addr(&mut network, TEST_ENDPOINT_1);
+        add_realtime_channel_with_peering_addr(&mut network, TEST_ENDPOINT_2);
+        network.perma_ban(TEST_ENDPOINT_2);
+        let mut endpoints = [NULL_ENDPOINT; 3];
+
+        network.random_fill_realtime(&mut endpoints, Timestamp::new_test_instance());
+
+        assert!(endpoints.contains(&TEST_ENDPOINT_1));
+        assert!(!endpoints.contains(&TEST_ENDPOINT_2));
+    }
+
     fn add_realtime_channel_with_peering_addr(
         network: &mut NetworkInfo,
         peering_addr: SocketAddrV6,
@@ -808,10 +1181,12 @@ mod tests {
             )
             .unwrap();
         channel.set_peering_addr(peering_addr);
-        network.upgrade_to_realtime_connection(
-            channel.channel_id(),
-            NodeId::from(peering_addr.ip().to_bits()),
-        );
+        network
+            .upgrade_to_realtime_connection(
+                channel.channel_id(),
+                NodeId::from(peering_addr.ip().to_bits()),
+            )
+            .unwrap();
     }
 
     mod purging {
@@ -17,6 +17,10 @@ pub trait NetworkObserver: Send + Sync {
     fn attempt_cancelled(&self, _peer: SocketAddrV6) {}
     fn merge_peer(&self) {}
     fn accept_failure(&self) {}
+    /// All inbound slots are taken. Only fired once until a slot becomes available again
+    fn inbound_capacity_reached(&self) {}
+    /// An inbound slot became available after capacity was reached
+    fn inbound_slot_available(&self) {}
 }
 
 pub struct NullNetworkObserver {}
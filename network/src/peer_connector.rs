@@ -1,6 +1,6 @@
 use crate::{
     ChannelDirection, ChannelMode, Network, NetworkObserver, NullNetworkObserver,
-    NullResponseServerSpawner, ResponseServerSpawner,
+    NullResponseServerSpawner, ResponseServerSpawner, TcpSocketOptions,
 };
 use rsban_nullable_clock::SteadyClock;
 use rsban_nullable_tcp::TcpStream;
@@ -18,6 +18,7 @@ pub struct PeerConnector {
     response_server_spawner: Arc<dyn ResponseServerSpawner>,
     connect_listener: OutputListenerMt<SocketAddrV6>,
     clock: Arc<SteadyClock>,
+    socket_options: TcpSocketOptions,
 }
 
 impl PeerConnector {
@@ -30,6 +31,7 @@ impl PeerConnector {
         tokio: tokio::runtime::Handle,
         response_server_spawner: Arc<dyn ResponseServerSpawner>,
         clock: Arc<SteadyClock>,
+        socket_options: TcpSocketOptions,
     ) -> Self {
         Self {
             connect_timeout,
@@ -40,6 +42,7 @@ impl PeerConnector {
             response_server_spawner,
             connect_listener: OutputListenerMt::new(),
             clock,
+            socket_options,
         }
     }
 
@@ -53,6 +56,7 @@ impl PeerConnector {
             response_server_spawner: Arc::new(NullResponseServerSpawner::new()),
             connect_listener: OutputListenerMt::new(),
             clock: Arc::new(SteadyClock::new_null()),
+            socket_options: TcpSocketOptions::default(),
         }
     }
 
@@ -103,10 +107,11 @@ impl PeerConnector {
         let connect_timeout = self.connect_timeout;
         let cancel_token = self.cancel_token.clone();
         let observer = self.network_observer.clone();
+        let socket_options = self.socket_options;
 
         self.tokio.spawn(async move {
             tokio::select! {
-                result =  connect_impl(peer, &network_l, &*response_server_spawner_l) =>{
+                result =  connect_impl(peer, socket_options, &network_l, &*response_server_spawner_l) =>{
                     if let Err(e) = result {
                         observer.connect_error(peer, e);
                     }
@@ -135,10 +140,11 @@ impl PeerConnector {
 
 async fn connect_impl(
     peer: SocketAddrV6,
+    socket_options: TcpSocketOptions,
     network: &Network,
     response_server_spawner: &dyn ResponseServerSpawner,
 ) -> anyhow::Result<()> {
-    let tcp_stream = connect_stream(peer).await?;
+    let tcp_stream = connect_stream(peer, socket_options).await?;
 
     let channel = network.add(
         tcp_stream,
@@ -150,8 +156,12 @@ async fn connect_impl(
     Ok(())
 }
 
-async fn connect_stream(peer: SocketAddrV6) -> tokio::io::Result<TcpStream> {
+async fn connect_stream(
+    peer: SocketAddrV6,
+    socket_options: TcpSocketOptions,
+) -> tokio::io::Result<TcpStream> {
     let socket = tokio::net::TcpSocket::new_v6()?;
+    socket_options.apply(&socket)?;
     let tcp_stream = socket.connect(peer.into()).await?;
     Ok(TcpStream::new(tcp_stream))
 }
@@ -170,4 +180,12 @@ mod tests {
 
         assert_eq!(connect_tracker.output(), vec![TEST_ENDPOINT_1]);
     }
+
+    #[tokio::test]
+    async fn only_one_concurrent_dial_to_same_peer() {
+        let peer_connector = PeerConnector::new_null(tokio::runtime::Handle::current());
+
+        assert!(peer_connector.connect_to(TEST_ENDPOINT_1));
+        assert!(!peer_connector.connect_to(TEST_ENDPOINT_1));
+    }
 }
@@ -88,6 +88,15 @@ impl PeerExclusion {
         }
     }
 
+    /// Like `is_excluded`, but doesn't clean up expired entries
+    pub fn is_currently_excluded(&self, peer_addr: &SocketAddrV6, now: Timestamp) -> bool {
+        self.perma_bans.contains(peer_addr)
+            || self
+                .by_ip
+                .get(&peer_addr.ip())
+                .is_some_and(|peer| peer.is_excluded(now))
+    }
+
     fn remove(&mut self, endpoint: &SocketAddrV6) {
         if let Some(item) = self.by_ip.remove(&endpoint.ip()) {
             self.ordered_by_date
@@ -1,4 +1,7 @@
-use crate::{ChannelDirection, ChannelMode, Network, NetworkObserver, ResponseServerSpawner};
+use crate::{
+    ChannelDirection, ChannelMode, Network, NetworkObserver, ResponseServerSpawner,
+    TcpSocketOptions,
+};
 use async_trait::async_trait;
 use rsban_nullable_tcp::TcpStream;
 use std::{
@@ -23,6 +26,7 @@ pub struct TcpListener {
     condition: Condvar,
     cancel_token: CancellationToken,
     response_server_spawner: Arc<dyn ResponseServerSpawner>,
+    socket_options: TcpSocketOptions,
 }
 
 impl Drop for TcpListener {
@@ -43,6 +47,7 @@ impl TcpListener {
         network_observer: Arc<dyn NetworkObserver>,
         tokio: tokio::runtime::Handle,
         response_server_spawner: Arc<dyn ResponseServerSpawner>,
+        socket_options: TcpSocketOptions,
     ) -> Self {
         Self {
             port: AtomicU16::new(port),
@@ -56,6 +61,7 @@ impl TcpListener {
             condition: Condvar::new(),
             cancel_token: CancellationToken::new(),
             response_server_spawner,
+            socket_options,
         }
     }
 
@@ -75,6 +81,16 @@ impl TcpListener {
     }
 }
 
+/// Accepted sockets inherit the options of the listening socket
+fn bind(port: u16, socket_options: TcpSocketOptions) -> std::io::Result<tokio::net::TcpListener> {
+    let socket = tokio::net::TcpSocket::new_v6()?;
+    #[cfg(unix)]
+    socket.set_reuseaddr(true)?;
+    socket_options.apply(&socket)?;
+    socket.bind(SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), port))?;
+    socket.listen(1024)
+}
+
 #[async_trait]
 pub trait TcpListenerExt {
     fn start(&self);
@@ -88,12 +104,7 @@ impl TcpListenerExt for Arc<TcpListener> {
         let self_l = Arc::clone(self);
         self.tokio.spawn(async move {
             let port = self_l.port.load(Ordering::SeqCst);
-            let Ok(listener) = tokio::net::TcpListener::bind(SocketAddr::new(
-                IpAddr::V6(Ipv6Addr::UNSPECIFIED),
-                port,
-            ))
-            .await
-            else {
+            let Ok(listener) = bind(port, self_l.socket_options) else {
                 error!("Error while binding for incoming connections on: {}", port);
                 return;
             };
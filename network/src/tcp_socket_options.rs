@@ -0,0 +1,70 @@
+use tokio::net::TcpSocket;
+
+/// TCP level options which get applied to every socket the node creates.
+/// Options which are not set keep the defaults of the operating system.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TcpSocketOptions {
+    nodelay: Option<bool>,
+    keepalive: Option<bool>,
+}
+
+impl TcpSocketOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Enables or disables Nagle's algorithm (TCP_NODELAY)
+    pub fn nodelay(mut self, nodelay: bool) -> Self {
+        self.nodelay = Some(nodelay);
+        self
+    }
+
+    /// Enables or disables OS level keepalive probes (SO_KEEPALIVE)
+    pub fn keepalive(mut self, keepalive: bool) -> Self {
+        self.keepalive = Some(keepalive);
+        self
+    }
+
+    pub fn apply(&self, socket: &TcpSocket) -> std::io::Result<()> {
+        if let Some(nodelay) = self.nodelay {
+            socket.set_nodelay(nodelay)?;
+        }
+        if let Some(keepalive) = self.keepalive {
+            socket.set_keepalive(keepalive)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn apply_nodelay() {
+        let socket = TcpSocket::new_v6().unwrap();
+        TcpSocketOptions::new()
+            .nodelay(true)
+            .apply(&socket)
+            .unwrap();
+        assert!(socket.nodelay().unwrap());
+
+        TcpSocketOptions::new()
+            .nodelay(false)
+            .apply(&socket)
+            .unwrap();
+        assert!(!socket.nodelay().unwrap());
+    }
+
+    #[tokio::test]
+    async fn keep_os_defaults_when_nothing_set() {
+        let socket = TcpSocket::new_v6().unwrap();
+        let nodelay = socket.nodelay().unwrap();
+        let keepalive = socket.keepalive().unwrap();
+
+        TcpSocketOptions::new().apply(&socket).unwrap();
+
+        assert_eq!(socket.nodelay().unwrap(), nodelay);
+        assert_eq!(socket.keepalive().unwrap(), keepalive);
+    }
+}
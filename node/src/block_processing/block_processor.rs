@@ -319,6 +319,17 @@ impl BlockProcessor {
         self.processor_loop.info()
     }
 
+    /// Checks if the block would be accepted, without touching the ledger or any caches.
+    /// Returns `BlockStatus::Progress` if the block is valid
+    pub fn validate_only(&self, block: &Block) -> BlockStatus {
+        let ledger = &self.processor_loop.ledger;
+        let txn = ledger.read_txn();
+        match ledger.validate(&txn, block) {
+            Ok(()) => BlockStatus::Progress,
+            Err(status) => status,
+        }
+    }
+
     pub fn container_info(&self) -> ContainerInfo {
         self.processor_loop.container_info()
     }
@@ -796,6 +807,7 @@ impl DeadChannelCleanupStep for BlockProcessorCleanup {
 mod tests {
     use super::*;
     use crate::stats::Direction;
+    use rsban_core::{BlockHash, SavedAccountChain};
 
     #[test]
     fn insufficient_work() {
@@ -821,4 +833,34 @@ mod tests {
 
         assert_eq!(block_processor.total_queue_len(), 0);
     }
+
+    #[test]
+    fn validate_only() {
+        let chain = SavedAccountChain::new_opened_chain();
+        let ledger = Arc::new(
+            Ledger::new_null_builder()
+                .blocks(chain.blocks())
+                .account_info(&chain.account(), &chain.account_info())
+                .finish(),
+        );
+        let block_processor = BlockProcessor::new_test_instance(ledger.clone());
+        let valid = chain.new_state_block().build();
+        let gap = chain
+            .new_state_block()
+            .previous(BlockHash::from(12345))
+            .build();
+
+        assert_eq!(block_processor.validate_only(&valid), BlockStatus::Progress);
+        assert_eq!(
+            block_processor.validate_only(&gap),
+            BlockStatus::GapPrevious
+        );
+
+        let txn = ledger.read_txn();
+        assert!(!ledger.any().block_exists(&txn, &valid.hash()));
+        assert_eq!(
+            ledger.any().account_head(&txn, &chain.account()),
+            Some(chain.frontier())
+        );
+    }
 }
@@ -31,8 +31,8 @@ use rsban_core::{
 };
 use rsban_ledger::{BlockStatus, Ledger};
 use rsban_messages::{
-    AccountInfoAckPayload, AccountInfoReqPayload, AscPullAck, AscPullAckType, AscPullReq,
-    AscPullReqType, BlocksAckPayload, BlocksReqPayload, HashType, Message,
+    AccountInfoAckPayload, AscPullAck, AscPullAckType, AscPullReq, AscPullReqType,
+    BlocksAckPayload, BlocksReqPayload, HashType, Message,
 };
 use rsban_network::{
     bandwidth_limiter::RateLimiter, ChannelId, DropPolicy, NetworkInfo, TrafficType,
@@ -139,7 +139,7 @@ impl BootstrapAscending {
         self.message_publisher.lock().unwrap().try_send(
             channel_id,
             &request,
-            DropPolicy::CanDrop,
+            DropPolicy::Default,
             TrafficType::Bootstrap,
         );
     }
@@ -153,7 +153,7 @@ impl BootstrapAscending {
             guard.tags.insert(tag.clone());
         }
 
-        let req_type = match tag.query_type {
+        let request = match tag.query_type {
             QueryType::BlocksByHash | QueryType::BlocksByAccount => {
                 let start_type = if tag.query_type == QueryType::BlocksByHash {
                     HashType::Block
@@ -161,23 +161,23 @@ impl BootstrapAscending {
                     HashType::Account
                 };
 
-                AscPullReqType::Blocks(BlocksReqPayload {
-                    start_type,
-                    start: tag.start,
-                    count: tag.count as u8,
-                })
+                AscPullReq {
+                    id: tag.id,
+                    req_type: AscPullReqType::Blocks(BlocksReqPayload {
+                        start_type,
+                        start: tag.start,
+                        count: tag.count as u8,
+                    }),
+                }
+            }
+            // Query account info by block hash
+            QueryType::AccountInfoByHash => {
+                AscPullReq::account_info(tag.id, tag.start, HashType::Block)
             }
-            QueryType::AccountInfoByHash => AscPullReqType::AccountInfo(AccountInfoReqPayload {
-                target: tag.start,
-                target_type: HashType::Block, // Query account info by block hash
-            }),
             QueryType::Invalid => panic!("invalid query type"),
         };
 
-        Message::AscPullReq(AscPullReq {
-            id: tag.id,
-            req_type,
-        })
+        Message::AscPullReq(request)
     }
 
     pub fn priority_len(&self) -> usize {
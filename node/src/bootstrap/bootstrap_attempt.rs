@@ -124,7 +124,7 @@ impl BootstrapAttempt {
                 id: self.id.clone(),
                 mode: self.mode,
                 total_blocks: self.total_blocks.load(Ordering::SeqCst),
-                duration: self.duration(),
+                duration: self.elapsed(),
             });
 
         Ok(())
@@ -199,9 +199,27 @@ impl BootstrapAttempt {
         running && still_pulling
     }
 
-    pub fn duration(&self) -> Duration {
+    pub fn elapsed(&self) -> Duration {
         self.attempt_start.elapsed()
     }
+
+    /// Estimates the time left until `total_expected` blocks are processed,
+    /// based on the block rate of this attempt so far
+    pub fn estimated_remaining(&self, total_expected: u64) -> Option<Duration> {
+        estimate_remaining(
+            self.elapsed(),
+            self.total_blocks.load(Ordering::SeqCst),
+            total_expected,
+        )
+    }
+}
+
+fn estimate_remaining(elapsed: Duration, processed: u64, total_expected: u64) -> Option<Duration> {
+    if processed == 0 {
+        return None;
+    }
+    let remaining = total_expected.saturating_sub(processed);
+    Some(elapsed.mul_f64(remaining as f64 / processed as f64))
 }
 
 impl Drop for BootstrapAttempt {
@@ -217,7 +235,7 @@ impl Drop for BootstrapAttempt {
                 id: self.id.clone(),
                 mode: self.mode,
                 total_blocks: self.total_blocks.load(Ordering::SeqCst),
-                duration: self.duration(),
+                duration: self.elapsed(),
             });
     }
 }
@@ -295,3 +313,26 @@ pub struct BootstrapCallbackData {
     pub total_blocks: u64,
     pub duration: Duration,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_estimate_without_processed_blocks() {
+        assert_eq!(estimate_remaining(Duration::from_secs(10), 0, 1000), None);
+    }
+
+    #[test]
+    fn estimate_from_block_rate() {
+        // 100 blocks per second
+        let remaining = estimate_remaining(Duration::from_secs(10), 1000, 5000);
+        assert_eq!(remaining, Some(Duration::from_secs(40)));
+    }
+
+    #[test]
+    fn nothing_remaining_when_expected_total_reached() {
+        let remaining = estimate_remaining(Duration::from_secs(10), 1000, 800);
+        assert_eq!(remaining, Some(Duration::ZERO));
+    }
+}
@@ -1,17 +1,23 @@
 use super::BootstrapStrategy;
-use std::{collections::HashMap, sync::Arc, usize};
+use std::{collections::HashMap, fmt, sync::Arc, usize};
 
 /// WARNING: BootstrapAttempts is not connected to the C++ version yet!
 /// Container for bootstrap sessions that are active. Owned by `BootstrapInitiator`.
 pub struct BootstrapAttempts {
     incremental: usize,
+    max_attempts: usize,
     attempts: HashMap<usize, Arc<BootstrapStrategy>>,
 }
 
 impl BootstrapAttempts {
     pub fn new() -> Self {
+        Self::with_max_attempts(usize::MAX)
+    }
+
+    pub fn with_max_attempts(max_attempts: usize) -> Self {
         Self {
             incremental: 0,
+            max_attempts,
             attempts: HashMap::new(),
         }
     }
@@ -22,6 +28,17 @@ impl BootstrapAttempts {
         id
     }
 
+    /// Returns the id for a new attempt, unless the maximum number of
+    /// concurrent attempts is already running
+    pub fn next_attempt_id(&mut self) -> Result<usize, AttemptLimitReached> {
+        if self.attempts.len() >= self.max_attempts {
+            return Err(AttemptLimitReached {
+                max_attempts: self.max_attempts,
+            });
+        }
+        Ok(self.get_incremental_id())
+    }
+
     pub fn add(&mut self, attempt: Arc<BootstrapStrategy>) {
         self.attempts
             .insert(attempt.incremental_id() as usize, attempt);
@@ -47,3 +64,20 @@ impl BootstrapAttempts {
         self.incremental
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttemptLimitReached {
+    pub max_attempts: usize,
+}
+
+impl fmt::Display for AttemptLimitReached {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Maximum number of concurrent bootstrap attempts reached ({})",
+            self.max_attempts
+        )
+    }
+}
+
+impl std::error::Error for AttemptLimitReached {}
@@ -29,6 +29,7 @@ use std::{
     thread::JoinHandle,
     time::Duration,
 };
+use tracing::warn;
 
 #[derive(Clone)]
 pub struct BootstrapInitiatorConfig {
@@ -49,6 +50,8 @@ pub struct BootstrapInitiatorConfig {
     pub disable_bulk_push_client: bool,
     pub bootstrap_initiator_threads: u32,
     pub receive_minimum: Amount,
+    /// Maximum number of bootstrap attempts that may run at the same time
+    pub max_attempts: usize,
 }
 
 impl BootstrapInitiatorConfig {
@@ -70,6 +73,7 @@ impl BootstrapInitiatorConfig {
             disable_bulk_push_client: false,
             bootstrap_initiator_threads: 1,
             receive_minimum: Amount::micronano(1),
+            max_attempts: 4,
         }
     }
 }
@@ -121,7 +125,9 @@ impl BootstrapInitiator {
         message_publisher: MessagePublisher,
         clock: Arc<SteadyClock>,
     ) -> Self {
-        let attempts = Arc::new(Mutex::new(BootstrapAttempts::new()));
+        let attempts = Arc::new(Mutex::new(BootstrapAttempts::with_max_attempts(
+            config.max_attempts,
+        )));
         let cache = Arc::new(Mutex::new(PullsCache::new()));
         Self {
             mutex: Mutex::new(Data {
@@ -204,6 +210,23 @@ impl BootstrapInitiator {
         self.condition.notify_all();
     }
 
+    /// Reserves an id for a new attempt. Returns None if too many attempts
+    /// are running already
+    fn next_attempt_id(&self) -> Option<usize> {
+        match self.attempts.lock().unwrap().next_attempt_id() {
+            Ok(id) => Some(id),
+            Err(e) => {
+                self.stats.inc_dir(
+                    StatType::Bootstrap,
+                    DetailType::AttemptLimitReached,
+                    Direction::Out,
+                );
+                warn!("{}", e);
+                None
+            }
+        }
+    }
+
     pub fn current_legacy_attempt(&self) -> Option<Arc<BootstrapStrategy>> {
         let guard = self.mutex.lock().unwrap();
         guard.find_attempt(BootstrapMode::Legacy)
@@ -324,7 +347,9 @@ impl BootstrapInitiatorExt for Arc<BootstrapInitiator> {
                 },
                 Direction::Out,
             );
-            let incremental_id = self.attempts.lock().unwrap().get_incremental_id();
+            let Some(incremental_id) = self.next_attempt_id() else {
+                return;
+            };
             let self_w = Arc::downgrade(self);
             let legacy_attempt = Arc::new(
                 BootstrapAttemptLegacy::new(
@@ -362,7 +387,9 @@ impl BootstrapInitiatorExt for Arc<BootstrapInitiator> {
                 .inc_dir(StatType::Bootstrap, DetailType::Initiate, Direction::Out);
             let mut guard = self.mutex.lock().unwrap();
             let self_w = Arc::downgrade(self);
-            let incremental_id = self.attempts.lock().unwrap().get_incremental_id();
+            let Some(incremental_id) = self.next_attempt_id() else {
+                return;
+            };
             let legacy_attempt = Arc::new(
                 BootstrapAttemptLegacy::new(
                     Arc::downgrade(&self.block_processor),
@@ -415,7 +442,9 @@ impl BootstrapInitiatorExt for Arc<BootstrapInitiator> {
             if !self.stopped.load(Ordering::SeqCst)
                 && guard.find_attempt(BootstrapMode::Lazy).is_none()
             {
-                let incremental_id = self.attempts.lock().unwrap().get_incremental_id();
+                let Some(incremental_id) = self.next_attempt_id() else {
+                    return false;
+                };
 
                 let bootstrap_id = if id.is_empty() {
                     hash_or_account.to_string()
@@ -473,7 +502,9 @@ impl BootstrapInitiatorExt for Arc<BootstrapInitiator> {
             } else {
                 "".to_string()
             };
-            let incremental_id = self.attempts.lock().unwrap().get_incremental_id();
+            let Some(incremental_id) = self.next_attempt_id() else {
+                return;
+            };
             let wallet_attempt = Arc::new(
                 BootstrapAttemptWallet::new(
                     self.block_processor.clone(),
@@ -1,6 +1,7 @@
 use super::{
     bootstrap_limits, BootstrapAttempt, BootstrapAttemptTrait, BootstrapCallbacks,
     BootstrapConnections, BootstrapConnectionsExt, BootstrapInitiator, BootstrapMode,
+    BootstrapPriorityQueue,
 };
 use crate::{
     block_processing::{BlockProcessor, BlockSource},
@@ -15,7 +16,7 @@ use rsban_network::ChannelId;
 use rsban_store_lmdb::Transaction;
 use std::{
     cmp::max,
-    collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
     hash::{Hash, Hasher},
     sync::{atomic::Ordering, Arc, Mutex, MutexGuard, Weak},
     time::{Duration, Instant},
@@ -46,7 +47,9 @@ struct LazyData {
     lazy_blocks: HashSet<u64>,
     lazy_start_time: Instant,
     lazy_blocks_count: usize,
-    lazy_pulls: VecDeque<(HashOrAccount, u32)>,
+    /// Pending pulls scored by the amount they are expected to receive, so
+    /// that high value chains get pulled first
+    lazy_pulls: BootstrapPriorityQueue<(HashOrAccount, u32)>,
     lazy_undefined_links: HashSet<BlockHash>,
     lazy_state_backlog: HashMap<BlockHash, LazyStateBacklogItem>,
     lazy_keys: HashSet<BlockHash>,
@@ -77,10 +80,11 @@ impl LazyData {
         }
     }
 
-    fn lazy_add(&mut self, hash_or_account: HashOrAccount, retry_limit: u32) {
+    fn lazy_add(&mut self, hash_or_account: HashOrAccount, retry_limit: u32, amount: Amount) {
         // Add only unknown blocks
         if !self.lazy_blocks_processed(&hash_or_account.into()) {
-            self.lazy_pulls.push_back((hash_or_account, retry_limit));
+            self.lazy_pulls
+                .push((hash_or_account, retry_limit), amount.number());
         }
     }
 
@@ -101,26 +105,31 @@ impl LazyData {
                 return true;
             }
 
-            let mut lazy_add = |hash_or_account: HashOrAccount, retry_limit: u32| {
-                // Add only unknown blocks
-                if !lazy_blocks.contains(&u64_hash(&hash_or_account.into())) {
-                    lazy_pulls.push_back((hash_or_account, retry_limit));
-                }
-            };
+            let mut lazy_add =
+                |hash_or_account: HashOrAccount, retry_limit: u32, amount: Amount| {
+                    // Add only unknown blocks
+                    if !lazy_blocks.contains(&u64_hash(&hash_or_account.into())) {
+                        lazy_pulls.push((hash_or_account, retry_limit), amount.number());
+                    }
+                };
 
             let mut retain = true;
             if ledger.any().block_exists_or_pruned(&txn, hash) {
                 if let Some(balance) = ledger.any().block_balance(&txn, hash) {
                     if balance <= next_block.balance {
-                        lazy_add(next_block.link, next_block.retry_limit);
+                        lazy_add(
+                            next_block.link,
+                            next_block.retry_limit,
+                            next_block.balance - balance,
+                        );
                     }
                 } else {
                     // Not confirmed
-                    lazy_add(next_block.link, self.lazy_retry_limit);
+                    lazy_add(next_block.link, self.lazy_retry_limit, Amount::zero());
                 }
                 retain = false;
             } else {
-                lazy_add((*hash).into(), next_block.retry_limit);
+                lazy_add((*hash).into(), next_block.retry_limit, Amount::zero());
             }
             // We don't want to open read transactions for too long
             read_count += 1;
@@ -327,13 +336,13 @@ impl BootstrapAttemptLazy {
             let previous = block.previous();
             // If state block previous is 0 then source block required
             if previous.is_zero() {
-                data.lazy_add(link.into(), retry_limit);
+                data.lazy_add(link.into(), retry_limit, balance);
             }
             // In other cases previous block balance required to find out subtype of state block
             else if self.ledger.any().block_exists_or_pruned(&txn, &previous) {
                 if let Some(previous_balance) = self.ledger.any().block_balance(&txn, &previous) {
                     if previous_balance <= balance {
-                        data.lazy_add(link.into(), retry_limit);
+                        data.lazy_add(link.into(), retry_limit, balance - previous_balance);
                     }
                 }
                 // Else ignore pruned blocks
@@ -342,7 +351,7 @@ impl BootstrapAttemptLazy {
             else if data.lazy_blocks_processed(&previous) {
                 if let Some(previous_balance) = data.lazy_balances.get(&previous) {
                     if *previous_balance <= balance {
-                        data.lazy_add(link.into(), retry_limit);
+                        data.lazy_add(link.into(), retry_limit, balance - *previous_balance);
                     }
                     data.lazy_balances.remove(&previous);
                 }
@@ -374,7 +383,7 @@ impl BootstrapAttemptLazy {
             let mut count = 0;
             let mut txn = self.ledger.read_txn();
             while !data.lazy_pulls.is_empty() && count < MAX_PULLS {
-                let pull_start = data.lazy_pulls.pop_front().unwrap();
+                let pull_start = data.lazy_pulls.pop().unwrap();
                 // Recheck if block was already processed
                 if !data.lazy_blocks_processed(&pull_start.0.into())
                     && !self
@@ -483,10 +492,13 @@ impl BootstrapAttemptLazy {
             && !data.lazy_blocks_processed(&hash_or_account.into())
         {
             data.lazy_keys.insert(hash_or_account.into());
-            data.lazy_pulls.push_back((
-                *hash_or_account,
-                self.network_params.bootstrap.lazy_retry_limit,
-            ));
+            data.lazy_pulls.push(
+                (
+                    *hash_or_account,
+                    self.network_params.bootstrap.lazy_retry_limit,
+                ),
+                0,
+            );
             drop(data);
             drop(lock);
             self.attempt.condition.notify_all();
@@ -500,7 +512,7 @@ impl BootstrapAttemptLazy {
         debug_assert_eq!(BlockHash::from(pull.account_or_head), pull.head);
         let _lock = self.attempt.mutex.lock().unwrap();
         let mut data = self.data.lock().unwrap();
-        data.lazy_add(pull.account_or_head, pull.retry_limit);
+        data.lazy_add(pull.account_or_head, pull.retry_limit, Amount::zero());
     }
 
     pub fn lazy_requeue(&self, hash: &BlockHash, previous: &BlockHash) {
@@ -586,7 +598,7 @@ impl BootstrapAttemptTrait for BootstrapAttemptLazy {
     }
 
     fn duration(&self) -> Duration {
-        self.attempt.duration()
+        self.attempt.elapsed()
     }
 
     fn set_started(&self) -> bool {
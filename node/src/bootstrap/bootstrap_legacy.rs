@@ -354,7 +354,7 @@ impl BootstrapAttemptTrait for Arc<BootstrapAttemptLegacy> {
     }
 
     fn duration(&self) -> Duration {
-        self.attempt.duration()
+        self.attempt.elapsed()
     }
 
     fn set_started(&self) -> bool {
@@ -0,0 +1,112 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+/// Queue of pending pulls which pops the entry with the highest score first.
+/// Entries with equal scores are popped in insertion order.
+pub struct BootstrapPriorityQueue<T> {
+    entries: BinaryHeap<Entry<T>>,
+    next_sequence: u64,
+}
+
+impl<T> BootstrapPriorityQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            entries: BinaryHeap::new(),
+            next_sequence: 0,
+        }
+    }
+
+    pub fn push(&mut self, item: T, score: u128) {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        self.entries.push(Entry {
+            item,
+            score,
+            sequence,
+        });
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.entries.pop().map(|e| e.item)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T> Default for BootstrapPriorityQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Entry<T> {
+    item: T,
+    score: u128,
+    sequence: u64,
+}
+
+impl<T> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score
+            .cmp(&other.score)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl<T> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Entry<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsban_core::Account;
+
+    #[test]
+    fn empty() {
+        let mut queue = BootstrapPriorityQueue::<Account>::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn pop_highest_score_first() {
+        let mut queue = BootstrapPriorityQueue::new();
+        queue.push(Account::from(1), 10);
+        queue.push(Account::from(2), 1000);
+        queue.push(Account::from(3), 100);
+
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop(), Some(Account::from(2)));
+        assert_eq!(queue.pop(), Some(Account::from(3)));
+        assert_eq!(queue.pop(), Some(Account::from(1)));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn keep_insertion_order_for_equal_scores() {
+        let mut queue = BootstrapPriorityQueue::new();
+        queue.push(Account::from(1), 5);
+        queue.push(Account::from(2), 5);
+        queue.push(Account::from(3), 5);
+
+        assert_eq!(queue.pop(), Some(Account::from(1)));
+        assert_eq!(queue.pop(), Some(Account::from(2)));
+        assert_eq!(queue.pop(), Some(Account::from(3)));
+    }
+}
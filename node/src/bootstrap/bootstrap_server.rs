@@ -412,7 +412,7 @@ impl BootstrapServerImpl {
         self.message_publisher.lock().unwrap().try_send(
             channel_id,
             &msg,
-            DropPolicy::CanDrop,
+            DropPolicy::Default,
             TrafficType::Bootstrap,
         );
     }
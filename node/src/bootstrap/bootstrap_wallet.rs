@@ -193,7 +193,7 @@ impl BootstrapAttemptTrait for Arc<BootstrapAttemptWallet> {
     }
 
     fn duration(&self) -> Duration {
-        self.attempt.duration()
+        self.attempt.elapsed()
     }
 
     fn set_started(&self) -> bool {
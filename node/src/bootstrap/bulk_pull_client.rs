@@ -150,7 +150,12 @@ impl BulkPullClientExt for Arc<BulkPullClient> {
         );
 
         if self.attempt.should_log() {
-            debug!("Accounts in pull queue: {}", self.attempt.pulling());
+            debug!(
+                "Accounts in pull queue: {} (blocks: {}, elapsed: {}s)",
+                self.attempt.pulling(),
+                self.attempt.total_blocks(),
+                self.attempt.duration().as_secs()
+            );
         }
 
         let self_clone = Arc::clone(self);
@@ -69,10 +69,6 @@ struct FrontierReqServerImpl {
 }
 
 impl FrontierReqServerImpl {
-    pub fn send_confirmed(&self) -> bool {
-        self.request.only_confirmed
-    }
-
     pub fn send_next(&mut self, server: Arc<Mutex<FrontierReqServerImpl>>) {
         if !self.current.is_zero() && self.count < self.request.count as usize {
             trace!(
@@ -132,47 +128,13 @@ impl FrontierReqServerImpl {
     pub fn next(&mut self) {
         // Filling accounts deque to prevent often read transactions
         if self.accounts.is_empty() {
-            let now = seconds_since_epoch();
-            let disable_age_filter = self.request.age == u32::MAX;
-            let max_size = 128;
-            let transaction = self.ledger.read_txn();
-            if !self.send_confirmed() {
-                for (account, info) in self
-                    .ledger
-                    .any()
-                    .accounts_range(&transaction, self.current.inc().unwrap_or_default()..)
-                {
-                    if self.accounts.len() >= max_size {
-                        break;
-                    }
-                    if disable_age_filter || (now - info.modified) <= self.request.age as u64 {
-                        self.accounts.push_back((account, info.head))
-                    }
-                }
-            } else {
-                let mut i = self.ledger.store.confirmation_height.begin_at_account(
-                    &transaction,
-                    &self.current.number().overflowing_add(1.into()).0.into(),
-                );
-                while let Some((account, info)) = i.current() {
-                    if self.accounts.len() >= max_size {
-                        break;
-                    }
-
-                    let confirmed_frontier = info.frontier;
-                    if !confirmed_frontier.is_zero() {
-                        self.accounts.push_back((*account, confirmed_frontier));
-                    }
-
-                    i.next();
-                }
-            }
-
-            /* If loop breaks before max_size, then accounts_end () is reached. Add empty record to finish frontier_req_server */
-            if self.accounts.len() != max_size {
-                self.accounts
-                    .push_back((Account::zero(), BlockHash::zero()));
-            }
+            self.accounts = load_frontiers(
+                &self.ledger,
+                &self.request,
+                self.current,
+                seconds_since_epoch(),
+                128,
+            );
         }
 
         // Retrieving accounts from deque
@@ -194,3 +156,113 @@ impl FrontierReqServerImpl {
         }));
     }
 }
+
+/// Loads the next batch of account frontiers after `current`. If the request
+/// has the only_confirmed flag set, the confirmed frontiers are returned instead of the heads
+fn load_frontiers(
+    ledger: &Ledger,
+    request: &FrontierReq,
+    current: Account,
+    now: u64,
+    max_size: usize,
+) -> VecDeque<(Account, BlockHash)> {
+    let mut accounts = VecDeque::new();
+    let disable_age_filter = request.age == u32::MAX;
+    let transaction = ledger.read_txn();
+    if !request.only_confirmed {
+        for (account, info) in ledger
+            .any()
+            .accounts_range(&transaction, current.inc().unwrap_or_default()..)
+        {
+            if accounts.len() >= max_size {
+                break;
+            }
+            if disable_age_filter || (now - info.modified) <= request.age as u64 {
+                accounts.push_back((account, info.head))
+            }
+        }
+    } else {
+        let mut i = ledger.store.confirmation_height.begin_at_account(
+            &transaction,
+            &current.number().overflowing_add(1.into()).0.into(),
+        );
+        while let Some((account, info)) = i.current() {
+            if accounts.len() >= max_size {
+                break;
+            }
+
+            let confirmed_frontier = info.frontier;
+            if !confirmed_frontier.is_zero() {
+                accounts.push_back((*account, confirmed_frontier));
+            }
+
+            i.next();
+        }
+    }
+
+    /* If loop breaks before max_size, then accounts_end () is reached. Add empty record to finish frontier_req_server */
+    if accounts.len() != max_size {
+        accounts.push_back((Account::zero(), BlockHash::zero()));
+    }
+    accounts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsban_core::{ConfirmationHeightInfo, SavedAccountChain};
+
+    #[test]
+    fn send_heads() {
+        let (chain, ledger) = ledger_with_unconfirmed_blocks();
+
+        let frontiers = load_frontiers(&ledger, &request(false), Account::zero(), 0, 128);
+
+        assert_eq!(
+            frontiers,
+            [
+                (chain.account(), chain.frontier()),
+                (Account::zero(), BlockHash::zero())
+            ]
+        );
+    }
+
+    #[test]
+    fn send_only_confirmed_frontiers() {
+        let (chain, ledger) = ledger_with_unconfirmed_blocks();
+
+        let frontiers = load_frontiers(&ledger, &request(true), Account::zero(), 0, 128);
+
+        assert_eq!(
+            frontiers,
+            [
+                (chain.account(), chain.open()),
+                (Account::zero(), BlockHash::zero())
+            ]
+        );
+    }
+
+    fn request(only_confirmed: bool) -> FrontierReq {
+        FrontierReq {
+            start: Account::zero(),
+            age: u32::MAX,
+            count: u32::MAX,
+            only_confirmed,
+        }
+    }
+
+    fn ledger_with_unconfirmed_blocks() -> (SavedAccountChain, Ledger) {
+        let mut chain = SavedAccountChain::new_opened_chain();
+        chain.add_state();
+        chain.add_state();
+        let ledger = Ledger::new_null_builder()
+            .blocks(chain.blocks())
+            .account_info(&chain.account(), &chain.account_info())
+            .confirmation_height(
+                &chain.account(),
+                &ConfirmationHeightInfo::new(1, chain.open()),
+            )
+            .finish();
+        (chain, ledger)
+    }
+}
@@ -6,6 +6,7 @@ mod bootstrap_connections;
 mod bootstrap_initiator;
 mod bootstrap_lazy;
 mod bootstrap_legacy;
+mod bootstrap_priority_queue;
 mod bootstrap_server;
 mod bootstrap_wallet;
 mod bulk_pull_account_client;
@@ -27,6 +28,7 @@ pub use bootstrap_connections::*;
 pub use bootstrap_initiator::*;
 pub use bootstrap_lazy::*;
 pub use bootstrap_legacy::*;
+pub use bootstrap_priority_queue::BootstrapPriorityQueue;
 pub use bootstrap_server::*;
 pub use bootstrap_wallet::*;
 pub use bulk_pull_account_client::*;
@@ -49,6 +49,7 @@ impl From<&GlobalConfig> for BootstrapInitiatorConfig {
             disable_bulk_push_client: value.flags.disable_bootstrap_bulk_push_client,
             bootstrap_initiator_threads: value.node_config.bootstrap_initiator_threads,
             receive_minimum: value.node_config.receive_minimum,
+            max_attempts: value.node_config.bootstrap_max_attempts,
         }
     }
 }
@@ -72,7 +73,10 @@ impl From<&GlobalConfig> for NetworkConfig {
             disable_max_peers_per_subnetwork: value.flags.disable_max_peers_per_subnetwork,
             disable_network: value.flags.disable_tcp_realtime,
             min_protocol_version: value.network_params.network.protocol_info().version_min,
+            min_realtime_protocol_version: value.network_params.network.protocol_info().version_min,
             listening_port: value.node_config.peering_port.unwrap_or(0),
+            send_timeout: value.node_config.tcp.send_timeout,
+            max_send_failures: value.node_config.tcp.max_send_failures,
         }
     }
 }
@@ -19,6 +19,7 @@ use rsban_core::{
     utils::{get_env_or_default_string, is_sanitizer_build, Peer},
     Account, Amount, PublicKey,
 };
+use rsban_network::TrafficPolicyConfig;
 use rsban_store_lmdb::LmdbConfig;
 use std::{cmp::max, net::Ipv6Addr, time::Duration};
 
@@ -46,6 +47,7 @@ pub struct NodeConfig {
     pub bootstrap_connections: u32,
     pub bootstrap_connections_max: u32,
     pub bootstrap_initiator_threads: u32,
+    pub bootstrap_max_attempts: usize,
     pub bootstrap_serving_threads: u32,
     pub bootstrap_frontier_request_count: u32,
     pub block_processor_batch_max_time_ms: i64,
@@ -68,6 +70,8 @@ pub struct NodeConfig {
     pub bootstrap_server: BootstrapServerConfig,
     pub bootstrap_bandwidth_limit: usize,
     pub bootstrap_bandwidth_burst_ratio: f64,
+    /// Drop policies for messages which are sent with `DropPolicy::Default`
+    pub traffic_policy: TrafficPolicyConfig,
     pub confirming_set_batch_time: Duration,
     pub backup_before_upgrade: bool,
     pub max_work_generate_multiplier: f64,
@@ -244,6 +248,7 @@ impl NodeConfig {
             bootstrap_connections: bootstrap_initiator_cfg.bootstrap_connections,
             bootstrap_connections_max: bootstrap_initiator_cfg.bootstrap_connections_max,
             bootstrap_initiator_threads: 1,
+            bootstrap_max_attempts: bootstrap_initiator_cfg.max_attempts,
             bootstrap_serving_threads: 1,
             bootstrap_frontier_request_count: bootstrap_initiator_cfg.frontier_request_count,
             block_processor_batch_max_time_ms: block_processor_cfg.batch_max_time.as_millis()
@@ -275,6 +280,7 @@ impl NodeConfig {
             bootstrap_bandwidth_limit: 5 * 1024 * 1024,
             // Bootstrap traffic does not need bursts
             bootstrap_bandwidth_burst_ratio: 1.,
+            traffic_policy: TrafficPolicyConfig::default(),
             bootstrap_ascending: Default::default(),
             bootstrap_server: Default::default(),
             confirming_set_batch_time: Duration::from_millis(250),
@@ -77,6 +77,7 @@ mod tests {
         bootstrap_connections = 999
         bootstrap_connections_max = 999
         bootstrap_initiator_threads = 999
+        bootstrap_max_attempts = 999
         bootstrap_serving_threads = 999
         bootstrap_frontier_request_count = 9999
         bootstrap_fraction_numerator = 999
@@ -173,6 +174,9 @@ mod tests {
 
         [node.statistics]
         max_samples = 999
+        window_bucket_size_ms = 999
+        max_window_ms = 999
+        instance_label = "dev"
 
         [node.statistics.log]
         filename_counters = "devcounters.stat"
@@ -182,10 +186,19 @@ mod tests {
         interval_samples = 999
         rotation_count = 999
 
+        [node.statistics.statsd]
+        host = "stats.example.com"
+        port = 999
+        prefix = "dev"
+        interval = 999
+
         [node.websocket]
         address = "0:0:0:0:0:ffff:7f01:101"
         enable = true
         port = 999
+        stats_interval = 999
+        confirmation_ttl = 999
+        max_message_size = 999
 
         [node.lmdb]
         sync = "nosync_safe"
@@ -253,6 +266,7 @@ mod tests {
         [node.message_processor]
         threads = 999
         max_queue = 999
+        max_total_queue = 999
 
         [opencl]
         device = 999
@@ -319,6 +333,10 @@ mod tests {
             deserialized.node.bootstrap_initiator_threads,
             default_cfg.node.bootstrap_initiator_threads
         );
+        assert_ne!(
+            deserialized.node.bootstrap_max_attempts,
+            default_cfg.node.bootstrap_max_attempts
+        );
         assert_ne!(
             deserialized.node.bootstrap_serving_threads,
             default_cfg.node.bootstrap_serving_threads
@@ -713,6 +731,22 @@ mod tests {
             deserialized.node.stat_config.max_samples,
             default_cfg.node.stat_config.max_samples
         );
+        assert_ne!(
+            deserialized.node.stat_config.window_bucket_size,
+            default_cfg.node.stat_config.window_bucket_size
+        );
+        assert_ne!(
+            deserialized.node.stat_config.max_window,
+            default_cfg.node.stat_config.max_window
+        );
+        assert_ne!(
+            deserialized.node.stat_config.instance_label,
+            default_cfg.node.stat_config.instance_label
+        );
+        assert_ne!(
+            deserialized.node.stat_config.statsd,
+            default_cfg.node.stat_config.statsd
+        );
 
         // Statistics Log section
         assert_ne!(
@@ -753,6 +787,18 @@ mod tests {
             deserialized.node.websocket_config.port,
             default_cfg.node.websocket_config.port
         );
+        assert_ne!(
+            deserialized.node.websocket_config.stats_interval,
+            default_cfg.node.websocket_config.stats_interval
+        );
+        assert_ne!(
+            deserialized.node.websocket_config.confirmation_ttl,
+            default_cfg.node.websocket_config.confirmation_ttl
+        );
+        assert_ne!(
+            deserialized.node.websocket_config.max_message_size,
+            default_cfg.node.websocket_config.max_message_size
+        );
 
         // LMDB section
         assert_ne!(
@@ -955,6 +1001,10 @@ mod tests {
             deserialized.node.message_processor.max_queue,
             default_cfg.node.message_processor.max_queue
         );
+        assert_ne!(
+            deserialized.node.message_processor.max_total_queue,
+            default_cfg.node.message_processor.max_total_queue
+        );
 
         // OpenCL section
         assert_ne!(deserialized.opencl.device, default_cfg.opencl.device);
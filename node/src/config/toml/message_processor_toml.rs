@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Deserialize, Serialize)]
 pub struct MessageProcessorToml {
     pub max_queue: Option<usize>,
+    pub max_total_queue: Option<usize>,
     pub threads: Option<usize>,
 }
 
@@ -15,6 +16,9 @@ impl MessageProcessorConfig {
         if let Some(max_queue) = toml.max_queue {
             self.max_queue = max_queue;
         }
+        if let Some(max_total_queue) = toml.max_total_queue {
+            self.max_total_queue = max_total_queue;
+        }
     }
 }
 
@@ -23,6 +27,7 @@ impl From<&MessageProcessorConfig> for MessageProcessorToml {
         Self {
             threads: Some(config.threads),
             max_queue: Some(config.max_queue),
+            max_total_queue: Some(config.max_total_queue),
         }
     }
 }
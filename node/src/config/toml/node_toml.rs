@@ -21,6 +21,7 @@ pub struct NodeToml {
     pub bootstrap_fraction_numerator: Option<u32>,
     pub bootstrap_frontier_request_count: Option<u32>,
     pub bootstrap_initiator_threads: Option<u32>,
+    pub bootstrap_max_attempts: Option<usize>,
     pub bootstrap_serving_threads: Option<u32>,
     pub confirming_set_batch_time: Option<u64>,
     pub enable_voting: Option<bool>,
@@ -121,6 +122,9 @@ impl NodeConfig {
         if let Some(bootstrap_initiator_threads) = toml.bootstrap_initiator_threads {
             self.bootstrap_initiator_threads = bootstrap_initiator_threads;
         }
+        if let Some(bootstrap_max_attempts) = toml.bootstrap_max_attempts {
+            self.bootstrap_max_attempts = bootstrap_max_attempts;
+        }
         if let Some(bootstrap_serving_threads) = toml.bootstrap_serving_threads {
             self.bootstrap_serving_threads = bootstrap_serving_threads;
         }
@@ -357,6 +361,7 @@ impl From<&NodeConfig> for NodeToml {
             bootstrap_fraction_numerator: Some(config.bootstrap_fraction_numerator),
             bootstrap_frontier_request_count: Some(config.bootstrap_frontier_request_count),
             bootstrap_initiator_threads: Some(config.bootstrap_initiator_threads),
+            bootstrap_max_attempts: Some(config.bootstrap_max_attempts),
             bootstrap_serving_threads: Some(config.bootstrap_serving_threads),
             confirming_set_batch_time: Some(config.confirming_set_batch_time.as_millis() as u64),
             enable_voting: Some(config.enable_voting),
@@ -1,4 +1,4 @@
-use crate::stats::StatsConfig;
+use crate::stats::{StatsConfig, StatsdConfig};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -22,7 +22,19 @@ impl Default for LogToml {
 #[derive(Deserialize, Serialize)]
 pub struct StatsToml {
     pub max_samples: Option<usize>,
+    pub window_bucket_size_ms: Option<u64>,
+    pub max_window_ms: Option<u64>,
+    pub instance_label: Option<String>,
     pub log: Option<LogToml>,
+    pub statsd: Option<StatsdToml>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct StatsdToml {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub prefix: Option<String>,
+    pub interval: Option<u64>,
 }
 
 impl Default for StatsToml {
@@ -39,6 +51,15 @@ impl From<&StatsToml> for StatsConfig {
         if let Some(max_samples) = toml.max_samples {
             config.max_samples = max_samples;
         }
+        if let Some(window_bucket_size) = toml.window_bucket_size_ms {
+            config.window_bucket_size = Duration::from_millis(window_bucket_size);
+        }
+        if let Some(max_window) = toml.max_window_ms {
+            config.max_window = Duration::from_millis(max_window);
+        }
+        if let Some(instance_label) = &toml.instance_label {
+            config.instance_label = Some(instance_label.clone());
+        }
         if let Some(log) = &toml.log {
             if let Some(log_counters_filename) = &log.filename_counters {
                 config.log_counters_filename = log_counters_filename.clone();
@@ -59,6 +80,28 @@ impl From<&StatsToml> for StatsConfig {
                 config.log_samples_interval = Duration::from_millis(*log_samples_interval);
             }
         }
+        if let Some(statsd) = &toml.statsd {
+            config.statsd = Some(statsd.into());
+        }
+        config
+    }
+}
+
+impl From<&StatsdToml> for StatsdConfig {
+    fn from(toml: &StatsdToml) -> Self {
+        let mut config = StatsdConfig::default();
+        if let Some(host) = &toml.host {
+            config.host = host.clone();
+        }
+        if let Some(port) = toml.port {
+            config.port = port;
+        }
+        if let Some(prefix) = &toml.prefix {
+            config.prefix = prefix.clone();
+        }
+        if let Some(interval) = toml.interval {
+            config.interval = Duration::from_millis(interval);
+        }
         config
     }
 }
@@ -67,7 +110,11 @@ impl From<&StatsConfig> for StatsToml {
     fn from(config: &StatsConfig) -> Self {
         Self {
             max_samples: Some(config.max_samples),
+            window_bucket_size_ms: Some(config.window_bucket_size.as_millis() as u64),
+            max_window_ms: Some(config.max_window.as_millis() as u64),
+            instance_label: config.instance_label.clone(),
             log: Some(config.into()),
+            statsd: config.statsd.as_ref().map(|statsd| statsd.into()),
         }
     }
 }
@@ -84,3 +131,14 @@ impl From<&StatsConfig> for LogToml {
         }
     }
 }
+
+impl From<&StatsdConfig> for StatsdToml {
+    fn from(config: &StatsdConfig) -> Self {
+        Self {
+            host: Some(config.host.clone()),
+            port: Some(config.port),
+            prefix: Some(config.prefix.clone()),
+            interval: Some(config.interval.as_millis() as u64),
+        }
+    }
+}
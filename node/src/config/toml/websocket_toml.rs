@@ -1,11 +1,15 @@
 use crate::config::websocket_config::WebsocketConfig;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 #[derive(Deserialize, Serialize)]
 pub struct WebsocketToml {
     pub address: Option<String>,
     pub enable: Option<bool>,
     pub port: Option<u16>,
+    pub stats_interval: Option<u64>,
+    pub confirmation_ttl: Option<u64>,
+    pub max_message_size: Option<usize>,
 }
 
 impl WebsocketConfig {
@@ -19,6 +23,15 @@ impl WebsocketConfig {
         if let Some(address) = &toml.address {
             self.address = address.clone();
         }
+        if let Some(stats_interval) = toml.stats_interval {
+            self.stats_interval = Duration::from_millis(stats_interval);
+        }
+        if let Some(confirmation_ttl) = toml.confirmation_ttl {
+            self.confirmation_ttl = Duration::from_millis(confirmation_ttl);
+        }
+        if let Some(max_message_size) = toml.max_message_size {
+            self.max_message_size = max_message_size;
+        }
     }
 }
 
@@ -28,6 +41,9 @@ impl From<&WebsocketConfig> for WebsocketToml {
             enable: Some(websocket_config.enabled),
             port: Some(websocket_config.port),
             address: Some(websocket_config.address.clone()),
+            stats_interval: Some(websocket_config.stats_interval.as_millis() as u64),
+            confirmation_ttl: Some(websocket_config.confirmation_ttl.as_millis() as u64),
+            max_message_size: Some(websocket_config.max_message_size),
         }
     }
 }
@@ -1,11 +1,18 @@
 use crate::config::NetworkConstants;
-use std::net::Ipv6Addr;
+use std::{net::Ipv6Addr, time::Duration};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct WebsocketConfig {
     pub enabled: bool,
     pub port: u16,
     pub address: String,
+    /// How often stat deltas are pushed to subscribers of the stats topic
+    pub stats_interval: Duration,
+    /// Queued confirmations are dropped if they couldn't be sent to a slow client
+    /// within this time. Zero keeps them until they are sent
+    pub confirmation_ttl: Duration,
+    /// Sessions get closed when a client sends a message larger than this many bytes
+    pub max_message_size: usize,
 }
 
 impl WebsocketConfig {
@@ -14,6 +21,9 @@ impl WebsocketConfig {
             enabled: false,
             port: network.default_websocket_port,
             address: Ipv6Addr::LOCALHOST.to_string(),
+            stats_interval: Duration::from_secs(10),
+            confirmation_ttl: Duration::ZERO,
+            max_message_size: 64 * 1024 * 1024,
         }
     }
 }
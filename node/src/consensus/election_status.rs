@@ -1,6 +1,7 @@
 use crate::stats::DetailType;
 use rsban_core::{Amount, MaybeSavedBlock};
-use std::time::{Duration, SystemTime};
+use serde_json::json;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /**
  * Tag for the type of the election status
@@ -70,3 +71,56 @@ impl Default for ElectionStatus {
         }
     }
 }
+
+impl ElectionStatus {
+    /// Election details as they are published in confirmation messages.
+    /// All numbers are encoded as strings and times are in milliseconds
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "duration": self.election_duration.as_millis().to_string(),
+            "time": self
+                .election_end
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis()
+                .to_string(),
+            "tally": self.tally.to_string_dec(),
+            "final": self.final_tally.to_string_dec(),
+            "blocks": self.block_count.to_string(),
+            "voters": self.voter_count.to_string(),
+            "request_count": self.confirmation_request_count.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_json() {
+        let status = ElectionStatus {
+            tally: Amount::raw(1000),
+            final_tally: Amount::raw(600),
+            block_count: 2,
+            voter_count: 3,
+            confirmation_request_count: 4,
+            election_end: UNIX_EPOCH + Duration::from_millis(123456),
+            election_duration: Duration::from_millis(1500),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            status.to_json(),
+            json!({
+                "duration": "1500",
+                "time": "123456",
+                "tally": "1000",
+                "final": "600",
+                "blocks": "2",
+                "voters": "3",
+                "request_count": "4",
+            })
+        );
+    }
+}
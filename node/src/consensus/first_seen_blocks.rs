@@ -0,0 +1,95 @@
+use rsban_core::BlockHash;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::Instant,
+};
+
+/// Remembers when blocks arrived at the node, so that the time until
+/// their confirmation can be measured. The oldest entries are evicted
+/// once `max_len` is exceeded
+pub struct FirstSeenBlocks {
+    mutex: Mutex<FirstSeenBlocksImpl>,
+    max_len: usize,
+}
+
+impl FirstSeenBlocks {
+    pub const DEFAULT_MAX_LEN: usize = 64 * 1024;
+
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            mutex: Mutex::new(FirstSeenBlocksImpl {
+                sequential: VecDeque::new(),
+                by_hash: HashMap::new(),
+            }),
+            max_len,
+        }
+    }
+
+    /// Records the arrival time of a block. Later arrivals of the same block are ignored
+    pub fn add(&self, hash: BlockHash, first_seen: Instant) {
+        let mut guard = self.mutex.lock().unwrap();
+        if guard.by_hash.contains_key(&hash) {
+            return;
+        }
+        guard.by_hash.insert(hash, first_seen);
+        guard.sequential.push_back((hash, first_seen));
+        while guard.sequential.len() > self.max_len {
+            if let Some((old_hash, old_first_seen)) = guard.sequential.pop_front() {
+                // The entry could have been removed and added again in the meantime
+                if guard.by_hash.get(&old_hash) == Some(&old_first_seen) {
+                    guard.by_hash.remove(&old_hash);
+                }
+            }
+        }
+    }
+
+    /// Removes the block and returns when it was first seen
+    pub fn remove(&self, hash: &BlockHash) -> Option<Instant> {
+        self.mutex.lock().unwrap().by_hash.remove(hash)
+    }
+}
+
+impl Default for FirstSeenBlocks {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_LEN)
+    }
+}
+
+struct FirstSeenBlocksImpl {
+    by_hash: HashMap<BlockHash, Instant>,
+    sequential: VecDeque<(BlockHash, Instant)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn keep_first_arrival() {
+        let blocks = FirstSeenBlocks::new(10);
+        let hash = BlockHash::from(1);
+        let first = Instant::now();
+
+        blocks.add(hash, first);
+        blocks.add(hash, first + Duration::from_secs(1));
+
+        assert_eq!(blocks.remove(&hash), Some(first));
+        assert_eq!(blocks.remove(&hash), None);
+    }
+
+    #[test]
+    fn evict_oldest_entry() {
+        let blocks = FirstSeenBlocks::new(2);
+        let now = Instant::now();
+
+        blocks.add(BlockHash::from(1), now);
+        blocks.add(BlockHash::from(2), now);
+        blocks.add(BlockHash::from(3), now);
+
+        assert_eq!(blocks.remove(&BlockHash::from(1)), None);
+        assert_eq!(blocks.remove(&BlockHash::from(2)), Some(now));
+        assert_eq!(blocks.remove(&BlockHash::from(3)), Some(now));
+    }
+}
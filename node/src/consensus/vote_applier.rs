@@ -2,9 +2,9 @@ use crate::{
     block_processing::BlockProcessor,
     cementation::ConfirmingSet,
     config::NodeConfig,
-    consensus::{ElectionState, VoteInfo},
+    consensus::{ElectionState, FirstSeenBlocks, VoteInfo},
     representatives::OnlineReps,
-    stats::{DetailType, StatType, Stats},
+    stats::{DetailType, Sample, StatType, Stats},
     utils::ThreadPool,
     wallets::Wallets,
     NetworkParams,
@@ -36,6 +36,7 @@ pub struct VoteApplier {
     recently_confirmed: Arc<RecentlyConfirmedCache>,
     confirming_set: Arc<ConfirmingSet>,
     workers: Arc<dyn ThreadPool>,
+    first_seen: Arc<FirstSeenBlocks>,
     election_schedulers: RwLock<Option<Weak<ElectionSchedulers>>>,
 }
 
@@ -53,6 +54,7 @@ impl VoteApplier {
         recently_confirmed: Arc<RecentlyConfirmedCache>,
         confirming_set: Arc<ConfirmingSet>,
         workers: Arc<dyn ThreadPool>,
+        first_seen: Arc<FirstSeenBlocks>,
     ) -> Self {
         Self {
             ledger,
@@ -67,6 +69,7 @@ impl VoteApplier {
             recently_confirmed,
             confirming_set,
             workers,
+            first_seen,
             election_schedulers: RwLock::new(None),
         }
     }
@@ -293,6 +296,17 @@ impl VoteApplierExt for Arc<VoteApplier> {
             );
 
             self.stats.inc(StatType::Election, DetailType::ConfirmOnce);
+            // Time from the block arriving at the node until it was confirmed
+            if let Some(first_seen) = self
+                .first_seen
+                .remove(&status.winner.as_ref().unwrap().hash())
+            {
+                self.stats.sample(
+                    Sample::ConfirmationLatency,
+                    first_seen.elapsed().as_millis() as i64,
+                    (0, 60_000),
+                );
+            }
             trace!(
                 qualified_root = ?election.qualified_root,
                 "election confirmed"
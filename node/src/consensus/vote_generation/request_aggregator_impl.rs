@@ -29,7 +29,7 @@ impl<'a> RequestAggregatorImpl<'a> {
             let mut block = None;
 
             // 2. Final votes
-            let final_vote_hashes = self.ledger.store.final_vote.get(self.tx, *root);
+            let final_vote_hashes = self.ledger.store.final_vote.get_for_root(self.tx, *root);
             if !final_vote_hashes.is_empty() {
                 generate_final_vote = true;
                 block = self.ledger.any().get_block(self.tx, &final_vote_hashes[0]);
@@ -16,6 +16,7 @@ mod ipc;
 mod monitor;
 mod node;
 mod node_builder;
+mod node_health;
 mod node_id_key_file;
 pub mod pruning;
 pub mod representatives;
@@ -30,6 +31,7 @@ pub mod work;
 pub use ipc::*;
 pub use node::*;
 pub use node_builder::*;
+pub use node_health::*;
 pub use representatives::OnlineWeightSampler;
 pub use secure::*;
 pub use telemetry::*;
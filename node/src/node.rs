@@ -11,13 +11,14 @@ use crate::{
     config::{GlobalConfig, NodeConfig, NodeFlags},
     consensus::{
         election_schedulers::ElectionSchedulers, get_bootstrap_weights, log_bootstrap_weights,
-        ActiveElections, ActiveElectionsExt, ElectionStatusType, LocalVoteHistory,
+        ActiveElections, ActiveElectionsExt, ElectionStatusType, FirstSeenBlocks, LocalVoteHistory,
         ProcessLiveDispatcher, ProcessLiveDispatcherExt, RecentlyConfirmedCache, RepTiers,
         RequestAggregator, RequestAggregatorCleanup, VoteApplier, VoteBroadcaster, VoteCache,
         VoteCacheProcessor, VoteGenerators, VoteProcessor, VoteProcessorExt, VoteProcessorQueue,
         VoteProcessorQueueCleanup, VoteRouter,
     },
     monitor::Monitor,
+    node_health::{NodeHealth, HEALTH_ERROR_WINDOW},
     node_id_key_file::NodeIdKeyFile,
     pruning::{LedgerPruning, LedgerPruningExt},
     representatives::{OnlineReps, OnlineRepsCleanup, RepCrawler, RepCrawlerExt},
@@ -29,7 +30,7 @@ use crate::{
         InboundMessageQueue, InboundMessageQueueCleanup, KeepaliveFactory, LatestKeepalives,
         LatestKeepalivesCleanup, MessageProcessor, MessagePublisher, NanoResponseServerSpawner,
         NetworkFilter, NetworkThreads, PeerCacheConnector, PeerCacheUpdater,
-        RealtimeMessageHandler, SynCookies,
+        RealtimeMessageHandler, ResponseServer, SynCookies,
     },
     utils::{
         LongRunningTransactionLogger, ThreadPool, ThreadPoolImpl, TimerThread, TxnTrackingConfig,
@@ -63,7 +64,7 @@ use std::{
     collections::{HashMap, VecDeque},
     path::{Path, PathBuf},
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc, Mutex, RwLock,
     },
     time::{Duration, SystemTime},
@@ -90,6 +91,8 @@ pub struct Node {
     pub unchecked: Arc<UncheckedMap>,
     pub ledger: Arc<Ledger>,
     pub syn_cookies: Arc<SynCookies>,
+    /// Maximum number of inbound bootstrap connections. Can be changed at runtime
+    pub bootstrap_connections_max: Arc<AtomicUsize>,
     pub network_info: Arc<RwLock<NetworkInfo>>,
     pub network: Arc<Network>,
     pub telemetry: Arc<Telemetry>,
@@ -295,12 +298,16 @@ impl Node {
             runtime.clone(),
         );
         network.set_observer(network_observer.clone());
+        network.set_traffic_policy(config.traffic_policy);
         let network = Arc::new(network);
 
         dead_channel_cleanup.add_step(NetworkCleanup::new(network.clone()));
 
-        let mut inbound_message_queue =
-            InboundMessageQueue::new(config.message_processor.max_queue, stats.clone());
+        let mut inbound_message_queue = InboundMessageQueue::new(
+            config.message_processor.max_queue,
+            config.message_processor.max_total_queue,
+            stats.clone(),
+        );
         if let Some(cb) = args.callbacks.on_inbound {
             inbound_message_queue.set_inbound_callback(cb);
         }
@@ -470,6 +477,17 @@ impl Node {
             message_publisher.clone(),
         ));
 
+        let first_seen_blocks = Arc::new(FirstSeenBlocks::default());
+        let first_seen_l = first_seen_blocks.clone();
+        block_processor.add_batch_processed_observer(Box::new(move |batch| {
+            for (result, context) in batch {
+                if *result == BlockStatus::Progress {
+                    let hash = context.block.lock().unwrap().hash();
+                    first_seen_l.add(hash, context.arrival);
+                }
+            }
+        }));
+
         let vote_applier = Arc::new(VoteApplier::new(
             ledger.clone(),
             network_params.clone(),
@@ -483,6 +501,7 @@ impl Node {
             recently_confirmed.clone(),
             confirming_set.clone(),
             election_workers.clone(),
+            first_seen_blocks,
         ));
 
         let vote_router = Arc::new(VoteRouter::new(
@@ -593,6 +612,8 @@ impl Node {
         let latest_keepalives = Arc::new(Mutex::new(LatestKeepalives::default()));
         dead_channel_cleanup.add_step(LatestKeepalivesCleanup::new(latest_keepalives.clone()));
 
+        let bootstrap_connections_max =
+            Arc::new(AtomicUsize::new(ResponseServer::DEFAULT_CONNECTIONS_MAX));
         let response_server_spawner = Arc::new(NanoResponseServerSpawner {
             tokio: runtime.clone(),
             stats: stats.clone(),
@@ -604,10 +625,13 @@ impl Node {
             network: network_info.clone(),
             inbound_queue: inbound_message_queue.clone(),
             node_flags: flags.clone(),
+            tcp_config: config.tcp.clone(),
             network_params: network_params.clone(),
             syn_cookies: syn_cookies.clone(),
             latest_keepalives: latest_keepalives.clone(),
             network_filter: network_filter.clone(),
+            clock: steady_clock.clone(),
+            bootstrap_connections_max: bootstrap_connections_max.clone(),
         });
 
         let peer_connector = Arc::new(PeerConnector::new(
@@ -617,6 +641,7 @@ impl Node {
             runtime.clone(),
             response_server_spawner.clone(),
             steady_clock.clone(),
+            config.tcp.socket_options,
         ));
 
         let rep_crawler = Arc::new(RepCrawler::new(
@@ -647,6 +672,7 @@ impl Node {
             network_observer.clone(),
             runtime.clone(),
             response_server_spawner.clone(),
+            config.tcp.socket_options,
         ));
 
         let request_aggregator = Arc::new(RequestAggregator::new(
@@ -705,6 +731,7 @@ impl Node {
         let keepalive_factory = Arc::new(KeepaliveFactory {
             network: network_info.clone(),
             config: config.clone(),
+            clock: steady_clock.clone(),
         });
 
         let network_threads = Arc::new(Mutex::new(NetworkThreads::new(
@@ -1064,6 +1091,7 @@ impl Node {
             unchecked,
             telemetry,
             syn_cookies,
+            bootstrap_connections_max,
             network,
             network_info,
             ledger,
@@ -1188,6 +1216,29 @@ impl Node {
         }
     }
 
+    pub fn health(&self) -> NodeHealth {
+        let peer_count = self.network_info.read().unwrap().channels_info().total;
+        let recent_errors = [Direction::In, Direction::Out]
+            .into_iter()
+            .map(|dir| {
+                self.stats
+                    .windowed_all(StatType::Error, dir, HEALTH_ERROR_WINDOW)
+            })
+            .sum();
+        NodeHealth {
+            peer_count,
+            unchecked_count: self.unchecked.len() as u64,
+            // The counters are read independently, so blocks which are inserted and
+            // cemented in between could make the cemented count the larger one
+            unconfirmed_count: self
+                .ledger
+                .block_count()
+                .saturating_sub(self.ledger.cemented_count()),
+            bootstrap_in_progress: self.bootstrap_initiator.in_progress(),
+            recent_errors,
+        }
+    }
+
     pub fn is_stopped(&self) -> bool {
         self.stopped.load(Ordering::SeqCst)
     }
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+/// Errors counted in this trailing window are reported as recent errors
+pub const HEALTH_ERROR_WINDOW: Duration = Duration::from_secs(60);
+
+/// Summary of the state of the most important subsystems of a node
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct NodeHealth {
+    pub peer_count: usize,
+    pub unchecked_count: u64,
+    pub unconfirmed_count: u64,
+    pub bootstrap_in_progress: bool,
+    /// Number of errors counted by the stats in the last `HEALTH_ERROR_WINDOW`
+    pub recent_errors: u64,
+}
+
+impl NodeHealth {
+    /// A node without peers can neither receive nor publish blocks and votes
+    pub fn is_healthy(&self) -> bool {
+        self.peer_count > 0 && self.recent_errors == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_peers_is_unhealthy() {
+        let health = NodeHealth {
+            peer_count: 0,
+            ..Default::default()
+        };
+        assert!(!health.is_healthy());
+    }
+
+    #[test]
+    fn recent_errors_are_unhealthy() {
+        let health = NodeHealth {
+            peer_count: 3,
+            recent_errors: 1,
+            ..Default::default()
+        };
+        assert!(!health.is_healthy());
+    }
+
+    #[test]
+    fn healthy() {
+        let health = NodeHealth {
+            peer_count: 3,
+            unchecked_count: 10,
+            unconfirmed_count: 20,
+            bootstrap_in_progress: true,
+            recent_errors: 0,
+        };
+        assert!(health.is_healthy());
+    }
+}
@@ -413,6 +413,7 @@ impl RepCrawler {
         let peer_connector = self.peer_connector.clone();
         let network_info = self.network_info.clone();
         let publisher = self.message_publisher.clone();
+        let clock = self.steady_clock.clone();
         self.tokio.spawn(async move {
             match tokio::net::lookup_host((address.as_str(), port)).await {
                 Ok(addresses) => {
@@ -429,7 +430,7 @@ impl RepCrawler {
                                 network_info
                                     .read()
                                     .unwrap()
-                                    .random_fill_realtime(&mut peers);
+                                    .random_fill_realtime(&mut peers, clock.now());
                                 let keepalive = Message::Keepalive(Keepalive { peers });
 
                                 publisher.lock().unwrap().try_send(
@@ -1,4 +1,4 @@
-use crate::stats::{DetailType, Direction, StatType, Stats};
+use crate::stats::{DetailType, Direction, Sample, StatType, Stats};
 use anyhow::Error;
 use rsban_network::{ChannelDirection, ChannelInfo, NetworkError, NetworkObserver};
 use std::{net::SocketAddrV6, sync::Arc};
@@ -8,6 +8,9 @@ use tracing::debug;
 pub struct NetworkStats(Arc<Stats>);
 
 impl NetworkStats {
+    /// Expected range of a single traffic sample in bytes
+    const TRAFFIC_MIN_MAX: (i64, i64) = (0, 1024 * 1024);
+
     pub fn new(stats: Arc<Stats>) -> Self {
         Self(stats)
     }
@@ -21,6 +24,8 @@ impl NetworkObserver for NetworkStats {
             Direction::Out,
             buf_size as u64,
         );
+        self.0
+            .sample(Sample::TrafficOut, buf_size as i64, Self::TRAFFIC_MIN_MAX);
     }
 
     fn send_failed(&self) {
@@ -53,6 +58,8 @@ impl NetworkObserver for NetworkStats {
             Direction::In,
             count as u64,
         );
+        self.0
+            .sample(Sample::TrafficIn, count as i64, Self::TRAFFIC_MIN_MAX);
     }
 
     fn read_failed(&self) {
@@ -181,6 +188,17 @@ impl NetworkObserver for NetworkStats {
                     ?direction,
                     "Already connected to that peer, unable to open new connection");
             }
+            NetworkError::AttemptInProgress => {
+                self.0.inc_dir(
+                    StatType::TcpListenerRejected,
+                    DetailType::AttemptInProgress,
+                    direction.into(),
+                );
+                debug!(
+                    %peer,
+                    ?direction,
+                    "Connection attempt to that peer already in progress, unable to open new connection");
+            }
         }
     }
 
@@ -224,3 +242,21 @@ impl From<ChannelDirection> for Direction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_traffic() {
+        let stats = Arc::new(Stats::default());
+        let observer = NetworkStats::new(stats.clone());
+
+        observer.send_succeeded(100);
+        observer.send_succeeded(50);
+        observer.read_succeeded(300);
+
+        assert_eq!(stats.samples(Sample::TrafficOut).iter().sum::<i64>(), 150);
+        assert_eq!(stats.samples(Sample::TrafficIn).iter().sum::<i64>(), 300);
+    }
+}
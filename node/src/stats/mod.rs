@@ -3,8 +3,12 @@ mod stats;
 mod stats_config;
 mod stats_enums;
 mod stats_log_sink;
+mod statsd_sink;
+mod windowed_counter;
 
 pub use stats::*;
-pub use stats_config::StatsConfig;
+pub use stats_config::{StatsConfig, StatsdConfig};
 pub use stats_enums::*;
-pub use stats_log_sink::{StatFileWriter, StatsJsonWriterV2, StatsLogSink};
+pub use stats_log_sink::{StatFileWriter, StatsJsonWriterV2, StatsLogSink, StatsPrometheusWriter};
+pub use statsd_sink::StatsdSink;
+pub use windowed_counter::WindowedCounter;
@@ -1,5 +1,5 @@
 use super::{DetailType, Direction, Sample, StatType};
-use super::{StatFileWriter, StatsConfig, StatsLogSink};
+use super::{StatFileWriter, StatsConfig, StatsLogSink, StatsdSink, WindowedCounter};
 use anyhow::Result;
 use bounded_vec_deque::BoundedVecDeque;
 use once_cell::sync::Lazy;
@@ -11,7 +11,7 @@ use std::{
     thread::JoinHandle,
     time::{Duration, Instant, SystemTime},
 };
-use tracing::debug;
+use tracing::{debug, warn};
 
 pub struct Stats {
     config: StatsConfig,
@@ -32,6 +32,7 @@ impl Stats {
         let mutables = Arc::new(RwLock::new(StatMutables {
             counters: BTreeMap::new(),
             samplers: BTreeMap::new(),
+            descriptions: BTreeMap::new(),
             timestamp: Instant::now(),
         }));
         Self {
@@ -45,6 +46,9 @@ impl Stats {
                     stopped: false,
                     log_last_count_writeout: Instant::now(),
                     log_last_sample_writeout: Instant::now(),
+                    statsd_last_writeout: Instant::now(),
+                    statsd_sink: None,
+                    statsd_sample_cursors: BTreeMap::new(),
                 }),
             }),
             mutables,
@@ -67,7 +71,9 @@ impl Stats {
     }
 
     fn should_run(&self) -> bool {
-        !self.config.log_counters_interval.is_zero() || !self.config.log_samples_interval.is_zero()
+        !self.config.log_counters_interval.is_zero()
+            || !self.config.log_samples_interval.is_zero()
+            || self.config.statsd.is_some()
     }
 
     /// Stop stats being output
@@ -107,12 +113,17 @@ impl Stats {
         // Not found, create a new entry
         {
             let mut lock = self.mutables.write().unwrap();
-            let counter = lock.counters.entry(key).or_insert(CounterEntry::new());
+            let counter = lock
+                .counters
+                .entry(key)
+                .or_insert_with(|| CounterEntry::new(&self.config, stat_type));
             counter.add(value);
 
             let all_key = CounterKey::new(stat_type, DetailType::All, dir);
             if key != all_key {
-                lock.counters.entry(all_key).or_insert(CounterEntry::new());
+                lock.counters
+                    .entry(all_key)
+                    .or_insert_with(|| CounterEntry::new(&self.config, stat_type));
             }
         }
     }
@@ -158,10 +169,16 @@ impl Stats {
         // Not found, create a new entry
         {
             let mut lock = self.mutables.write().unwrap();
-            let counter = lock.counters.entry(key).or_insert(CounterEntry::new());
+            let counter = lock
+                .counters
+                .entry(key)
+                .or_insert_with(|| CounterEntry::new(&self.config, stat_type));
             counter.add(value);
             if key != all_key {
-                let all_counter = lock.counters.entry(all_key).or_insert(CounterEntry::new());
+                let all_counter = lock
+                    .counters
+                    .entry(all_key)
+                    .or_insert_with(|| CounterEntry::new(&self.config, stat_type));
                 all_counter.add(value);
             }
         }
@@ -217,6 +234,36 @@ impl Stats {
         }
     }
 
+    /// Summary of the currently buffered samples. Unlike `samples` this
+    /// does not drain the buffer
+    pub fn sample_stats(&self, sample: Sample) -> Option<SampleStats> {
+        let key = SamplerKey::new(sample);
+        let lock = self.mutables.read().unwrap();
+        lock.samplers.get(&key).and_then(|sampler| sampler.stats())
+    }
+
+    /// Registers a human readable description for a counter. It is passed
+    /// to the log sinks, which can include it in their output
+    pub fn describe(&self, stat_type: StatType, detail: DetailType, text: impl Into<String>) {
+        self.mutables
+            .write()
+            .unwrap()
+            .descriptions
+            .insert((stat_type, detail), text.into());
+    }
+
+    /// Returns the registered description or the name of the detail type
+    /// if there is none
+    pub fn description(&self, stat_type: StatType, detail: DetailType) -> String {
+        self.mutables
+            .read()
+            .unwrap()
+            .descriptions
+            .get(&(stat_type, detail))
+            .cloned()
+            .unwrap_or_else(|| detail.as_str().to_owned())
+    }
+
     /// Log counters to the given log link
     pub fn log_counters(&self, sink: &mut dyn StatsLogSink) -> Result<()> {
         let now = SystemTime::now();
@@ -272,17 +319,114 @@ impl Stats {
             .map(|i| i.into())
             .unwrap_or_default()
     }
+
+    /// Sum of the In and Out directions of a counter
+    pub fn count_both(&self, stat_type: StatType, detail: DetailType) -> u64 {
+        self.count(stat_type, detail, Direction::In) + self.count(stat_type, detail, Direction::Out)
+    }
+
+    /// Returns the sum of all increments of the given counter in the trailing `window`.
+    /// The window is limited to `StatsConfig::max_window`. Always returns 0 for
+    /// stat types which aren't listed in `StatsConfig::windowed_types`
+    pub fn windowed(
+        &self,
+        stat_type: StatType,
+        detail: DetailType,
+        dir: Direction,
+        window: Duration,
+    ) -> u64 {
+        let key = CounterKey::new(stat_type, detail, dir);
+        self.mutables
+            .read()
+            .unwrap()
+            .counters
+            .get(&key)
+            .map(|i| i.windowed(Instant::now(), window))
+            .unwrap_or_default()
+    }
+
+    /// Returns the sum of all increments of all counters of the given type in
+    /// the trailing `window`
+    pub fn windowed_all(&self, stat_type: StatType, dir: Direction, window: Duration) -> u64 {
+        let guard = self.mutables.read().unwrap();
+        let now = Instant::now();
+        let start = CounterKey::new(stat_type, DetailType::All, dir);
+        let mut result = 0u64;
+        for (key, entry) in guard.counters.range(start..) {
+            if key.stat_type != stat_type {
+                break;
+            }
+            if key.dir == dir && key.detail != DetailType::All {
+                result += entry.windowed(now, window);
+            }
+        }
+        result
+    }
+
+    /// Returns the current values of all counters
+    pub fn snapshot(&self) -> BTreeMap<CounterKey, u64> {
+        let guard = self.mutables.read().unwrap();
+        guard
+            .counters
+            .iter()
+            .map(|(key, entry)| (*key, u64::from(entry)))
+            .collect()
+    }
+
+    /// Captures the current counter values, so that the activity since
+    /// this point in time can be queried with `since`
+    pub fn mark(&self) -> StatsMark {
+        StatsMark {
+            counters: self.snapshot(),
+        }
+    }
+
+    /// Returns all counters that changed since the given mark, together with
+    /// the amount by which they increased
+    pub fn since(&self, mark: &StatsMark) -> BTreeMap<CounterKey, u64> {
+        let guard = self.mutables.read().unwrap();
+        guard
+            .counters
+            .iter()
+            .filter_map(|(key, entry)| {
+                let previous = mark.counters.get(key).cloned().unwrap_or_default();
+                let delta = u64::from(entry).saturating_sub(previous);
+                (delta > 0).then_some((*key, delta))
+            })
+            .collect()
+    }
+
+    /// Like `since`, but also moves the mark to the current counter values.
+    /// Both happen under the same lock, so that no increment gets lost in between
+    pub fn since_and_mark(&self, mark: &mut StatsMark) -> BTreeMap<CounterKey, u64> {
+        let guard = self.mutables.read().unwrap();
+        let mut deltas = BTreeMap::new();
+        for (key, entry) in &guard.counters {
+            let value = u64::from(entry);
+            let previous = mark.counters.insert(*key, value).unwrap_or_default();
+            let delta = value.saturating_sub(previous);
+            if delta > 0 {
+                deltas.insert(*key, delta);
+            }
+        }
+        deltas
+    }
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
-struct CounterKey {
-    stat_type: StatType,
-    detail: DetailType,
-    dir: Direction,
+/// Counter values at a point in time. See `Stats::mark`
+pub struct StatsMark {
+    counters: BTreeMap<CounterKey, u64>,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct CounterKey {
+    pub stat_type: StatType,
+    pub detail: DetailType,
+    pub dir: Direction,
 }
 
 impl CounterKey {
-    fn new(stat_type: StatType, detail: DetailType, dir: Direction) -> Self {
+    pub fn new(stat_type: StatType, detail: DetailType, dir: Direction) -> Self {
         Self {
             stat_type,
             detail,
@@ -311,6 +455,8 @@ struct StatMutables {
     /// Stat entries are sorted by key to simplify processing of log output
     counters: BTreeMap<CounterKey, CounterEntry>,
     samplers: BTreeMap<SamplerKey, SamplerEntry>,
+    /// Optional descriptions of counters. They survive clear()
+    descriptions: BTreeMap<(StatType, DetailType), String>,
 
     /// Time of last clear() call
     timestamp: Instant,
@@ -334,6 +480,10 @@ impl StatMutables {
             sink.write_header("samples", walltime)?;
         }
 
+        if let Some(label) = &config.instance_label {
+            sink.write_instance_label(label)?;
+        }
+
         for (&key, entry) in &self.samplers {
             let sample = key.sample.as_str();
             sink.write_sampler_entry(time, sample, entry.collect(), entry.expected_min_max)?;
@@ -343,6 +493,34 @@ impl StatMutables {
         Ok(())
     }
 
+    /// Writes the samples which were added since the last call with the same `cursors`.
+    /// Unlike `log_samples_impl` this doesn't drain the samplers
+    fn log_new_samples_impl(
+        &self,
+        sink: &mut dyn StatsLogSink,
+        config: &StatsConfig,
+        time: SystemTime,
+        cursors: &mut BTreeMap<SamplerKey, u64>,
+    ) -> Result<()> {
+        sink.begin()?;
+
+        if let Some(label) = &config.instance_label {
+            sink.write_instance_label(label)?;
+        }
+
+        for (&key, entry) in &self.samplers {
+            let cursor = cursors.entry(key).or_default();
+            let (values, new_cursor) = entry.copy_since(*cursor);
+            *cursor = new_cursor;
+            if !values.is_empty() {
+                let sample = key.sample.as_str();
+                sink.write_sampler_entry(time, sample, values, entry.expected_min_max)?;
+            }
+        }
+        sink.finalize();
+        Ok(())
+    }
+
     /// Unlocked implementation of log_counters() to avoid using recursive locking
     fn log_counters_impl(
         &self,
@@ -360,6 +538,14 @@ impl StatMutables {
             sink.write_header("counters", walltime)?;
         }
 
+        if let Some(label) = &config.instance_label {
+            sink.write_instance_label(label)?;
+        }
+
+        for ((stat_type, detail), text) in &self.descriptions {
+            sink.write_counter_description(stat_type.as_str(), detail.as_str(), text)?;
+        }
+
         for (&key, entry) in &self.counters {
             let type_str = key.stat_type.as_str();
             let detail = key.detail.as_str();
@@ -372,45 +558,120 @@ impl StatMutables {
     }
 }
 
-struct CounterEntry(AtomicU64);
+struct CounterEntry {
+    value: AtomicU64,
+    /// Only set for the stat types listed in `StatsConfig::windowed_types`,
+    /// so that the other counters stay lock free
+    windowed: Option<Mutex<WindowedCounter>>,
+}
 
 impl CounterEntry {
-    fn new() -> Self {
-        Self(AtomicU64::new(0))
+    fn new(config: &StatsConfig, stat_type: StatType) -> Self {
+        let windowed = config.windowed_types.contains(&stat_type).then(|| {
+            Mutex::new(WindowedCounter::new(
+                Instant::now(),
+                config.window_bucket_size,
+                config.max_window,
+            ))
+        });
+        Self {
+            value: AtomicU64::new(0),
+            windowed,
+        }
     }
 
     fn add(&self, value: u64) {
-        self.0.fetch_add(value, std::sync::atomic::Ordering::SeqCst);
+        self.value
+            .fetch_add(value, std::sync::atomic::Ordering::SeqCst);
+        if let Some(windowed) = &self.windowed {
+            windowed.lock().unwrap().add(Instant::now(), value);
+        }
+    }
+
+    fn windowed(&self, now: Instant, window: Duration) -> u64 {
+        match &self.windowed {
+            Some(windowed) => windowed.lock().unwrap().sum(now, window),
+            None => 0,
+        }
     }
 }
 
 impl From<&CounterEntry> for u64 {
     fn from(value: &CounterEntry) -> Self {
-        value.0.load(std::sync::atomic::Ordering::SeqCst)
+        value.value.load(std::sync::atomic::Ordering::SeqCst)
     }
 }
 
 struct SamplerEntry {
-    samples: Mutex<BoundedVecDeque<i64>>,
+    samples: Mutex<SampleBuffer>,
     pub expected_min_max: (i64, i64),
 }
 
+struct SampleBuffer {
+    values: BoundedVecDeque<i64>,
+    /// Number of samples that were ever added. Used as a cursor by `copy_since`
+    added: u64,
+}
+
 impl SamplerEntry {
     pub fn new(max_samples: usize, expected_min_max: (i64, i64)) -> Self {
         Self {
-            samples: Mutex::new(BoundedVecDeque::new(max_samples)),
+            samples: Mutex::new(SampleBuffer {
+                values: BoundedVecDeque::new(max_samples),
+                added: 0,
+            }),
             expected_min_max,
         }
     }
 
     fn add(&self, value: i64) {
-        self.samples.lock().unwrap().push_back(value);
+        let mut guard = self.samples.lock().unwrap();
+        guard.values.push_back(value);
+        guard.added += 1;
     }
 
     fn collect(&self) -> Vec<i64> {
         let mut guard = self.samples.lock().unwrap();
-        guard.drain(..).collect()
+        guard.values.drain(..).collect()
     }
+
+    /// Copies the buffered samples which were added after the given cursor without
+    /// draining them. Returns the copied samples and the new cursor
+    fn copy_since(&self, cursor: u64) -> (Vec<i64>, u64) {
+        let guard = self.samples.lock().unwrap();
+        let len = guard.values.len();
+        let new_samples = (guard.added.saturating_sub(cursor) as usize).min(len);
+        let values = guard
+            .values
+            .iter()
+            .skip(len - new_samples)
+            .copied()
+            .collect();
+        (values, guard.added)
+    }
+
+    fn stats(&self) -> Option<SampleStats> {
+        let guard = self.samples.lock().unwrap();
+        let count = guard.values.len();
+        if count == 0 {
+            return None;
+        }
+        let sum: i128 = guard.values.iter().map(|&v| v as i128).sum();
+        Some(SampleStats {
+            count,
+            min: *guard.values.iter().min().unwrap(),
+            max: *guard.values.iter().max().unwrap(),
+            average: (sum / count as i128) as i64,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SampleStats {
+    pub count: usize,
+    pub min: i64,
+    pub max: i64,
+    pub average: i64,
 }
 
 impl From<MessageType> for DetailType {
@@ -444,7 +705,20 @@ struct StatsLoop {
 
 impl StatsLoop {
     fn run(&self) {
+        // Resolving the StatsD host can block, so it is done once and outside of any lock
+        let statsd_sink = match &self.config.statsd {
+            Some(statsd_config) => match StatsdSink::new(statsd_config) {
+                Ok(sink) => Some(sink),
+                Err(e) => {
+                    warn!("Could not create StatsD sink: {:?}", e);
+                    None
+                }
+            },
+            None => None,
+        };
+
         let mut guard = self.loop_state.lock().unwrap();
+        guard.statsd_sink = statsd_sink;
         while !guard.stopped {
             guard = self
                 .condition
@@ -493,6 +767,23 @@ impl StatsLoop {
             lock.log_last_sample_writeout = Instant::now();
         }
 
+        // StatsD
+        if let Some(statsd_config) = &self.config.statsd {
+            if lock.statsd_last_writeout.elapsed() > statsd_config.interval {
+                lock.statsd_last_writeout = Instant::now();
+                let StatsLoopState {
+                    statsd_sink,
+                    statsd_sample_cursors,
+                    ..
+                } = lock;
+                if let Some(sink) = statsd_sink {
+                    let now = SystemTime::now();
+                    stats.log_counters_impl(sink, &self.config, now)?;
+                    stats.log_new_samples_impl(sink, &self.config, now, statsd_sample_cursors)?;
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -501,6 +792,11 @@ struct StatsLoopState {
     stopped: bool,
     log_last_count_writeout: Instant,
     log_last_sample_writeout: Instant,
+    statsd_last_writeout: Instant,
+    statsd_sink: Option<StatsdSink>,
+    /// Samples are pushed to StatsD without draining the samplers, so that they stay
+    /// available for the file log. This remembers which ones were pushed already
+    statsd_sample_cursors: BTreeMap<SamplerKey, u64>,
 }
 
 static LOG_COUNT: Lazy<Mutex<Option<StatFileWriter>>> = Lazy::new(|| Mutex::new(None));
@@ -509,6 +805,7 @@ static LOG_SAMPLE: Lazy<Mutex<Option<StatFileWriter>>> = Lazy::new(|| Mutex::new
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stats::StatsPrometheusWriter;
 
     /// Test stat counting at both type and detail levels
     #[test]
@@ -534,6 +831,146 @@ mod tests {
         );
     }
 
+    #[test]
+    fn count_both_directions() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.inc_dir_aggregate(StatType::Message, DetailType::Keepalive, Direction::In);
+        stats.inc_dir_aggregate(StatType::Message, DetailType::Keepalive, Direction::In);
+        stats.inc_dir_aggregate(StatType::Message, DetailType::Keepalive, Direction::Out);
+
+        assert_eq!(
+            stats.count_both(StatType::Message, DetailType::Keepalive),
+            3
+        );
+        assert_eq!(stats.count_both(StatType::Message, DetailType::All), 3);
+        assert_eq!(stats.count_both(StatType::Message, DetailType::Publish), 0);
+    }
+
+    #[test]
+    fn since_and_mark() {
+        let stats = Stats::new(StatsConfig::new());
+        let mut mark = stats.mark();
+        stats.add(StatType::Ledger, DetailType::Send, 3);
+
+        let diff = stats.since_and_mark(&mut mark);
+        assert_eq!(
+            diff.get(&CounterKey::new(
+                StatType::Ledger,
+                DetailType::Send,
+                Direction::In
+            )),
+            Some(&3)
+        );
+
+        stats.inc(StatType::Ledger, DetailType::Send);
+        let diff = stats.since_and_mark(&mut mark);
+        assert_eq!(
+            diff.get(&CounterKey::new(
+                StatType::Ledger,
+                DetailType::Send,
+                Direction::In
+            )),
+            Some(&1)
+        );
+        assert!(stats.since_and_mark(&mut mark).is_empty());
+    }
+
+    #[test]
+    fn counters_since_mark() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.inc(StatType::Ledger, DetailType::Send);
+        stats.inc(StatType::Ledger, DetailType::Receive);
+
+        let mark = stats.mark();
+        stats.add(StatType::Ledger, DetailType::Send, 3);
+        stats.inc(StatType::Vote, DetailType::Valid);
+
+        let diff = stats.since(&mark);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(
+            diff.get(&CounterKey::new(
+                StatType::Ledger,
+                DetailType::Send,
+                Direction::In
+            )),
+            Some(&3)
+        );
+        assert_eq!(
+            diff.get(&CounterKey::new(
+                StatType::Vote,
+                DetailType::Valid,
+                Direction::In
+            )),
+            Some(&1)
+        );
+    }
+
+    #[test]
+    fn windowed_counter() {
+        let stats = Stats::new(StatsConfig {
+            windowed_types: vec![StatType::Vote],
+            ..StatsConfig::new()
+        });
+        stats.add(StatType::Vote, DetailType::Valid, 3);
+        stats.inc(StatType::Vote, DetailType::Valid);
+
+        assert_eq!(
+            stats.windowed(
+                StatType::Vote,
+                DetailType::Valid,
+                Direction::In,
+                Duration::from_secs(60)
+            ),
+            4
+        );
+        assert_eq!(
+            stats.windowed(
+                StatType::Vote,
+                DetailType::Invalid,
+                Direction::In,
+                Duration::from_secs(60)
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn windowing_is_opt_in() {
+        let stats = Stats::new(StatsConfig {
+            windowed_types: Vec::new(),
+            ..StatsConfig::new()
+        });
+        stats.inc(StatType::Vote, DetailType::Valid);
+
+        assert_eq!(
+            stats.count(StatType::Vote, DetailType::Valid, Direction::In),
+            1
+        );
+        assert_eq!(
+            stats.windowed(
+                StatType::Vote,
+                DetailType::Valid,
+                Direction::In,
+                Duration::from_secs(60)
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn windowed_all() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.add(StatType::Error, DetailType::UnreachableHost, 2);
+        stats.inc(StatType::Error, DetailType::InsufficientWork);
+        stats.inc_dir(StatType::Error, DetailType::UnreachableHost, Direction::Out);
+        stats.inc(StatType::Vote, DetailType::Valid);
+
+        assert_eq!(
+            stats.windowed_all(StatType::Error, Direction::In, Duration::from_secs(60)),
+            3
+        );
+    }
+
     #[test]
     fn samples() {
         let stats = Stats::new(StatsConfig::new());
@@ -558,4 +995,93 @@ mod tests {
         let samples4 = stats.samples(Sample::BootstrapTagDuration);
         assert_eq!(samples4, [2137]);
     }
+
+    #[test]
+    fn sample_stats() {
+        let stats = Stats::new(StatsConfig::new());
+        assert_eq!(stats.sample_stats(Sample::ConfirmationLatency), None);
+
+        stats.sample(Sample::ConfirmationLatency, 10, (0, 100));
+        stats.sample(Sample::ConfirmationLatency, 20, (0, 100));
+        stats.sample(Sample::ConfirmationLatency, 60, (0, 100));
+
+        let expected = SampleStats {
+            count: 3,
+            min: 10,
+            max: 60,
+            average: 30,
+        };
+        assert_eq!(
+            stats.sample_stats(Sample::ConfirmationLatency),
+            Some(expected)
+        );
+        // not drained
+        assert_eq!(
+            stats.sample_stats(Sample::ConfirmationLatency),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn default_description() {
+        let stats = Stats::new(StatsConfig::new());
+        assert_eq!(
+            stats.description(StatType::Ledger, DetailType::Send),
+            "send"
+        );
+    }
+
+    #[test]
+    fn prometheus_output_contains_description() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.describe(StatType::Ledger, DetailType::Send, "Send blocks processed");
+        stats.inc(StatType::Ledger, DetailType::Send);
+        stats.inc(StatType::Ledger, DetailType::Receive);
+
+        let mut sink = StatsPrometheusWriter::new();
+        stats.log_counters(&mut sink).unwrap();
+        let output = sink.to_string();
+
+        assert!(output.contains("# HELP rsban_ledger_send Send blocks processed\n"));
+        assert!(output.contains("rsban_ledger_send{dir=\"in\"} 1\n"));
+        assert!(output.contains("# HELP rsban_ledger_receive receive\n"));
+    }
+
+    #[test]
+    fn prometheus_output_contains_instance_label() {
+        let output_for = |label: &str| {
+            let stats = Stats::new(StatsConfig {
+                instance_label: Some(label.to_owned()),
+                ..Default::default()
+            });
+            stats.inc(StatType::Ledger, DetailType::Send);
+            let mut sink = StatsPrometheusWriter::new();
+            stats.log_counters(&mut sink).unwrap();
+            sink.to_string()
+        };
+
+        let output1 = output_for("node1");
+        let output2 = output_for("node2");
+
+        assert!(output1.contains("rsban_ledger_send{instance=\"node1\",dir=\"in\"} 1\n"));
+        assert!(output2.contains("rsban_ledger_send{instance=\"node2\",dir=\"in\"} 1\n"));
+        assert_ne!(output1, output2);
+    }
+
+    #[test]
+    fn prometheus_output_is_escaped() {
+        let stats = Stats::new(StatsConfig {
+            instance_label: Some("a\"b\\c\nd".to_owned()),
+            ..Default::default()
+        });
+        stats.describe(StatType::Ledger, DetailType::Send, "line 1\nline \\2");
+        stats.inc(StatType::Ledger, DetailType::Send);
+
+        let mut sink = StatsPrometheusWriter::new();
+        stats.log_counters(&mut sink).unwrap();
+        let output = sink.to_string();
+
+        assert!(output.contains("# HELP rsban_ledger_send line 1\\nline \\\\2\n"));
+        assert!(output.contains("rsban_ledger_send{instance=\"a\\\"b\\\\c\\nd\",dir=\"in\"} 1\n"));
+    }
 }
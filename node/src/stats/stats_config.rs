@@ -1,3 +1,4 @@
+use super::StatType;
 use std::time::Duration;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -22,6 +23,22 @@ pub struct StatsConfig {
 
     /** Filename for the sampling log */
     pub log_samples_filename: String,
+
+    /** Size of the time slots of the windowed counters */
+    pub window_bucket_size: Duration,
+
+    /** Stat types whose counters additionally track increments over time.
+    Windowing costs a lock per increment, so only the types that are queried need it */
+    pub windowed_types: Vec<StatType>,
+
+    /** Longest window that can be queried from the windowed counters */
+    pub max_window: Duration,
+
+    /** Optional label which distinguishes the stats of multiple nodes in a shared sink */
+    pub instance_label: Option<String>,
+
+    /** Periodically push the stats to a StatsD server if set */
+    pub statsd: Option<StatsdConfig>,
 }
 
 impl Default for StatsConfig {
@@ -34,6 +51,11 @@ impl Default for StatsConfig {
             log_headers: true,
             log_counters_filename: "counters.stat".to_string(),
             log_samples_filename: "samples.stat".to_string(),
+            window_bucket_size: Duration::from_secs(1),
+            windowed_types: vec![StatType::Error],
+            max_window: Duration::from_secs(5 * 60),
+            instance_label: None,
+            statsd: None,
         }
     }
 }
@@ -43,3 +65,26 @@ impl StatsConfig {
         Default::default()
     }
 }
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatsdConfig {
+    pub host: String,
+    pub port: u16,
+
+    /** Prepended to all metric names */
+    pub prefix: String,
+
+    /** How often the counters and samples get pushed */
+    pub interval: Duration,
+}
+
+impl Default for StatsdConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 8125,
+            prefix: "banano".to_string(),
+            interval: Duration::from_secs(10),
+        }
+    }
+}
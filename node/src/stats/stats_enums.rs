@@ -86,6 +86,7 @@ pub enum StatType {
     MessageProcessorOverfill,
     MessageProcessorType,
     ProcessConfirmed,
+    Websocket,
 }
 
 impl StatType {
@@ -201,12 +202,14 @@ pub enum DetailType {
 
     // dropped messages
     ConfirmAckZeroAccount,
+    MessageExpired,
 
     // bootstrap, callback
     Initiate,
     InitiateLegacyAge,
     InitiateLazy,
     InitiateWalletLazy,
+    AttemptLimitReached,
 
     // bootstrap specific
     BulkPull,
@@ -308,6 +311,7 @@ pub enum DetailType {
     ConnectRejected,
     ConnectSuccess,
     AttemptTimeout,
+    AttemptInProgress,
     NotAPeer,
 
     // tcp_channels
@@ -323,6 +327,8 @@ pub enum DetailType {
     HandshakeInitiate,
     HandshakeResponse,
     HandshakeResponseInvalid,
+    HandshakeTimeout,
+    MessageRateLimited,
 
     // ipc
     Invocations,
@@ -551,7 +557,11 @@ impl Direction {
 pub enum Sample {
     ActiveElectionDuration,
     BootstrapTagDuration,
+    ConfirmationLatency,
+    MessageDeserialization,
     RepResponseTime,
+    TrafficIn,
+    TrafficOut,
     VoteGeneratorFinalHashes,
     VoteGeneratorHashes,
 }
@@ -1,6 +1,9 @@
 use anyhow::Result;
 use chrono::{DateTime, Local};
-use std::{any::Any, fs::File, io::Write, path::PathBuf, time::SystemTime};
+use std::{
+    any::Any, collections::HashMap, fmt::Write as _, fs::File, io::Write, path::PathBuf,
+    time::SystemTime,
+};
 
 pub trait StatsLogSink {
     /// Called before logging starts
@@ -22,6 +25,21 @@ pub trait StatsLogSink {
         value: u64,
     ) -> Result<()>;
 
+    /// Passes the registered description of a counter. Called before the counter entries are written.
+    fn write_counter_description(
+        &mut self,
+        _entry_type: &str,
+        _detail: &str,
+        _description: &str,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Passes the instance label of the stats. Called before any entries are written.
+    fn write_instance_label(&mut self, _label: &str) -> Result<()> {
+        Ok(())
+    }
+
     fn write_sampler_entry(
         &mut self,
         time: SystemTime,
@@ -137,6 +155,7 @@ impl StatsLogSink for StatFileWriter {
 pub struct StatsJsonWriterV2 {
     tree: serde_json::Map<String, serde_json::Value>,
     entries: Vec<serde_json::Value>,
+    descriptions: HashMap<(String, String), String>,
     log_entries: usize,
 }
 
@@ -145,6 +164,7 @@ impl StatsJsonWriterV2 {
         Self {
             tree: Default::default(),
             entries: Default::default(),
+            descriptions: HashMap::new(),
             log_entries: 0,
         }
     }
@@ -168,6 +188,7 @@ impl Default for StatsJsonWriterV2 {
 impl StatsLogSink for StatsJsonWriterV2 {
     fn begin(&mut self) -> Result<()> {
         self.tree.clear();
+        self.descriptions.clear();
         Ok(())
     }
 
@@ -217,10 +238,40 @@ impl StatsLogSink for StatsJsonWriterV2 {
             "value".to_owned(),
             serde_json::Value::String(value.to_string()),
         );
+        if let Some(description) = self
+            .descriptions
+            .get(&(entry_type.to_owned(), detail.to_owned()))
+        {
+            entry.insert(
+                "description".to_owned(),
+                serde_json::Value::String(description.clone()),
+            );
+        }
         self.entries.push(serde_json::Value::Object(entry));
         Ok(())
     }
 
+    fn write_counter_description(
+        &mut self,
+        entry_type: &str,
+        detail: &str,
+        description: &str,
+    ) -> Result<()> {
+        self.descriptions.insert(
+            (entry_type.to_owned(), detail.to_owned()),
+            description.to_owned(),
+        );
+        Ok(())
+    }
+
+    fn write_instance_label(&mut self, label: &str) -> Result<()> {
+        self.tree.insert(
+            "instance".to_owned(),
+            serde_json::Value::String(label.to_owned()),
+        );
+        Ok(())
+    }
+
     fn rotate(&mut self) -> Result<()> {
         Ok(())
     }
@@ -276,3 +327,136 @@ impl StatsLogSink for StatsJsonWriterV2 {
         Ok(())
     }
 }
+
+/// Writes counters in the Prometheus text exposition format. Each detail of a stat type
+/// is a separate metric with the direction as label. Samples are not supported.
+pub struct StatsPrometheusWriter {
+    output: String,
+    descriptions: HashMap<(String, String), String>,
+    current_metric: Option<String>,
+    instance_label: Option<String>,
+    log_entries: usize,
+}
+
+impl StatsPrometheusWriter {
+    pub fn new() -> Self {
+        Self {
+            output: String::new(),
+            descriptions: HashMap::new(),
+            current_metric: None,
+            instance_label: None,
+            log_entries: 0,
+        }
+    }
+}
+
+/// Escapes backslashes and line breaks, as required for HELP lines
+fn escape_prometheus_help(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+/// Escapes backslashes, double quotes and line breaks, as required for label values
+fn escape_prometheus_label(value: &str) -> String {
+    escape_prometheus_help(value).replace('"', "\\\"")
+}
+
+impl Default for StatsPrometheusWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StatsLogSink for StatsPrometheusWriter {
+    fn begin(&mut self) -> Result<()> {
+        self.output.clear();
+        self.descriptions.clear();
+        self.current_metric = None;
+        self.instance_label = None;
+        Ok(())
+    }
+
+    fn finalize(&mut self) {}
+
+    fn write_header(&mut self, _header: &str, _walltime: SystemTime) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_counter_entry(
+        &mut self,
+        _time: SystemTime,
+        entry_type: &str,
+        detail: &str,
+        dir: &str,
+        value: u64,
+    ) -> Result<()> {
+        let metric = format!("rsban_{entry_type}_{detail}");
+        if self.current_metric.as_ref() != Some(&metric) {
+            let help = self
+                .descriptions
+                .get(&(entry_type.to_owned(), detail.to_owned()))
+                .map(|d| d.as_str())
+                .unwrap_or(detail);
+            let help = escape_prometheus_help(help);
+            writeln!(self.output, "# HELP {metric} {help}")?;
+            writeln!(self.output, "# TYPE {metric} counter")?;
+            self.current_metric = Some(metric.clone());
+        }
+        let dir = escape_prometheus_label(dir);
+        match &self.instance_label {
+            Some(instance) => writeln!(
+                self.output,
+                "{metric}{{instance=\"{instance}\",dir=\"{dir}\"}} {value}"
+            )?,
+            None => writeln!(self.output, "{metric}{{dir=\"{dir}\"}} {value}")?,
+        }
+        Ok(())
+    }
+
+    fn write_counter_description(
+        &mut self,
+        entry_type: &str,
+        detail: &str,
+        description: &str,
+    ) -> Result<()> {
+        self.descriptions.insert(
+            (entry_type.to_owned(), detail.to_owned()),
+            description.to_owned(),
+        );
+        Ok(())
+    }
+
+    fn write_instance_label(&mut self, label: &str) -> Result<()> {
+        self.instance_label = Some(escape_prometheus_label(label));
+        Ok(())
+    }
+
+    fn write_sampler_entry(
+        &mut self,
+        _time: SystemTime,
+        _sample: &str,
+        _values: Vec<i64>,
+        _expected_min_max: (i64, i64),
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn entries(&self) -> usize {
+        self.log_entries
+    }
+
+    fn inc_entries(&mut self) {
+        self.log_entries += 1;
+    }
+
+    fn to_string(&self) -> String {
+        self.output.clone()
+    }
+
+    fn to_object(&self) -> Option<&dyn Any> {
+        None
+    }
+}
@@ -0,0 +1,257 @@
+use super::{StatsLogSink, StatsdConfig};
+use anyhow::Result;
+use std::{
+    any::Any,
+    collections::HashMap,
+    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+    time::SystemTime,
+};
+
+/// Lines are batched into datagrams of at most this size, so that they
+/// don't get fragmented on a standard ethernet MTU
+const MAX_DATAGRAM_SIZE: usize = 1432;
+
+/// Pushes counters to a StatsD server over UDP. Counters are sent as StatsD
+/// counters containing the increase since the last push. Samples written to this
+/// sink are sent as timings. If an instance label is set, it is added as a DogStatsD tag.
+pub struct StatsdSink {
+    socket: UdpSocket,
+    target: SocketAddr,
+    prefix: String,
+    tags: String,
+    datagram: String,
+    last_counters: HashMap<String, u64>,
+    log_entries: usize,
+}
+
+impl StatsdSink {
+    pub fn new(config: &StatsdConfig) -> Result<Self> {
+        let target = (config.host.as_str(), config.port)
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| anyhow!("could not resolve StatsD host {}", config.host))?;
+
+        let bind_addr = if target.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(bind_addr)?;
+        // The stats loop must never wait for the network
+        socket.set_nonblocking(true)?;
+
+        Ok(Self {
+            socket,
+            target,
+            prefix: config.prefix.clone(),
+            tags: String::new(),
+            datagram: String::new(),
+            last_counters: HashMap::new(),
+            log_entries: 0,
+        })
+    }
+
+    fn metric_name(&self, parts: &[&str]) -> String {
+        let mut name = self.prefix.clone();
+        for part in parts {
+            if !name.is_empty() {
+                name.push('.');
+            }
+            name.push_str(part);
+        }
+        name
+    }
+
+    fn push_line(&mut self, line: String) {
+        if !self.datagram.is_empty() && self.datagram.len() + 1 + line.len() > MAX_DATAGRAM_SIZE {
+            self.flush();
+        }
+        if !self.datagram.is_empty() {
+            self.datagram.push('\n');
+        }
+        self.datagram.push_str(&line);
+    }
+
+    fn flush(&mut self) {
+        if self.datagram.is_empty() {
+            return;
+        }
+        // StatsD is lossy by design, so a datagram which can't be sent right away is dropped
+        let _ = self.socket.send_to(self.datagram.as_bytes(), self.target);
+        self.datagram.clear();
+    }
+}
+
+impl StatsLogSink for StatsdSink {
+    fn begin(&mut self) -> Result<()> {
+        self.tags.clear();
+        self.datagram.clear();
+        Ok(())
+    }
+
+    fn finalize(&mut self) {
+        self.flush();
+    }
+
+    fn write_header(&mut self, _header: &str, _walltime: SystemTime) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_counter_entry(
+        &mut self,
+        _time: SystemTime,
+        entry_type: &str,
+        detail: &str,
+        dir: &str,
+        value: u64,
+    ) -> Result<()> {
+        let name = self.metric_name(&[entry_type, detail, dir]);
+        let last = self.last_counters.insert(name.clone(), value).unwrap_or(0);
+        // The counter was reset if it is smaller than before
+        let delta = if value >= last { value - last } else { value };
+        if delta > 0 {
+            let line = format!("{}:{}|c{}", name, delta, self.tags);
+            self.push_line(line);
+        }
+        Ok(())
+    }
+
+    fn write_instance_label(&mut self, label: &str) -> Result<()> {
+        self.tags = format!("|#instance:{}", label);
+        Ok(())
+    }
+
+    fn write_sampler_entry(
+        &mut self,
+        _time: SystemTime,
+        sample: &str,
+        values: Vec<i64>,
+        _expected_min_max: (i64, i64),
+    ) -> Result<()> {
+        let name = self.metric_name(&[sample]);
+        for value in values {
+            let line = format!("{}:{}|ms{}", name, value, self.tags);
+            self.push_line(line);
+        }
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn entries(&self) -> usize {
+        self.log_entries
+    }
+
+    fn inc_entries(&mut self) {
+        self.log_entries += 1;
+    }
+
+    fn to_string(&self) -> String {
+        String::new()
+    }
+
+    fn to_object(&self) -> Option<&dyn Any> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{DetailType, Sample, StatType, Stats, StatsConfig};
+    use std::time::Duration;
+
+    #[test]
+    fn send_counter_to_statsd_server() {
+        let (server, mut sink) = create_server_and_sink();
+        let stats = Stats::new(StatsConfig::new());
+        stats.add(StatType::Ledger, DetailType::Send, 5);
+
+        stats.log_counters(&mut sink).unwrap();
+
+        assert_eq!(receive(&server), "banano.ledger.send.in:5|c");
+    }
+
+    #[test]
+    fn send_only_increase_since_last_push() {
+        let (server, mut sink) = create_server_and_sink();
+        let stats = Stats::new(StatsConfig::new());
+        stats.add(StatType::Ledger, DetailType::Send, 5);
+        stats.log_counters(&mut sink).unwrap();
+        receive(&server);
+
+        stats.add(StatType::Ledger, DetailType::Send, 2);
+        stats.log_counters(&mut sink).unwrap();
+
+        assert_eq!(receive(&server), "banano.ledger.send.in:2|c");
+    }
+
+    #[test]
+    fn add_instance_label_as_tag() {
+        let (server, mut sink) = create_server_and_sink();
+        let stats = Stats::new(StatsConfig {
+            instance_label: Some("node1".to_string()),
+            ..Default::default()
+        });
+        stats.add(StatType::Ledger, DetailType::Send, 5);
+
+        stats.log_counters(&mut sink).unwrap();
+
+        assert_eq!(
+            receive(&server),
+            "banano.ledger.send.in:5|c|#instance:node1"
+        );
+    }
+
+    #[test]
+    fn push_samples_as_timings_without_draining() {
+        let server = create_server();
+        let stats = Stats::new(StatsConfig {
+            statsd: Some(StatsdConfig {
+                port: server.local_addr().unwrap().port(),
+                interval: Duration::ZERO,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        stats.sample(Sample::ActiveElectionDuration, 5, (1, 10));
+
+        stats.start();
+        let received = (0..10)
+            .map(|_| receive(&server))
+            .find(|datagram| datagram.contains("|ms"));
+        stats.stop();
+
+        assert_eq!(
+            received.as_deref(),
+            Some("banano.active_election_duration:5|ms")
+        );
+        assert_eq!(stats.samples(Sample::ActiveElectionDuration), vec![5]);
+    }
+
+    fn create_server() -> UdpSocket {
+        let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+        server
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        server
+    }
+
+    fn create_server_and_sink() -> (UdpSocket, StatsdSink) {
+        let server = create_server();
+        let sink = StatsdSink::new(&StatsdConfig {
+            port: server.local_addr().unwrap().port(),
+            ..Default::default()
+        })
+        .unwrap();
+        (server, sink)
+    }
+
+    fn receive(server: &UdpSocket) -> String {
+        let mut buffer = [0; MAX_DATAGRAM_SIZE];
+        let len = server.recv(&mut buffer).unwrap();
+        String::from_utf8(buffer[..len].to_vec()).unwrap()
+    }
+}
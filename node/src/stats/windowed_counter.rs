@@ -0,0 +1,114 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// Counts increments in time slots of a fixed size, so that the sum over
+/// a trailing time window can be queried. Slots older than `max_window`
+/// are discarded.
+pub struct WindowedCounter {
+    start: Instant,
+    bucket_size: Duration,
+    max_buckets: u64,
+    /// (slot number, count) sorted by slot number
+    buckets: VecDeque<(u64, u64)>,
+}
+
+impl WindowedCounter {
+    pub fn new(start: Instant, bucket_size: Duration, max_window: Duration) -> Self {
+        let bucket_size = bucket_size.max(Duration::from_millis(1));
+        Self {
+            start,
+            bucket_size,
+            max_buckets: buckets_for(max_window, bucket_size),
+            buckets: VecDeque::new(),
+        }
+    }
+
+    pub fn add(&mut self, now: Instant, value: u64) {
+        let slot = self.slot(now);
+        match self.buckets.back_mut() {
+            Some((last_slot, count)) if *last_slot == slot => *count += value,
+            _ => self.buckets.push_back((slot, value)),
+        }
+        self.discard_old(slot);
+    }
+
+    /// Sum of all increments in the trailing `window`, including the current slot
+    pub fn sum(&self, now: Instant, window: Duration) -> u64 {
+        let slot = self.slot(now);
+        let window_buckets = buckets_for(window, self.bucket_size).min(self.max_buckets);
+        let oldest = (slot + 1).saturating_sub(window_buckets);
+        self.buckets
+            .iter()
+            .rev()
+            .take_while(|(s, _)| *s >= oldest)
+            .filter(|(s, _)| *s <= slot)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    fn discard_old(&mut self, current_slot: u64) {
+        let oldest = (current_slot + 1).saturating_sub(self.max_buckets);
+        while let Some((slot, _)) = self.buckets.front() {
+            if *slot >= oldest {
+                break;
+            }
+            self.buckets.pop_front();
+        }
+    }
+
+    fn slot(&self, now: Instant) -> u64 {
+        (now.saturating_duration_since(self.start).as_nanos() / self.bucket_size.as_nanos()) as u64
+    }
+}
+
+fn buckets_for(window: Duration, bucket_size: Duration) -> u64 {
+    window.as_nanos().div_ceil(bucket_size.as_nanos()) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let start = Instant::now();
+        let counter = WindowedCounter::new(start, Duration::from_secs(1), Duration::from_secs(60));
+        assert_eq!(counter.sum(start, Duration::from_secs(60)), 0);
+    }
+
+    #[test]
+    fn sum_within_window() {
+        let start = Instant::now();
+        let mut counter =
+            WindowedCounter::new(start, Duration::from_secs(1), Duration::from_secs(60));
+
+        counter.add(start, 1);
+        counter.add(start + Duration::from_millis(500), 2);
+        counter.add(start + Duration::from_secs(5), 3);
+
+        let now = start + Duration::from_secs(5);
+        assert_eq!(counter.sum(now, Duration::from_secs(60)), 6);
+        assert_eq!(counter.sum(now, Duration::from_secs(1)), 3);
+    }
+
+    #[test]
+    fn drop_old_buckets() {
+        let start = Instant::now();
+        let mut counter =
+            WindowedCounter::new(start, Duration::from_secs(10), Duration::from_secs(60));
+
+        counter.add(start, 1);
+        counter.add(start + Duration::from_secs(30), 2);
+        counter.add(start + Duration::from_secs(65), 4);
+
+        let now = start + Duration::from_secs(65);
+        assert_eq!(counter.sum(now, Duration::from_secs(60)), 6);
+        assert_eq!(counter.sum(now, Duration::from_secs(20)), 4);
+
+        let later = start + Duration::from_secs(100);
+        assert_eq!(counter.sum(later, Duration::from_secs(60)), 4);
+        assert_eq!(counter.sum(later, Duration::from_secs(10)), 0);
+    }
+}
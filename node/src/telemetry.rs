@@ -148,6 +148,7 @@ impl Telemetry {
             return;
         }
         let data = telemetry.0.as_ref().unwrap();
+        channel.rtt_probe_answered(self.clock.now());
 
         let mut guard = self.mutex.lock().unwrap();
         let peer_addr = channel.peer_addr();
@@ -266,6 +267,9 @@ impl Telemetry {
 
     fn request(&self, channel_id: ChannelId) {
         self.stats.inc(StatType::Telemetry, DetailType::Request);
+        if let Some(channel) = self.network_info.read().unwrap().get(channel_id) {
+            channel.rtt_probe_sent(self.clock.now());
+        }
         self.message_publisher.lock().unwrap().try_send(
             channel_id,
             &Message::TelemetryReq,
@@ -181,6 +181,11 @@ impl HandshakeProcess {
                     return HandshakeStatus::Realtime(response.node_id); // Switch to realtime
                 }
                 Err(HandshakeResponseError::OwnNodeId) => {
+                    self.stats.inc_dir(
+                        StatType::Handshake,
+                        HandshakeResponseError::OwnNodeId.into(),
+                        Direction::In,
+                    );
                     warn!(
                         "This node tried to connect to itself. Closing channel ({})",
                         self.remote_endpoint
@@ -320,3 +325,33 @@ impl From<HandshakeResponseError> for DetailType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn abort_when_response_contains_own_node_id() {
+        let process = HandshakeProcess::new_null();
+        let response = NodeIdHandshakeResponse::new_v1(&[1; 32], &process.node_id);
+        let message = NodeIdHandshake {
+            query: None,
+            response: Some(response),
+            is_v2: false,
+        };
+
+        let status = process
+            .process_handshake(&message, &Channel::new_null())
+            .await;
+
+        assert!(matches!(status, HandshakeStatus::AbortOwnNodeId));
+        assert_eq!(
+            process.stats.count(
+                StatType::Handshake,
+                DetailType::InvalidNodeId,
+                Direction::In
+            ),
+            1
+        );
+    }
+}
@@ -14,15 +14,17 @@ pub struct InboundMessageQueue {
     stats: Arc<Stats>,
     inbound_callback: Option<MessageCallback>,
     inbound_dropped_callback: Option<MessageCallback>,
+    max_total_queue: usize,
 }
 
 impl InboundMessageQueue {
-    pub fn new(max_queue: usize, stats: Arc<Stats>) -> Self {
+    pub fn new(max_queue: usize, max_total_queue: usize, stats: Arc<Stats>) -> Self {
         Self {
             state: Mutex::new(State {
                 queue: FairQueue::new(Box::new(move |_| max_queue), Box::new(|_| 1)),
                 stopped: false,
             }),
+            max_total_queue,
             condition: Condvar::new(),
             stats,
             inbound_callback: None,
@@ -40,12 +42,16 @@ impl InboundMessageQueue {
 
     pub fn put(&self, message: Message, channel: Arc<ChannelInfo>) -> bool {
         let message_type = message.message_type();
-        let added = self
-            .state
-            .lock()
-            .unwrap()
-            .queue
-            .push(channel.channel_id(), (message.clone(), channel.clone()));
+        let added = {
+            let mut guard = self.state.lock().unwrap();
+            if guard.queue.len() >= self.max_total_queue {
+                false
+            } else {
+                guard
+                    .queue
+                    .push(channel.channel_id(), (message.clone(), channel.clone()))
+            }
+        };
 
         if added {
             self.stats
@@ -92,6 +98,11 @@ impl InboundMessageQueue {
         self.state.lock().unwrap().queue.len()
     }
 
+    /// Maximum number of queued messages across all channels
+    pub fn capacity(&self) -> usize {
+        self.max_total_queue
+    }
+
     /// Stop container and notify waiting threads
     pub fn stop(&self) {
         {
@@ -111,7 +122,7 @@ impl InboundMessageQueue {
 
 impl Default for InboundMessageQueue {
     fn default() -> Self {
-        Self::new(64, Arc::new(Stats::default()))
+        Self::new(64, 1024 * 16, Arc::new(Stats::default()))
     }
 }
 
@@ -140,11 +151,12 @@ struct State {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stats::Direction;
     use rsban_messages::Message;
 
     #[test]
     fn put_and_get_one_message() {
-        let manager = InboundMessageQueue::new(1, Arc::new(Stats::default()));
+        let manager = InboundMessageQueue::new(1, 1, Arc::new(Stats::default()));
         assert_eq!(manager.size(), 0);
         manager.put(
             Message::BulkPush,
@@ -154,4 +166,25 @@ mod tests {
         assert_eq!(manager.next_batch(1000).len(), 1);
         assert_eq!(manager.size(), 0);
     }
+
+    #[test]
+    fn drop_messages_when_total_capacity_reached() {
+        let stats = Arc::new(Stats::default());
+        let manager = InboundMessageQueue::new(3, 2, stats.clone());
+        let channel = Arc::new(ChannelInfo::new_test_instance());
+
+        assert!(manager.put(Message::BulkPush, channel.clone()));
+        assert!(manager.put(Message::BulkPush, channel.clone()));
+        assert!(!manager.put(Message::BulkPush, channel));
+
+        assert_eq!(manager.size(), 2);
+        assert_eq!(
+            stats.count(
+                StatType::MessageProcessor,
+                DetailType::Overfill,
+                Direction::In
+            ),
+            1
+        );
+    }
 }
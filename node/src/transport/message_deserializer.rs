@@ -1,8 +1,9 @@
 use super::NetworkFilter;
+use crate::stats::{Sample, Stats};
 use rsban_core::{utils::BufferReader, work::WorkThresholds};
 use rsban_messages::*;
 use rsban_network::AsyncBufferReader;
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 pub struct MessageDeserializer<T: AsyncBufferReader + Send> {
     network_filter: Arc<NetworkFilter>,
@@ -10,14 +11,19 @@ pub struct MessageDeserializer<T: AsyncBufferReader + Send> {
     protocol_info: ProtocolInfo,
     read_buffer: Vec<u8>,
     buffer_reader: T,
+    stats: Arc<Stats>,
 }
 
 impl<T: AsyncBufferReader + Send> MessageDeserializer<T> {
+    /// Expected range of a single deserialization in microseconds
+    const DESERIALIZATION_MIN_MAX: (i64, i64) = (0, 10_000);
+
     pub fn new(
         protocol_info: ProtocolInfo,
         work_thresholds: WorkThresholds,
         network_filter: Arc<NetworkFilter>,
         buffer_reader: T,
+        stats: Arc<Stats>,
     ) -> Self {
         Self {
             protocol_info,
@@ -25,6 +31,7 @@ impl<T: AsyncBufferReader + Send> MessageDeserializer<T> {
             buffer_reader,
             work_thresholds,
             network_filter,
+            stats,
         }
     }
 
@@ -65,8 +72,14 @@ impl<T: AsyncBufferReader + Send> MessageDeserializer<T> {
     ) -> Result<DeserializedMessage, ParseMessageError> {
         let payload_bytes = &self.read_buffer[..payload_size];
         let digest = self.filter_duplicate_messages(header.message_type, payload_bytes)?;
+        let start = Instant::now();
         let message = Message::deserialize(payload_bytes, &header, digest)
             .ok_or(ParseMessageError::InvalidMessage(header.message_type))?;
+        self.stats.sample(
+            Sample::MessageDeserialization,
+            start.elapsed().as_micros() as i64,
+            Self::DESERIALIZATION_MIN_MAX,
+        );
         self.validate_work(&message)?;
         Ok(DeserializedMessage::new(message, header.protocol))
     }
@@ -132,6 +145,7 @@ mod tests {
             WorkThresholds::publish_full().clone(),
             Arc::new(NetworkFilter::default()),
             reader,
+            Arc::new(Stats::default()),
         );
 
         let error = deserializer.read().await.unwrap_err();
@@ -154,6 +168,7 @@ mod tests {
             WorkThresholds::new(0, 0, 0),
             Arc::new(NetworkFilter::default()),
             reader,
+            Arc::new(Stats::default()),
         );
 
         deserializer.read().await.unwrap();
@@ -177,6 +192,7 @@ mod tests {
             WorkThresholds::new(0, 0, 0),
             Arc::new(NetworkFilter::default()),
             reader,
+            Arc::new(Stats::default()),
         );
 
         deserializer.read().await.unwrap();
@@ -184,4 +200,31 @@ mod tests {
 
         assert_eq!(error, ParseMessageError::DuplicateConfirmAckMessage);
     }
+
+    #[tokio::test]
+    async fn sample_deserialization_duration() {
+        let protocol = ProtocolInfo::default();
+        let mut serializer = MessageSerializer::new(protocol);
+        let mut buffer = serializer
+            .serialize(&Message::Keepalive(Keepalive::new_test_instance()))
+            .to_vec();
+        buffer.extend_from_slice(serializer.serialize(&Message::TelemetryReq));
+        let reader = VecBufferReader::new(buffer);
+        let stats = Arc::new(Stats::default());
+
+        let mut deserializer = MessageDeserializer::new(
+            protocol,
+            WorkThresholds::new(0, 0, 0),
+            Arc::new(NetworkFilter::default()),
+            reader,
+            stats.clone(),
+        );
+
+        deserializer.read().await.unwrap();
+        deserializer.read().await.unwrap();
+
+        let samples = stats.samples(Sample::MessageDeserialization);
+        assert_eq!(samples.len(), 2);
+        assert!(samples.iter().all(|s| *s >= 0));
+    }
 }
@@ -18,6 +18,8 @@ use tracing::debug;
 pub struct MessageProcessorConfig {
     pub threads: usize,
     pub max_queue: usize,
+    /// Maximum number of queued messages across all channels
+    pub max_total_queue: usize,
 }
 
 impl MessageProcessorConfig {
@@ -25,6 +27,7 @@ impl MessageProcessorConfig {
         Self {
             threads: min(2, max(parallelism / 4, 1)),
             max_queue: 64,
+            max_total_queue: 1024 * 16,
         }
     }
 }
@@ -144,7 +144,20 @@ impl MessagePublisher {
         channels.truncate(count);
     }
 
-    pub fn flood(&mut self, message: &Message, drop_policy: DropPolicy, scale: f32) {
+    pub fn flood(&mut self, message: &Message, drop_policy: DropPolicy, scale: f32) -> usize {
+        self.flood_message(message, drop_policy, TrafficType::Generic, scale)
+    }
+
+    /// Sends the message to a random selection of realtime channels. The size of the
+    /// selection depends on the `scale` and the number of realtime channels.
+    /// Returns the number of channels which accepted the message
+    pub fn flood_message(
+        &mut self,
+        message: &Message,
+        drop_policy: DropPolicy,
+        traffic_type: TrafficType,
+        scale: f32,
+    ) -> usize {
         let buffer = self.message_serializer.serialize(message);
         let channels = self
             .network
@@ -153,17 +166,21 @@ impl MessagePublisher {
             .unwrap()
             .random_fanout_realtime(scale);
 
+        let mut sent_count = 0;
         for channel in channels {
-            try_send_serialized_message(
+            if try_send_serialized_message(
                 &self.network,
                 &self.stats,
                 channel.channel_id(),
                 buffer,
                 message,
                 drop_policy,
-                TrafficType::Generic,
-            );
+                traffic_type,
+            ) {
+                sent_count += 1;
+            }
         }
+        sent_count
     }
 }
 
@@ -189,3 +206,44 @@ fn try_send_serialized_message(
 
     sent
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::DetailType;
+    use rsban_network::{ChannelDirection, ChannelMode};
+    use rsban_nullable_tcp::TcpStream;
+    use std::net::{Ipv6Addr, SocketAddrV6};
+
+    #[tokio::test]
+    async fn flood_to_fanout() {
+        let mut publisher = MessagePublisher::new_null(tokio::runtime::Handle::current());
+        for i in 0..9 {
+            let peer_addr =
+                SocketAddrV6::new(Ipv6Addr::new(0x2000, i, 0, 0, 0, 0, 0, 1), 7075, 0, 0);
+            publisher
+                .network
+                .add(
+                    TcpStream::new_null_with_peer_addr(peer_addr),
+                    ChannelDirection::Inbound,
+                    ChannelMode::Realtime,
+                )
+                .unwrap();
+        }
+
+        let sent = publisher.flood_message(
+            &Message::Keepalive(Default::default()),
+            DropPolicy::ShouldNotDrop,
+            TrafficType::Generic,
+            1.0,
+        );
+
+        assert_eq!(sent, 3);
+        assert_eq!(
+            publisher
+                .stats
+                .count(StatType::Message, DetailType::Keepalive, Direction::Out),
+            3
+        );
+    }
+}
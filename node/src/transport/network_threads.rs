@@ -186,13 +186,14 @@ impl CleanupLoop {
 pub struct KeepaliveFactory {
     pub network: Arc<RwLock<NetworkInfo>>,
     pub config: NodeConfig,
+    pub clock: Arc<SteadyClock>,
 }
 
 impl KeepaliveFactory {
     pub fn create_keepalive_self(&self) -> Keepalive {
         let mut result = Keepalive::default();
         let network = self.network.read().unwrap();
-        network.random_fill_realtime(&mut result.peers);
+        network.random_fill_realtime(&mut result.peers, self.clock.now());
         // We will clobber values in index 0 and 1 and if there are only 2 nodes in the system, these are the only positions occupied
         // Move these items to index 2 and 3 so they propagate
         result.peers[2] = result.peers[0];
@@ -265,7 +266,7 @@ impl KeepaliveLoop {
         let (message, keepalive_list) = {
             let network = self.network.read().unwrap();
             let mut peers = [NULL_ENDPOINT; 8];
-            network.random_fill_realtime(&mut peers);
+            network.random_fill_realtime(&mut peers, self.clock.now());
             let message = Message::Keepalive(Keepalive { peers });
             let list = network.idle_channels(self.keepalive_period, self.clock.now());
             (message, list)
@@ -286,7 +287,7 @@ impl KeepaliveLoop {
         self.network
             .read()
             .unwrap()
-            .random_fill_realtime(&mut keepalive.peers);
+            .random_fill_realtime(&mut keepalive.peers, self.clock.now());
         self.message_publisher
             .flood(&Message::Keepalive(keepalive), DropPolicy::CanDrop, scale);
     }
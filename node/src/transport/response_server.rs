@@ -17,9 +17,14 @@ use async_trait::async_trait;
 use rsban_core::{NodeId, PrivateKey};
 use rsban_ledger::Ledger;
 use rsban_messages::*;
-use rsban_network::{Channel, ChannelMode, ChannelReader, NetworkInfo};
+use rsban_network::{
+    token_bucket::TokenBucket, Channel, ChannelMode, ChannelReader, NetworkConfig, NetworkInfo,
+    RealtimeUpgradeError, TcpSocketOptions,
+};
+use rsban_nullable_clock::Timestamp;
 use rsban_output_tracker::{OutputListenerMt, OutputTrackerMt};
 use std::{
+    collections::HashMap,
     net::SocketAddrV6,
     sync::{
         atomic::{AtomicUsize, Ordering},
@@ -36,6 +41,18 @@ pub struct TcpConfig {
     pub max_attempts: usize,
     pub max_attempts_per_ip: usize,
     pub connect_timeout: Duration,
+    /// Maximum number of realtime messages per second accepted from a
+    /// single connection for each message type. 0 means unlimited.
+    pub max_message_rate: usize,
+    /// Time a connection may stay in undefined mode before it has to send
+    /// either a handshake or a bootstrap request
+    pub handshake_timeout: Duration,
+    /// TCP options for inbound and outbound sockets
+    pub socket_options: TcpSocketOptions,
+    /// Maximum time a send may wait for the write queue or the bandwidth limiter
+    pub send_timeout: Duration,
+    /// A channel gets closed after this many sends in a row timed out. 0 means never
+    pub max_send_failures: u32,
 }
 
 impl TcpConfig {
@@ -46,6 +63,11 @@ impl TcpConfig {
             max_attempts: 128,
             max_attempts_per_ip: 128,
             connect_timeout: Duration::from_secs(5),
+            max_message_rate: 0,
+            handshake_timeout: Duration::from_secs(5),
+            socket_options: TcpSocketOptions::default(),
+            send_timeout: NetworkConfig::DEFAULT_SEND_TIMEOUT,
+            max_send_failures: 0,
         }
     }
 }
@@ -58,14 +80,48 @@ impl Default for TcpConfig {
             max_attempts: 60,
             max_attempts_per_ip: 1,
             connect_timeout: Duration::from_secs(60),
+            max_message_rate: 0,
+            handshake_timeout: Duration::from_secs(30),
+            socket_options: TcpSocketOptions::default(),
+            send_timeout: NetworkConfig::DEFAULT_SEND_TIMEOUT,
+            max_send_failures: 0,
+        }
+    }
+}
+
+/// The state of a connection from the point of view of the response server
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConnectionState {
+    /// Waiting for a handshake or a bootstrap request
+    Undefined,
+    Bootstrap,
+    Realtime,
+}
+
+impl From<ChannelMode> for ConnectionState {
+    fn from(value: ChannelMode) -> Self {
+        match value {
+            ChannelMode::Undefined => ConnectionState::Undefined,
+            ChannelMode::Bootstrap => ConnectionState::Bootstrap,
+            ChannelMode::Realtime => ConnectionState::Realtime,
         }
     }
 }
 
+/// Snapshot of a response server for diagnostics
+#[derive(Clone, Debug)]
+pub struct ResponseServerInfo {
+    pub unique_id: usize,
+    pub connection_state: ConnectionState,
+    pub remote_endpoint: SocketAddrV6,
+    pub remote_node_id: Option<NodeId>,
+}
+
 pub struct ResponseServer {
     channel: Arc<Channel>,
     pub disable_bootstrap_listener: bool,
-    pub connections_max: usize,
+    /// Maximum number of bootstrap connections. Shared by all response servers of a node
+    connections_max: Arc<AtomicUsize>,
 
     // Remote endpoint used to remove response channel even after socket closing
     remote_endpoint: Mutex<SocketAddrV6>,
@@ -76,6 +132,11 @@ pub struct ResponseServer {
     stats: Arc<Stats>,
     pub disable_bootstrap_bulk_pull_server: bool,
     allow_bootstrap: bool,
+    allow_realtime: bool,
+    max_message_rate: usize,
+    message_rate_limiters: Mutex<HashMap<MessageType, TokenBucket>>,
+    handshake_timeout: Duration,
+    handshake_deadline: Mutex<Option<Timestamp>>,
     network_info: Arc<RwLock<NetworkInfo>>,
     inbound_queue: Arc<InboundMessageQueue>,
     handshake_process: HandshakeProcess,
@@ -93,6 +154,8 @@ pub struct ResponseServer {
 static NEXT_UNIQUE_ID: AtomicUsize = AtomicUsize::new(0);
 
 impl ResponseServer {
+    pub const DEFAULT_CONNECTIONS_MAX: usize = 64;
+
     pub fn new(
         network_info: Arc<RwLock<NetworkInfo>>,
         inbound_queue: Arc<InboundMessageQueue>,
@@ -101,6 +164,9 @@ impl ResponseServer {
         network_params: Arc<NetworkParams>,
         stats: Arc<Stats>,
         allow_bootstrap: bool,
+        allow_realtime: bool,
+        max_message_rate: usize,
+        handshake_timeout: Duration,
         syn_cookies: Arc<SynCookies>,
         node_id: PrivateKey,
         tokio: tokio::runtime::Handle,
@@ -110,6 +176,7 @@ impl ResponseServer {
         bootstrap_initiator: Arc<BootstrapInitiator>,
         flags: NodeFlags,
         latest_keepalives: Arc<Mutex<LatestKeepalives>>,
+        connections_max: Arc<AtomicUsize>,
     ) -> Self {
         let network_constants = network_params.network.clone();
         let remote_endpoint = channel.info.peer_addr();
@@ -118,7 +185,7 @@ impl ResponseServer {
             inbound_queue,
             channel,
             disable_bootstrap_listener: false,
-            connections_max: 64,
+            connections_max,
             remote_endpoint: Mutex::new(remote_endpoint),
             last_telemetry_req: Mutex::new(None),
             handshake_process: HandshakeProcess::new(
@@ -134,6 +201,11 @@ impl ResponseServer {
             stats: stats.clone(),
             disable_bootstrap_bulk_pull_server: false,
             allow_bootstrap,
+            allow_realtime,
+            max_message_rate,
+            message_rate_limiters: Mutex::new(HashMap::new()),
+            handshake_timeout,
+            handshake_deadline: Mutex::new(None),
             initiate_handshake_listener: OutputListenerMt::new(),
             network_filter,
             tokio,
@@ -162,6 +234,49 @@ impl ResponseServer {
         *self.remote_endpoint.lock().unwrap()
     }
 
+    /// Starts the deadline until which the connection has to leave the undefined mode
+    pub fn start(&self, now: Timestamp) {
+        *self.handshake_deadline.lock().unwrap() = Some(now + self.handshake_timeout);
+    }
+
+    /// Closes the connection if it is still undefined after the handshake deadline.
+    /// Returns true if the connection was closed
+    pub fn timeout(&self, now: Timestamp) -> bool {
+        let Some(deadline) = *self.handshake_deadline.lock().unwrap() else {
+            return false;
+        };
+
+        if !self.is_undefined_connection() || now < deadline || self.is_stopped() {
+            return false;
+        }
+
+        self.stats.inc_dir(
+            StatType::TcpServer,
+            DetailType::HandshakeTimeout,
+            Direction::In,
+        );
+        debug!(
+            "Closing connection because no handshake was received in time ({})",
+            self.remote_endpoint()
+        );
+        self.channel.info.close();
+        true
+    }
+
+    pub fn handshake_timeout(&self) -> Duration {
+        self.handshake_timeout
+    }
+
+    pub fn connections_max(&self) -> usize {
+        self.connections_max.load(Ordering::Relaxed)
+    }
+
+    /// Changes the maximum number of bootstrap connections for all response servers
+    /// of the node. Existing bootstrap connections are kept when the limit is lowered
+    pub fn set_connections_max(&self, max: usize) {
+        self.connections_max.store(max, Ordering::Relaxed);
+    }
+
     fn is_outside_cooldown_period(&self) -> bool {
         let lock = self.last_telemetry_req.lock().unwrap();
         match *lock {
@@ -172,7 +287,7 @@ impl ResponseServer {
         }
     }
 
-    fn to_bootstrap_connection(&self) -> bool {
+    pub fn to_bootstrap_connection(&self) -> bool {
         if !self.allow_bootstrap {
             return false;
         }
@@ -191,7 +306,7 @@ impl ResponseServer {
             .unwrap()
             .count_by_mode(ChannelMode::Bootstrap);
 
-        if bootstrap_count >= self.connections_max {
+        if bootstrap_count >= self.connections_max() {
             return false;
         }
 
@@ -209,6 +324,19 @@ impl ResponseServer {
         self.unique_id
     }
 
+    pub fn connection_state(&self) -> ConnectionState {
+        self.channel.info.mode().into()
+    }
+
+    pub fn info(&self) -> ResponseServerInfo {
+        ResponseServerInfo {
+            unique_id: self.unique_id,
+            connection_state: self.connection_state(),
+            remote_endpoint: self.remote_endpoint(),
+            remote_node_id: self.channel.info.node_id(),
+        }
+    }
+
     fn is_undefined_connection(&self) -> bool {
         self.channel.info.mode() == ChannelMode::Undefined
     }
@@ -226,6 +354,15 @@ impl ResponseServer {
         // TODO: Throttle if not added
     }
 
+    /// Returns true if the message fits into the rate limit of its message type
+    fn try_consume_message_rate(&self, message_type: MessageType) -> bool {
+        let mut limiters = self.message_rate_limiters.lock().unwrap();
+        limiters
+            .entry(message_type)
+            .or_insert_with(|| TokenBucket::new(self.max_message_rate, self.max_message_rate))
+            .try_consume(1)
+    }
+
     fn set_last_keepalive(&self, keepalive: Keepalive) {
         self.latest_keepalives
             .lock()
@@ -282,6 +419,10 @@ pub enum ProcessResult {
 #[async_trait]
 impl ResponseServerExt for Arc<ResponseServer> {
     fn to_realtime_connection(&self, node_id: &NodeId) -> bool {
+        if !self.allow_realtime {
+            return false;
+        }
+
         if self.channel.info.mode() != ChannelMode::Undefined {
             return false;
         }
@@ -292,28 +433,42 @@ impl ResponseServerExt for Arc<ResponseServer> {
             .unwrap()
             .upgrade_to_realtime_connection(self.channel.channel_id(), *node_id);
 
-        if let Some((channel, observers)) = result {
-            for observer in observers {
-                observer(channel.clone());
-            }
+        match result {
+            Ok((channel, observers)) => {
+                for observer in observers {
+                    observer(channel.clone());
+                }
+
+                self.stats
+                    .inc(StatType::TcpChannels, DetailType::ChannelAccepted);
 
-            self.stats
-                .inc(StatType::TcpChannels, DetailType::ChannelAccepted);
-
-            debug!(
-                "Switched to realtime mode (addr: {}, node_id: {})",
-                self.channel.info.peer_addr(),
-                node_id
-            );
-            true
-        } else {
-            debug!(
-                channel_id = ?self.channel.channel_id(),
-                peer = %self.channel.info.peer_addr(),
-                %node_id,
-                "Could not upgrade channel to realtime connection, because another channel for the same node ID was found",
-            );
-            false
+                debug!(
+                    "Switched to realtime mode (addr: {}, node_id: {})",
+                    self.channel.info.peer_addr(),
+                    node_id
+                );
+                true
+            }
+            Err(RealtimeUpgradeError::ProtocolVersionTooLow) => {
+                self.stats
+                    .inc(StatType::TcpChannels, DetailType::OutdatedVersion);
+                debug!(
+                    channel_id = ?self.channel.channel_id(),
+                    peer = %self.channel.info.peer_addr(),
+                    protocol_version = self.channel.info.protocol_version(),
+                    "Could not upgrade channel to realtime connection, because its protocol version is too low",
+                );
+                false
+            }
+            Err(_) => {
+                debug!(
+                    channel_id = ?self.channel.channel_id(),
+                    peer = %self.channel.info.peer_addr(),
+                    %node_id,
+                    "Could not upgrade channel to realtime connection, because another channel for the same node ID was found",
+                );
+                false
+            }
         }
     }
 
@@ -332,6 +487,7 @@ impl ResponseServerExt for Arc<ResponseServer> {
             self.network_params.network.work.clone(),
             self.network_filter.clone(),
             ChannelReader::new(self.channel.clone()),
+            self.stats.clone(),
         );
 
         let mut first_message = true;
@@ -543,7 +699,15 @@ impl ResponseServerExt for Arc<ResponseServer> {
         };
 
         if process {
-            self.queue_realtime(message);
+            if self.try_consume_message_rate(message.message_type()) {
+                self.queue_realtime(message);
+            } else {
+                self.stats.inc_dir(
+                    StatType::TcpServer,
+                    DetailType::MessageRateLimited,
+                    Direction::In,
+                );
+            }
         }
 
         ProcessResult::Progress
@@ -1,6 +1,6 @@
 use super::{
     InboundMessageQueue, LatestKeepalives, MessagePublisher, NetworkFilter, ResponseServer,
-    ResponseServerExt, SynCookies,
+    ResponseServerExt, SynCookies, TcpConfig,
 };
 use crate::{
     block_processing::BlockProcessor,
@@ -16,7 +16,8 @@ use rsban_network::{
     Channel, ChannelDirection, Network, NetworkInfo, NullNetworkObserver, ResponseServerSpawner,
 };
 use rsban_nullable_clock::SteadyClock;
-use std::sync::{Arc, Mutex, RwLock};
+use std::sync::{atomic::AtomicUsize, Arc, Mutex, RwLock};
+use tokio::time::sleep;
 
 pub struct NanoResponseServerSpawner {
     pub(crate) tokio: tokio::runtime::Handle,
@@ -30,9 +31,12 @@ pub struct NanoResponseServerSpawner {
     pub(crate) network_filter: Arc<NetworkFilter>,
     pub(crate) inbound_queue: Arc<InboundMessageQueue>,
     pub(crate) node_flags: NodeFlags,
+    pub(crate) tcp_config: TcpConfig,
     pub(crate) network_params: NetworkParams,
     pub(crate) syn_cookies: Arc<SynCookies>,
     pub(crate) latest_keepalives: Arc<Mutex<LatestKeepalives>>,
+    pub(crate) clock: Arc<SteadyClock>,
+    pub(crate) bootstrap_connections_max: Arc<AtomicUsize>,
 }
 
 impl NanoResponseServerSpawner {
@@ -49,6 +53,7 @@ impl NanoResponseServerSpawner {
         let block_processor = Arc::new(BlockProcessor::new_test_instance(ledger.clone()));
         let clock = Arc::new(SteadyClock::new_null());
         Self {
+            clock: clock.clone(),
             tokio: tokio.clone(),
             stats: stats.clone(),
             node_id: PrivateKey::from(42),
@@ -73,10 +78,14 @@ impl NanoResponseServerSpawner {
             network: network_info,
             inbound_queue: Arc::new(InboundMessageQueue::default()),
             node_flags: flags,
+            tcp_config: TcpConfig::for_dev_network(),
             network_params,
             syn_cookies: Arc::new(SynCookies::new(1)),
             latest_keepalives: Arc::new(Mutex::new(LatestKeepalives::default())),
             network_filter,
+            bootstrap_connections_max: Arc::new(AtomicUsize::new(
+                ResponseServer::DEFAULT_CONNECTIONS_MAX,
+            )),
         }
     }
 
@@ -95,7 +104,10 @@ impl NanoResponseServerSpawner {
             self.network_filter.clone(),
             Arc::new(self.network_params.clone()),
             Arc::clone(&self.stats),
-            true,
+            !self.node_flags.disable_bootstrap_listener,
+            !self.node_flags.disable_tcp_realtime,
+            self.tcp_config.max_message_rate,
+            self.tcp_config.handshake_timeout,
             self.syn_cookies.clone(),
             self.node_id.clone(),
             self.tokio.clone(),
@@ -105,11 +117,23 @@ impl NanoResponseServerSpawner {
             self.bootstrap_initiator.clone(),
             self.node_flags.clone(),
             self.latest_keepalives.clone(),
+            self.bootstrap_connections_max.clone(),
         ));
 
+        server.start(self.clock.now());
         let server_l = server.clone();
         self.tokio.spawn(async move { server_l.run().await });
 
+        let server_w = Arc::downgrade(&server);
+        let clock = self.clock.clone();
+        let handshake_timeout = server.handshake_timeout();
+        self.tokio.spawn(async move {
+            sleep(handshake_timeout).await;
+            if let Some(server) = server_w.upgrade() {
+                server.timeout(clock.now());
+            }
+        });
+
         server
     }
 }
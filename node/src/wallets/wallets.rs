@@ -856,6 +856,31 @@ impl Wallets {
         Ok(wallet.store.representative(&tx))
     }
 
+    /// Returns the opened wallet accounts whose representative on the ledger
+    /// differs from the representative configured for the wallet
+    pub fn accounts_needing_representative(
+        &self,
+        wallet_id: &WalletId,
+    ) -> Result<Vec<Account>, WalletsError> {
+        let guard = self.mutex.lock().unwrap();
+        let wallet = Self::get_wallet(&guard, wallet_id)?;
+        let tx = self.env.tx_begin_read();
+        let representative = wallet.store.representative(&tx);
+        let block_tx = self.ledger.read_txn();
+        let mut accounts = Vec::new();
+        let mut it = wallet.store.begin(&tx);
+        while let Some((&account, _)) = it.current() {
+            let account = Account::from(account);
+            if let Some(info) = self.ledger.account_info(&block_tx, &account) {
+                if info.representative != representative {
+                    accounts.push(account);
+                }
+            }
+            it.next();
+        }
+        Ok(accounts)
+    }
+
     pub fn decrypt(&self, wallet_id: WalletId) -> Result<Vec<(PublicKey, RawKey)>, WalletsError> {
         let guard = self.mutex.lock().unwrap();
         let wallet = Self::get_wallet(&guard, &wallet_id)?;
@@ -933,6 +958,13 @@ pub trait WalletsExt {
         generate_work: bool,
     ) -> Result<PublicKey, WalletsError>;
 
+    /// Derives `count` sequential accounts from the wallet seed in a single transaction
+    fn derive_accounts(
+        &self,
+        wallet_id: &WalletId,
+        count: u32,
+    ) -> Result<Vec<Account>, WalletsError>;
+
     fn insert_adhoc(&self, wallet: &Arc<Wallet>, key: &RawKey, generate_work: bool) -> PublicKey;
 
     fn insert_adhoc2(
@@ -1249,6 +1281,23 @@ impl WalletsExt for Arc<Wallets> {
         Ok(self.deterministic_insert(wallet, &mut tx, generate_work))
     }
 
+    fn derive_accounts(
+        &self,
+        wallet_id: &WalletId,
+        count: u32,
+    ) -> Result<Vec<Account>, WalletsError> {
+        let guard = self.mutex.lock().unwrap();
+        let wallet = Wallets::get_wallet(&guard, wallet_id)?;
+        let mut tx = self.env.tx_begin_write();
+        if !wallet.store.valid_password(&tx) {
+            return Err(WalletsError::WalletLocked);
+        }
+        let accounts = (0..count)
+            .map(|_| self.deterministic_insert(wallet, &mut tx, false).into())
+            .collect();
+        Ok(accounts)
+    }
+
     fn insert_adhoc(&self, wallet: &Arc<Wallet>, key: &RawKey, generate_work: bool) -> PublicKey {
         let mut tx = self.env.tx_begin_write();
         if !wallet.store.valid_password(&tx) {
@@ -6,17 +6,19 @@ use rsban_ledger::{DEV_GENESIS_ACCOUNT, DEV_GENESIS_HASH};
 use rsban_messages::BulkPull;
 use rsban_network::{
     bandwidth_limiter::BandwidthLimiter, Channel, ChannelInfo, NullNetworkObserver,
+    TrafficPolicyConfig,
 };
 use rsban_node::{
     bootstrap::{BootstrapAttemptTrait, BootstrapInitiatorExt, BootstrapStrategy, BulkPullServer},
     config::{NodeConfig, NodeFlags},
     stats::{DetailType, Direction, StatType},
-    transport::{LatestKeepalives, ResponseServer},
+    transport::{LatestKeepalives, ResponseServer, TcpConfig},
     wallets::WalletsExt,
     Node, NodeExt,
 };
 use rsban_nullable_tcp::TcpStream;
-use std::sync::{atomic::Ordering, Arc, Mutex};
+use std::collections::VecDeque;
+use std::sync::{atomic::Ordering, Arc, Mutex, RwLock};
 use std::time::Duration;
 use test_helpers::{
     assert_timely, assert_timely_eq, assert_timely_msg, get_available_port, setup_chain, System,
@@ -1272,6 +1274,31 @@ mod bootstrap_processor {
             Amount::raw(100),
         );
     }
+
+    #[test]
+    fn reject_attempt_when_limit_reached() {
+        let mut system = System::new();
+        let mut config = System::default_config();
+        config.bootstrap_max_attempts = 1;
+        let node = system.build_node().config(config).disconnected().finish();
+
+        node.bootstrap_initiator
+            .bootstrap_lazy(BlockHash::from(1).into(), false, "".to_string());
+        assert!(node.bootstrap_initiator.current_lazy_attempt().is_some());
+
+        node.bootstrap_initiator
+            .bootstrap_wallet(VecDeque::from([Account::from(1)]));
+
+        assert!(node.bootstrap_initiator.current_wallet_attempt().is_none());
+        assert_eq!(
+            node.stats.count(
+                StatType::Bootstrap,
+                DetailType::AttemptLimitReached,
+                Direction::Out
+            ),
+            1
+        );
+    }
 }
 
 mod bulk_pull {
@@ -2296,11 +2323,172 @@ fn lazy_max_pull_count() {
     });
 }
 
+mod response_server {
+    use super::*;
+    use rsban_core::{
+        utils::{TEST_ENDPOINT_1, TEST_ENDPOINT_2},
+        NodeId,
+    };
+    use rsban_messages::{Keepalive, Message};
+    use rsban_network::{ChannelDirection, ChannelMode};
+    use rsban_node::transport::{ConnectionState, ResponseServerExt};
+    use rsban_nullable_clock::Timestamp;
+
+    #[test]
+    fn realtime_disabled_does_not_upgrade_connection() {
+        let mut system = System::new();
+        let node = system.make_node();
+        let response_server =
+            create_response_server_with(&node, true, false, &TcpConfig::default());
+
+        assert!(!response_server.to_realtime_connection(&NodeId::from(1)));
+        assert_eq!(
+            response_server.channel().info.mode(),
+            ChannelMode::Undefined
+        );
+    }
+
+    #[test]
+    fn connection_state_changes_from_undefined_to_realtime() {
+        let mut system = System::new();
+        let node = system.make_node();
+        let channel_info = node
+            .network_info
+            .write()
+            .unwrap()
+            .add(
+                TEST_ENDPOINT_1,
+                TEST_ENDPOINT_2,
+                ChannelDirection::Inbound,
+                ChannelMode::Realtime,
+                node.steady_clock.now(),
+            )
+            .unwrap();
+        let response_server = create_response_server_for_channel(
+            &node,
+            channel_info,
+            true,
+            true,
+            &TcpConfig::default(),
+        );
+        assert_eq!(
+            response_server.connection_state(),
+            ConnectionState::Undefined
+        );
+
+        let node_id = NodeId::from(1);
+        assert!(response_server.to_realtime_connection(&node_id));
+
+        let info = response_server.info();
+        assert_eq!(info.connection_state, ConnectionState::Realtime);
+        assert_eq!(info.remote_endpoint, TEST_ENDPOINT_2);
+        assert_eq!(info.remote_node_id, Some(node_id));
+    }
+
+    #[test]
+    fn refuse_bootstrap_connection_when_max_lowered() {
+        let mut system = System::new();
+        let node = system.make_node();
+        let response_server = create_response_server(&node);
+
+        response_server.set_connections_max(0);
+        assert!(!response_server.to_bootstrap_connection());
+        assert_eq!(
+            response_server.connection_state(),
+            ConnectionState::Undefined
+        );
+
+        response_server.set_connections_max(ResponseServer::DEFAULT_CONNECTIONS_MAX);
+        assert!(response_server.to_bootstrap_connection());
+        assert_eq!(
+            response_server.connection_state(),
+            ConnectionState::Bootstrap
+        );
+    }
+
+    #[test]
+    fn drop_messages_exceeding_rate_limit() {
+        let mut system = System::new();
+        let node = system.make_node();
+        let tcp_config = TcpConfig {
+            max_message_rate: 1,
+            ..Default::default()
+        };
+        let response_server = create_response_server_with(&node, true, true, &tcp_config);
+
+        for _ in 0..3 {
+            response_server.process_realtime(Message::Keepalive(Keepalive::default()));
+        }
+
+        assert_eq!(
+            node.stats.count(
+                StatType::TcpServer,
+                DetailType::MessageRateLimited,
+                Direction::In
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn close_undefined_connection_after_handshake_timeout() {
+        let mut system = System::new();
+        let node = system.make_node();
+        let tcp_config = TcpConfig {
+            handshake_timeout: Duration::from_secs(5),
+            ..Default::default()
+        };
+        let response_server = create_response_server_with(&node, true, true, &tcp_config);
+        let now = Timestamp::new_test_instance();
+        response_server.start(now);
+
+        assert!(!response_server.timeout(now + Duration::from_secs(4)));
+        assert!(!response_server.is_stopped());
+
+        assert!(response_server.timeout(now + Duration::from_secs(5)));
+        assert!(response_server.is_stopped());
+        assert_eq!(
+            node.stats.count(
+                StatType::TcpServer,
+                DetailType::HandshakeTimeout,
+                Direction::In
+            ),
+            1
+        );
+    }
+}
+
 fn create_response_server(node: &Node) -> Arc<ResponseServer> {
-    let channel = Channel::create(
+    create_response_server_with(node, true, true, &TcpConfig::default())
+}
+
+fn create_response_server_with(
+    node: &Node,
+    allow_bootstrap: bool,
+    allow_realtime: bool,
+    tcp_config: &TcpConfig,
+) -> Arc<ResponseServer> {
+    create_response_server_for_channel(
+        node,
         Arc::new(ChannelInfo::new_test_instance()),
+        allow_bootstrap,
+        allow_realtime,
+        tcp_config,
+    )
+}
+
+fn create_response_server_for_channel(
+    node: &Node,
+    channel_info: Arc<ChannelInfo>,
+    allow_bootstrap: bool,
+    allow_realtime: bool,
+    tcp_config: &TcpConfig,
+) -> Arc<ResponseServer> {
+    let channel = Channel::create(
+        channel_info,
         TcpStream::new_null(),
         Arc::new(BandwidthLimiter::default()),
+        Arc::new(RwLock::new(TrafficPolicyConfig::default())),
         node.steady_clock.clone(),
         Arc::new(NullNetworkObserver::new()),
         &node.runtime,
@@ -2313,7 +2501,10 @@ fn create_response_server(node: &Node) -> Arc<ResponseServer> {
         node.network_filter.clone(),
         Arc::new(node.network_params.clone()),
         node.stats.clone(),
-        true,
+        allow_bootstrap,
+        allow_realtime,
+        tcp_config.max_message_rate,
+        tcp_config.handshake_timeout,
         node.syn_cookies.clone(),
         node.node_id.clone(),
         node.runtime.clone(),
@@ -2323,5 +2514,6 @@ fn create_response_server(node: &Node) -> Arc<ResponseServer> {
         node.bootstrap_initiator.clone(),
         node.flags.clone(),
         Arc::new(Mutex::new(LatestKeepalives::default())),
+        node.bootstrap_connections_max.clone(),
     ))
 }
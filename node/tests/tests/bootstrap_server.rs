@@ -142,6 +142,44 @@ fn serve_hash_one() {
     assert_eq!(response_payload.blocks()[0].hash(), blocks[0].hash());
 }
 
+#[test]
+fn serve_requested_count() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let responses = ResponseHelper::new();
+    responses.connect(&node);
+
+    let mut chains = setup_chains(&node, 1, 128, &DEV_GENESIS_KEY, true);
+    let (account, blocks) = chains.pop().unwrap();
+
+    // Request fewer blocks than available in the chain
+    let request = Message::AscPullReq(AscPullReq {
+        id: 7,
+        req_type: AscPullReqType::Blocks(BlocksReqPayload {
+            start_type: HashType::Account,
+            start: account.into(),
+            count: 10,
+        }),
+    });
+
+    let channel = make_fake_channel(&node);
+    node.inbound_message_queue
+        .put(request, channel.info.clone());
+
+    assert_timely_eq(Duration::from_secs(5), || responses.len(), 1);
+
+    let response = responses.get().pop().unwrap();
+    assert_eq!(response.id, 7);
+    let AscPullAckType::Blocks(response_payload) = response.pull_type else {
+        panic!("wrong ack type")
+    };
+
+    // Server must not send more blocks than requested
+    assert_eq!(response_payload.blocks().len(), 10);
+    assert!(compare_blocks(response_payload.blocks(), &blocks[..10]));
+}
+
 #[test]
 fn serve_end_of_chain() {
     let mut system = System::new();
@@ -1,10 +1,11 @@
 use rsban_core::{
     Amount, PrivateKey, UnsavedBlockLatticeBuilder, Vote, VoteSource, DEV_GENESIS_KEY,
 };
+use rsban_ledger::BlockStatus;
 use rsban_node::{
     config::NodeConfig,
     consensus::ElectionBehavior,
-    stats::{DetailType, Direction, StatType},
+    stats::{DetailType, Direction, Sample, StatType},
     wallets::WalletsExt,
 };
 use std::{sync::Arc, time::Duration};
@@ -313,3 +314,32 @@ fn election_behavior() {
     let election = start_election(&node, &chain[0].hash());
     assert_eq!(election.behavior, ElectionBehavior::Manual);
 }
+
+#[test]
+fn sample_confirmation_latency() {
+    let mut system = System::new();
+    let node = system.build_node().finish();
+    let key = PrivateKey::new();
+    let mut lattice = UnsavedBlockLatticeBuilder::new();
+    let send1 = lattice.genesis().send(&key, 1);
+    let send2 = lattice.genesis().send(&key, 1);
+    node.process(send1).unwrap();
+    // send2 doesn't get activated on arrival, because send1 isn't confirmed
+    assert_eq!(
+        node.process_local(send2.clone()),
+        Some(BlockStatus::Progress)
+    );
+
+    // The delay is between the block arriving and the election starting
+    std::thread::sleep(Duration::from_millis(200));
+    let election = start_election(&node, &send2.hash());
+    node.active.force_confirm(&election);
+
+    let stats = node
+        .stats
+        .sample_stats(Sample::ConfirmationLatency)
+        .unwrap();
+    assert_eq!(stats.count, 1);
+    assert!(stats.max >= 200, "latency was {}", stats.max);
+    assert!(stats.max < 2000, "latency was {}", stats.max);
+}
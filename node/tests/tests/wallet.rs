@@ -39,6 +39,29 @@ fn no_special_keys_accounts() {
     }
 }
 
+#[test]
+fn deterministic_index_round_trip() {
+    let mut test_file = unique_path().unwrap();
+    test_file.push("wallet.ldb");
+    let env = LmdbEnv::new(test_file).unwrap();
+    let mut tx = env.tx_begin_write();
+    let kdf = KeyDerivationFunction::new(DEV_NETWORK_PARAMS.kdf_work);
+    let wallet =
+        LmdbWalletStore::new(0, kdf, &mut tx, &DEV_GENESIS_PUB_KEY, &PathBuf::from("0")).unwrap();
+    assert_eq!(wallet.deterministic_index_get(&tx), 0);
+
+    wallet.deterministic_insert(&mut tx);
+    wallet.deterministic_insert(&mut tx);
+    let index = wallet.deterministic_index_get(&tx);
+    assert_eq!(index, 2);
+
+    wallet.deterministic_index_set(&mut tx, 0);
+    assert_eq!(wallet.deterministic_index_get(&tx), 0);
+
+    wallet.deterministic_index_set(&mut tx, index);
+    assert_eq!(wallet.deterministic_index_get(&tx), index);
+}
+
 #[test]
 fn no_key() {
     let mut test_file = unique_path().unwrap();
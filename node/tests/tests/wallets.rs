@@ -1,4 +1,7 @@
-use rsban_core::{Amount, PrivateKey, UnsavedBlockLatticeBuilder, WalletId, DEV_GENESIS_KEY};
+use rsban_core::{
+    deterministic_key, Account, Amount, PrivateKey, PublicKey, RawKey, UnsavedBlockLatticeBuilder,
+    WalletId, DEV_GENESIS_KEY,
+};
 use rsban_ledger::{DEV_GENESIS_ACCOUNT, DEV_GENESIS_PUB_KEY};
 use rsban_node::{
     config::{NodeConfig, NodeFlags},
@@ -19,6 +22,65 @@ fn open_create() {
     assert_eq!(node.wallets.wallet_exists(&id), true);
 }
 
+#[test]
+fn derive_accounts() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = node.wallets.wallet_ids()[0];
+    let seed = RawKey::from(1);
+    node.wallets.change_seed(wallet_id, &seed, 0).unwrap();
+    let start_index = node.wallets.deterministic_index_get(&wallet_id).unwrap();
+
+    let accounts = node.wallets.derive_accounts(&wallet_id, 5).unwrap();
+
+    let expected: Vec<Account> = (start_index..start_index + 5)
+        .map(|i| {
+            PublicKey::try_from(&deterministic_key(&seed, i))
+                .unwrap()
+                .into()
+        })
+        .collect();
+    assert_eq!(accounts, expected);
+    assert_eq!(
+        node.wallets.deterministic_index_get(&wallet_id).unwrap(),
+        start_index + 5
+    );
+}
+
+#[test]
+fn accounts_needing_representative() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = node.wallets.wallet_ids()[0];
+    node.wallets
+        .insert_adhoc2(&wallet_id, &DEV_GENESIS_KEY.raw_key(), false)
+        .unwrap();
+    let unopened = PrivateKey::new();
+    node.wallets
+        .insert_adhoc2(&wallet_id, &unopened.raw_key(), false)
+        .unwrap();
+
+    node.wallets
+        .set_representative(wallet_id, *DEV_GENESIS_PUB_KEY, false)
+        .unwrap();
+    assert!(node
+        .wallets
+        .accounts_needing_representative(&wallet_id)
+        .unwrap()
+        .is_empty());
+
+    let new_rep = PrivateKey::new();
+    node.wallets
+        .set_representative(wallet_id, new_rep.public_key(), false)
+        .unwrap();
+    assert_eq!(
+        node.wallets
+            .accounts_needing_representative(&wallet_id)
+            .unwrap(),
+        vec![*DEV_GENESIS_ACCOUNT]
+    );
+}
+
 #[test]
 fn vote_minimum() {
     let mut system = System::new();
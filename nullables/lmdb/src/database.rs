@@ -30,3 +30,27 @@ enum DatabaseType {
     Real(lmdb::Database),
     Stub(u32),
 }
+
+/// Statistics of a single database, see `mdb_stat`
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct DatabaseStat {
+    pub page_size: u32,
+    pub depth: u32,
+    pub branch_pages: usize,
+    pub leaf_pages: usize,
+    pub overflow_pages: usize,
+    pub entries: usize,
+}
+
+impl From<lmdb::Stat> for DatabaseStat {
+    fn from(value: lmdb::Stat) -> Self {
+        Self {
+            page_size: value.page_size(),
+            depth: value.depth(),
+            branch_pages: value.branch_pages(),
+            leaf_pages: value.leaf_pages(),
+            overflow_pages: value.overflow_pages(),
+            entries: value.entries(),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ConfiguredDatabase, LmdbDatabase, RoCursor};
+use super::{ConfiguredDatabase, DatabaseStat, LmdbDatabase, RoCursor};
 use crate::EMPTY_DATABASE;
 
 pub struct RoTransaction {
@@ -56,6 +56,16 @@ impl RoTransaction {
             RoTransactionStrategy::Nulled(s) => s.count(database),
         }
     }
+
+    pub fn stat(&self, database: LmdbDatabase) -> lmdb::Result<DatabaseStat> {
+        match &self.strategy {
+            RoTransactionStrategy::Real(s) => s.stat(database),
+            RoTransactionStrategy::Nulled(s) => Ok(DatabaseStat {
+                entries: s.count(database) as usize,
+                ..Default::default()
+            }),
+        }
+    }
 }
 
 enum RoTransactionStrategy {
@@ -93,6 +103,10 @@ impl RoTransactionWrapper {
         let stat = lmdb::Transaction::stat(&self.0, database.as_real());
         stat.unwrap().entries() as u64
     }
+
+    fn stat(&self, database: LmdbDatabase) -> lmdb::Result<DatabaseStat> {
+        lmdb::Transaction::stat(&self.0, database.as_real()).map(DatabaseStat::from)
+    }
 }
 
 struct RoTransactionStub {
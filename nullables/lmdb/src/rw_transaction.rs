@@ -1,4 +1,4 @@
-use super::{ConfiguredDatabase, LmdbDatabase, RoCursor};
+use super::{ConfiguredDatabase, DatabaseStat, LmdbDatabase, RoCursor};
 use lmdb::DatabaseFlags;
 
 pub struct RwTransaction {
@@ -96,7 +96,17 @@ impl RwTransaction {
     pub fn count(&self, database: LmdbDatabase) -> u64 {
         match &self.strategy {
             RwTransactionStrategy::Real(s) => s.count(database.as_real()),
-            RwTransactionStrategy::Nulled(_) => 0,
+            RwTransactionStrategy::Nulled(s) => s.count(database),
+        }
+    }
+
+    pub fn stat(&self, database: LmdbDatabase) -> lmdb::Result<DatabaseStat> {
+        match &self.strategy {
+            RwTransactionStrategy::Real(s) => s.stat(database.as_real()),
+            RwTransactionStrategy::Nulled(s) => Ok(DatabaseStat {
+                entries: s.count(database) as usize,
+                ..Default::default()
+            }),
         }
     }
 
@@ -157,6 +167,10 @@ impl RwTransactionWrapper {
         stat.unwrap().entries() as u64
     }
 
+    fn stat(&self, database: lmdb::Database) -> lmdb::Result<DatabaseStat> {
+        lmdb::Transaction::stat(&self.0, database).map(DatabaseStat::from)
+    }
+
     /// ## Safety
     ///
     /// This method is unsafe in the same ways as `Environment::close_db`, and
@@ -209,4 +223,10 @@ impl RwTransactionStub {
     fn create_db(&self, _name: Option<&str>, _flags: DatabaseFlags) -> lmdb::Result<LmdbDatabase> {
         Ok(LmdbDatabase::new_null(42))
     }
+
+    fn count(&self, database: LmdbDatabase) -> u64 {
+        self.get_database(database)
+            .map(|db| db.entries.len())
+            .unwrap_or_default() as u64
+    }
 }
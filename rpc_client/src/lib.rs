@@ -287,6 +287,14 @@ impl NanoRpcClient {
         self.request(&cmd).await
     }
 
+    pub async fn account_representative_set(
+        &self,
+        args: AccountRepresentativeSetArgs,
+    ) -> Result<AccountRepresentativeSetResponse> {
+        let cmd = RpcCommand::account_representative_set(args);
+        self.request(&cmd).await
+    }
+
     pub async fn account_move(
         &self,
         wallet: WalletId,
@@ -347,15 +355,15 @@ impl NanoRpcClient {
         self.request(&cmd).await
     }
 
-    pub async fn account_weight(&self, account: Account) -> Result<WeightDto> {
-        self.request(&RpcCommand::account_weight(account)).await
+    pub async fn account_weight(&self, args: impl Into<AccountWeightArgs>) -> Result<WeightDto> {
+        self.request(&RpcCommand::AccountWeight(args.into())).await
     }
 
     pub async fn available_supply(&self) -> Result<AvailableSupplyReponse> {
         self.request(&RpcCommand::AvailableSupply).await
     }
 
-    pub async fn block_account(&self, hash: BlockHash) -> Result<AccountResponse> {
+    pub async fn block_account(&self, hash: BlockHash) -> Result<BlockAccountResponse> {
         self.request(&RpcCommand::block_account(hash)).await
     }
 
@@ -371,6 +379,10 @@ impl NanoRpcClient {
         self.request(&RpcCommand::uptime()).await
     }
 
+    pub async fn health(&self) -> Result<HealthResponse> {
+        self.request(&RpcCommand::health()).await
+    }
+
     pub async fn frontier_count(&self) -> Result<CountResponse> {
         self.request(&RpcCommand::FrontierCount).await
     }
@@ -548,8 +560,12 @@ impl NanoRpcClient {
         self.request(&RpcCommand::Delegators(args.into())).await
     }
 
-    pub async fn delegators_count(&self, account: Account) -> Result<CountResponse> {
-        self.request(&RpcCommand::delegators_count(account)).await
+    pub async fn delegators_count(
+        &self,
+        args: impl Into<DelegatorsCountArgs>,
+    ) -> Result<CountResponse> {
+        self.request(&RpcCommand::DelegatorsCount(args.into()))
+            .await
     }
 
     pub async fn block_hash(&self, block: JsonBlock) -> Result<HashRpcMessage> {
@@ -23,6 +23,9 @@ pub struct AccountHistoryArgs {
     pub reverse: Option<RpcBool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub account_filter: Option<Vec<Account>>,
+    /// Only return blocks with one of these subtypes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtypes: Option<Vec<BlockSubTypeDto>>,
 }
 
 impl AccountHistoryArgs {
@@ -35,6 +38,7 @@ impl AccountHistoryArgs {
             offset: None,
             reverse: None,
             account_filter: None,
+            subtypes: None,
         }
     }
 
@@ -62,6 +66,7 @@ impl AccountHistoryArgsBuilder {
                 offset: None,
                 reverse: None,
                 account_filter: None,
+                subtypes: None,
             },
         }
     }
@@ -91,6 +96,11 @@ impl AccountHistoryArgsBuilder {
         self
     }
 
+    pub fn subtypes(mut self, subtypes: Vec<BlockSubTypeDto>) -> Self {
+        self.args.subtypes = Some(subtypes);
+        self
+    }
+
     pub fn finish(self) -> AccountHistoryArgs {
         self.args
     }
@@ -155,6 +165,7 @@ mod tests {
             .offset(5)
             .reverse()
             .account_filter(vec![Account::from(123)])
+            .subtypes(vec![BlockSubTypeDto::Send, BlockSubTypeDto::Receive])
             .finish();
 
         assert_eq!(
@@ -168,6 +179,10 @@ mod tests {
   "reverse": "true",
   "account_filter": [
     "ban_111111111111111111111111111111111111111111111111115uwdgas549"
+  ],
+  "subtypes": [
+    "send",
+    "receive"
   ]
 }"#
         )
@@ -183,7 +198,8 @@ mod tests {
             "head": "0000000000000000000000000000000000000000000000000000000000000000",
             "offset": "10",
             "reverse": "false",
-            "account_filter": ["ban_1111111111111111111111111111111111111111111111111111hifc8npp"]
+            "account_filter": ["ban_1111111111111111111111111111111111111111111111111111hifc8npp"],
+            "subtypes": ["receive"]
         }"#;
 
         let deserialized: RpcCommand = serde_json::from_str(json).unwrap();
@@ -197,6 +213,7 @@ mod tests {
             assert_eq!(args.offset, Some(10.into()));
             assert_eq!(args.reverse, Some(false.into()));
             assert_eq!(args.account_filter, Some(vec![Account::zero()]));
+            assert_eq!(args.subtypes, Some(vec![BlockSubTypeDto::Receive]));
         } else {
             panic!("Deserialized to wrong RpcCommand variant");
         }
@@ -1,4 +1,4 @@
-use crate::RpcCommand;
+use crate::{RpcBool, RpcCommand};
 use rsban_core::Account;
 use serde::{Deserialize, Serialize};
 
@@ -11,16 +11,34 @@ impl RpcCommand {
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct AccountWeightArgs {
     pub account: Account,
+    /// Split the weight into the account's own balance and delegated weight
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<RpcBool>,
 }
 
 impl AccountWeightArgs {
     pub fn new(account: Account) -> Self {
-        Self { account }
+        Self {
+            account,
+            breakdown: None,
+        }
+    }
+
+    pub fn with_breakdown(mut self) -> Self {
+        self.breakdown = Some(true.into());
+        self
+    }
+}
+
+impl From<Account> for AccountWeightArgs {
+    fn from(account: Account) -> Self {
+        Self::new(account)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::AccountWeightArgs;
     use crate::RpcCommand;
     use rsban_core::Account;
     use serde_json::{from_str, to_string_pretty};
@@ -36,6 +54,22 @@ mod tests {
         )
     }
 
+    #[test]
+    fn serialize_account_weight_with_breakdown() {
+        let cmd =
+            RpcCommand::AccountWeight(AccountWeightArgs::new(Account::from(123)).with_breakdown());
+        assert_eq!(
+            serde_json::to_string_pretty(&cmd).unwrap(),
+            r#"{
+  "action": "account_weight",
+  "account": "ban_111111111111111111111111111111111111111111111111115uwdgas549",
+  "breakdown": "true"
+}"#
+        );
+        let deserialized: RpcCommand = from_str(&to_string_pretty(&cmd).unwrap()).unwrap();
+        assert_eq!(cmd, deserialized)
+    }
+
     #[test]
     fn derialize_account_weight_command() {
         let account = Account::from(123);
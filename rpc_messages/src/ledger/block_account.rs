@@ -1,5 +1,6 @@
-use crate::{common::HashRpcMessage, RpcCommand};
-use rsban_core::BlockHash;
+use crate::{common::HashRpcMessage, RpcBool, RpcCommand};
+use rsban_core::{Account, BlockHash};
+use serde::{Deserialize, Serialize};
 
 impl RpcCommand {
     pub fn block_account(hash: BlockHash) -> Self {
@@ -7,10 +8,26 @@ impl RpcCommand {
     }
 }
 
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct BlockAccountResponse {
+    pub account: Account,
+    pub confirmed: RpcBool,
+}
+
+impl BlockAccountResponse {
+    pub fn new(account: Account, confirmed: bool) -> Self {
+        Self {
+            account,
+            confirmed: confirmed.into(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::BlockAccountResponse;
     use crate::RpcCommand;
-    use rsban_core::BlockHash;
+    use rsban_core::{Account, BlockHash};
     use serde_json::{from_str, to_string_pretty};
 
     #[test]
@@ -31,4 +48,15 @@ mod tests {
         let deserialized: RpcCommand = from_str(&serialized).unwrap();
         assert_eq!(cmd, deserialized)
     }
+
+    #[test]
+    fn serialize_block_account_response() {
+        assert_eq!(
+            to_string_pretty(&BlockAccountResponse::new(Account::from(123), true)).unwrap(),
+            r#"{
+  "account": "ban_111111111111111111111111111111111111111111111111115uwdgas549",
+  "confirmed": "true"
+}"#
+        )
+    }
 }
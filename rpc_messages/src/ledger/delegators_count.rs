@@ -1,9 +1,38 @@
-use crate::{common::AccountArg, RpcCommand};
-use rsban_core::Account;
+use crate::RpcCommand;
+use rsban_core::{Account, Amount};
+use serde::{Deserialize, Serialize};
 
 impl RpcCommand {
     pub fn delegators_count(account: Account) -> Self {
-        Self::DelegatorsCount(AccountArg::new(account))
+        Self::DelegatorsCount(DelegatorsCountArgs::new(account))
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct DelegatorsCountArgs {
+    pub account: Account,
+    /// Only count delegators with at least this balance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<Amount>,
+}
+
+impl DelegatorsCountArgs {
+    pub fn new(account: Account) -> Self {
+        Self {
+            account,
+            threshold: None,
+        }
+    }
+
+    pub fn with_minimum_balance(mut self, threshold: Amount) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+}
+
+impl From<Account> for DelegatorsCountArgs {
+    fn from(account: Account) -> Self {
+        Self::new(account)
     }
 }
 
@@ -27,4 +56,22 @@ mod tests {
         let expected = RpcCommand::delegators_count(Account::zero());
         assert_eq!(deserialized, expected);
     }
+
+    #[test]
+    fn serialize_delegators_count_with_threshold() {
+        let args = DelegatorsCountArgs::new(Account::zero()).with_minimum_balance(Amount::raw(5));
+        let serialized = serde_json::to_value(RpcCommand::DelegatorsCount(args)).unwrap();
+        let expected = json!({"action": "delegators_count", "account": "ban_1111111111111111111111111111111111111111111111111111hifc8npp", "threshold": "5"});
+        assert_eq!(serialized, expected);
+    }
+
+    #[test]
+    fn deserialize_delegators_count_with_threshold() {
+        let json = r#"{"action": "delegators_count","account": "ban_1111111111111111111111111111111111111111111111111111hifc8npp","threshold":"5"}"#;
+        let deserialized: RpcCommand = serde_json::from_str(json).unwrap();
+        let expected = RpcCommand::DelegatorsCount(
+            DelegatorsCountArgs::new(Account::zero()).with_minimum_balance(Amount::raw(5)),
+        );
+        assert_eq!(deserialized, expected);
+    }
 }
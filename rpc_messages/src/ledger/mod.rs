@@ -36,12 +36,14 @@ pub use accounts_balances::*;
 pub use accounts_receivable::*;
 pub use accounts_representatives::*;
 pub use available_supply::*;
+pub use block_account::*;
 pub use block_count::*;
 pub use block_info::*;
 pub use blocks::*;
 pub use blocks_info::*;
 pub use chain::*;
 pub use delegators::*;
+pub use delegators_count::*;
 pub use frontiers::*;
 pub use ledger::*;
 pub use representatives::*;
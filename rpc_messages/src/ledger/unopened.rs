@@ -5,8 +5,11 @@ use std::collections::HashMap;
 
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Default)]
 pub struct UnopenedArgs {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The scan starts at this account. To fetch the next page, pass the
+    /// successor of the highest account of the previous page
+    #[serde(skip_serializing_if = "Option::is_none", alias = "start")]
     pub account: Option<Account>,
+    /// Maximum number of accounts per page
     #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<RpcU64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -104,6 +107,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_unopened_args_with_start() {
+        let json = json!({
+            "start": "ban_1111111111111111111111111111111111111111111111111111hifc8npp",
+            "count": "2"
+        });
+
+        let args: UnopenedArgs = from_value(json).unwrap();
+
+        assert_eq!(
+            args,
+            UnopenedArgs {
+                account: Some(Account::zero()),
+                count: Some(2.into()),
+                ..Default::default()
+            }
+        );
+    }
+
     #[test]
     fn deserialize_unopened_command_threshold_some() {
         let json = json!({
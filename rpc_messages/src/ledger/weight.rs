@@ -4,10 +4,28 @@ use serde::{Deserialize, Serialize};
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct WeightDto {
     pub weight: Amount,
+    /// Part of the weight that comes from the representative's own balance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub own: Option<Amount>,
+    /// Part of the weight that is delegated by other accounts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delegated: Option<Amount>,
 }
 
 impl WeightDto {
     pub fn new(weight: Amount) -> Self {
-        Self { weight }
+        Self {
+            weight,
+            own: None,
+            delegated: None,
+        }
+    }
+
+    pub fn with_breakdown(weight: Amount, own: Amount) -> Self {
+        Self {
+            weight,
+            own: Some(own),
+            delegated: Some(weight - own),
+        }
     }
 }
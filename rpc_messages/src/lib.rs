@@ -31,7 +31,7 @@ pub enum RpcCommand {
     AccountRemove(WalletWithAccountArgs),
     AccountMove(AccountMoveArgs),
     AccountList(WalletRpcMessage),
-    AccountRepresentativeSet(()), // TODO
+    AccountRepresentativeSet(AccountRepresentativeSetArgs),
     ActiveDifficulty,
     WalletCreate(WalletCreateArgs),
     WalletContains(WalletWithAccountArgs),
@@ -50,6 +50,7 @@ pub enum RpcCommand {
     ConfirmationHistory(ConfirmationHistoryArgs),
     BlockCount,
     Uptime,
+    Health,
     FrontierCount,
     ValidateAccountNumber(AccountCandidateArg),
     NanoToRaw(AmountRpcMessage),
@@ -83,7 +84,7 @@ pub enum RpcCommand {
     Stats(StatsArgs),
     WalletChangeSeed(WalletChangeSeedArgs),
     Delegators(DelegatorsArgs),
-    DelegatorsCount(AccountArg),
+    DelegatorsCount(DelegatorsCountArgs),
     BlockHash(BlockHashArgs),
     AccountsBalances(AccountsBalancesArgs),
     BlockInfo(HashRpcMessage),
@@ -0,0 +1,42 @@
+use crate::{RpcBool, RpcCommand, RpcU64};
+use serde::{Deserialize, Serialize};
+
+impl RpcCommand {
+    pub fn health() -> Self {
+        Self::Health
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct HealthResponse {
+    pub healthy: RpcBool,
+    pub peer_count: RpcU64,
+    pub unchecked_count: RpcU64,
+    pub unconfirmed_count: RpcU64,
+    pub bootstrap_in_progress: RpcBool,
+    pub recent_errors: RpcU64,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RpcCommand;
+    use serde_json::{from_str, to_string_pretty};
+
+    #[test]
+    fn serialize_health_command() {
+        assert_eq!(
+            to_string_pretty(&RpcCommand::Health).unwrap(),
+            r#"{
+  "action": "health"
+}"#
+        );
+    }
+
+    #[test]
+    fn deserialize_health_command() {
+        let cmd = RpcCommand::Health;
+        let serialized = to_string_pretty(&cmd).unwrap();
+        let deserialized: RpcCommand = from_str(&serialized).unwrap();
+        assert_eq!(cmd, deserialized);
+    }
+}
@@ -7,6 +7,7 @@ mod confirmation_active;
 mod confirmation_history;
 mod confirmation_info;
 mod confirmation_quorum;
+mod health;
 mod keepalive;
 mod node_id;
 mod peers;
@@ -42,6 +43,7 @@ pub use confirmation_active::*;
 pub use confirmation_history::*;
 pub use confirmation_info::*;
 pub use confirmation_quorum::*;
+pub use health::*;
 pub use node_id::*;
 pub use peers::*;
 pub use process::*;
@@ -0,0 +1,129 @@
+use crate::{RpcBool, RpcCommand};
+use rsban_core::{Account, BlockHash, WalletId, WorkNonce};
+use serde::{Deserialize, Serialize};
+
+impl RpcCommand {
+    pub fn account_representative_set(args: AccountRepresentativeSetArgs) -> Self {
+        Self::AccountRepresentativeSet(args)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct AccountRepresentativeSetArgs {
+    pub wallet: WalletId,
+    pub account: Account,
+    pub representative: Account,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub work: Option<WorkNonce>,
+    /// Validate the change block without processing it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<RpcBool>,
+    /// Previous block for the dry run. Defaults to the account frontier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous: Option<BlockHash>,
+}
+
+impl AccountRepresentativeSetArgs {
+    pub fn new(wallet: WalletId, account: Account, representative: Account) -> Self {
+        Self {
+            wallet,
+            account,
+            representative,
+            work: None,
+            dry_run: None,
+            previous: None,
+        }
+    }
+
+    pub fn builder(
+        wallet: WalletId,
+        account: Account,
+        representative: Account,
+    ) -> AccountRepresentativeSetArgsBuilder {
+        AccountRepresentativeSetArgsBuilder {
+            args: AccountRepresentativeSetArgs::new(wallet, account, representative),
+        }
+    }
+}
+
+pub struct AccountRepresentativeSetArgsBuilder {
+    args: AccountRepresentativeSetArgs,
+}
+
+impl AccountRepresentativeSetArgsBuilder {
+    pub fn work(mut self, work: WorkNonce) -> Self {
+        self.args.work = Some(work);
+        self
+    }
+
+    pub fn dry_run(mut self) -> Self {
+        self.args.dry_run = Some(true.into());
+        self
+    }
+
+    pub fn previous(mut self, previous: BlockHash) -> Self {
+        self.args.previous = Some(previous);
+        self
+    }
+
+    pub fn build(self) -> AccountRepresentativeSetArgs {
+        self.args
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct AccountRepresentativeSetResponse {
+    pub block: BlockHash,
+    /// Only set by dry runs when the ledger would reject the block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<String>,
+}
+
+impl AccountRepresentativeSetResponse {
+    pub fn new(block: BlockHash) -> Self {
+        Self {
+            block,
+            reject_reason: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{from_str, to_string_pretty};
+
+    #[test]
+    fn serialize_account_representative_set_command() {
+        let args = AccountRepresentativeSetArgs::new(
+            WalletId::zero(),
+            Account::zero(),
+            Account::from(123),
+        );
+        assert_eq!(
+            to_string_pretty(&RpcCommand::account_representative_set(args)).unwrap(),
+            r#"{
+  "action": "account_representative_set",
+  "wallet": "0000000000000000000000000000000000000000000000000000000000000000",
+  "account": "ban_1111111111111111111111111111111111111111111111111111hifc8npp",
+  "representative": "ban_111111111111111111111111111111111111111111111111115uwdgas549"
+}"#
+        )
+    }
+
+    #[test]
+    fn deserialize_account_representative_set_command_dry_run() {
+        let args = AccountRepresentativeSetArgs::builder(
+            WalletId::zero(),
+            Account::zero(),
+            Account::from(123),
+        )
+        .dry_run()
+        .previous(BlockHash::from(42))
+        .build();
+        let cmd = RpcCommand::account_representative_set(args);
+        let serialized = to_string_pretty(&cmd).unwrap();
+        let deserialized: RpcCommand = from_str(&serialized).unwrap();
+        assert_eq!(cmd, deserialized)
+    }
+}
@@ -2,6 +2,7 @@ mod account_create;
 mod account_list;
 mod account_move;
 mod account_remove;
+mod account_representative_set;
 mod accounts_create;
 mod password_change;
 mod password_enter;
@@ -38,6 +39,7 @@ mod work_set;
 
 pub use account_create::*;
 pub use account_move::*;
+pub use account_representative_set::*;
 pub use accounts_create::*;
 pub use receive::*;
 pub use send::*;
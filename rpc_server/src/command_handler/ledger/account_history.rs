@@ -1,6 +1,6 @@
 use crate::command_handler::RpcCommandHandler;
 use anyhow::anyhow;
-use rsban_core::{Account, Block, BlockBase, BlockHash, SavedBlock};
+use rsban_core::{Account, Block, BlockBase, BlockHash, BlockSubType, BlockType, SavedBlock};
 use rsban_ledger::Ledger;
 use rsban_rpc_messages::{
     unwrap_bool_or_false, unwrap_u64_or_zero, AccountHistoryArgs, AccountHistoryResponse,
@@ -21,6 +21,7 @@ impl RpcCommandHandler {
 pub(crate) struct AccountHistoryHelper<'a> {
     pub ledger: &'a Ledger,
     pub accounts_to_filter: Vec<Account>,
+    pub subtypes: Vec<BlockSubTypeDto>,
     pub reverse: bool,
     pub offset: u64,
     pub head: Option<BlockHash>,
@@ -36,6 +37,7 @@ impl<'a> AccountHistoryHelper<'a> {
         Self {
             ledger,
             accounts_to_filter: args.account_filter.unwrap_or_default(),
+            subtypes: args.subtypes.unwrap_or_default(),
             reverse: unwrap_bool_or_false(args.reverse),
             offset: unwrap_u64_or_zero(args.offset),
             head: args.head,
@@ -94,7 +96,7 @@ impl<'a> AccountHistoryHelper<'a> {
 
             if self.offset > 0 {
                 self.offset -= 1;
-            } else {
+            } else if self.is_requested_subtype(&block) {
                 if let Some(entry) = self.entry_for(&block, &tx) {
                     history.push(entry);
                     self.count -= 1;
@@ -119,6 +121,20 @@ impl<'a> AccountHistoryHelper<'a> {
         self.ledger.any().get_block(tx, &self.current_block_hash)
     }
 
+    fn is_requested_subtype(&self, block: &SavedBlock) -> bool {
+        if self.subtypes.is_empty() {
+            return true;
+        }
+        let subtype = match block.block_type() {
+            BlockType::State => block.subtype(),
+            block_type => match BlockSubType::try_from(block_type) {
+                Ok(subtype) => subtype,
+                Err(_) => return false,
+            },
+        };
+        self.subtypes.contains(&subtype.into())
+    }
+
     fn should_ignore_account(&self, account: &Account) -> bool {
         if self.accounts_to_filter.is_empty() {
             return false;
@@ -1,10 +1,20 @@
 use crate::command_handler::RpcCommandHandler;
+use rsban_core::Amount;
 use rsban_rpc_messages::{AccountWeightArgs, WeightDto};
 
 impl RpcCommandHandler {
     pub(crate) fn account_weight(&self, args: AccountWeightArgs) -> WeightDto {
         let tx = self.node.ledger.read_txn();
         let weight = self.node.ledger.weight_exact(&tx, args.account.into());
-        WeightDto::new(weight)
+        if !args.breakdown.unwrap_or_default().inner() {
+            return WeightDto::new(weight);
+        }
+
+        // The balance only counts towards the own weight if the account represents itself
+        let own = match self.node.ledger.any().get_account(&tx, &args.account) {
+            Some(info) if info.representative == args.account.into() => info.balance,
+            _ => Amount::zero(),
+        };
+        WeightDto::with_breakdown(weight, own.min(weight))
     }
 }
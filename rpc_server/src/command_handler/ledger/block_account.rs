@@ -1,10 +1,20 @@
 use crate::command_handler::RpcCommandHandler;
-use rsban_rpc_messages::{AccountResponse, HashRpcMessage};
+use rsban_rpc_messages::{BlockAccountResponse, HashRpcMessage};
 
 impl RpcCommandHandler {
-    pub(crate) fn block_account(&self, args: HashRpcMessage) -> anyhow::Result<AccountResponse> {
+    pub(crate) fn block_account(
+        &self,
+        args: HashRpcMessage,
+    ) -> anyhow::Result<BlockAccountResponse> {
         let tx = self.node.ledger.read_txn();
         let block = self.load_block_any(&tx, &args.hash)?;
-        Ok(AccountResponse::new(block.account()))
+        let account = block.account();
+        let confirmed = self
+            .node
+            .ledger
+            .get_confirmation_height(&tx, &account)
+            .map(|info| block.height() <= info.height)
+            .unwrap_or(false);
+        Ok(BlockAccountResponse::new(account, confirmed))
     }
 }
@@ -6,6 +6,7 @@ impl RpcCommandHandler {
     pub(crate) fn block_confirm(&self, args: HashRpcMessage) -> anyhow::Result<StartedResponse> {
         let tx = self.node.ledger.read_txn();
         let block = self.load_block_any(&tx, &args.hash)?;
+        let mut started = false;
         if !self
             .node
             .ledger
@@ -15,6 +16,7 @@ impl RpcCommandHandler {
             // Start new confirmation for unconfirmed (or not being confirmed) block
             if !self.node.confirming_set.contains(&args.hash) {
                 self.node.election_schedulers.manual.push(block, None);
+                started = true;
             }
         } else {
             // Add record in confirmation history for confirmed block
@@ -25,6 +27,6 @@ impl RpcCommandHandler {
             status.election_status_type = ElectionStatusType::ActiveConfirmationHeight;
             self.node.active.insert_recently_cemented(status);
         }
-        Ok(StartedResponse::new(true))
+        Ok(StartedResponse::new(started))
     }
 }
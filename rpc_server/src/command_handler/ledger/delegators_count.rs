@@ -1,17 +1,18 @@
 use crate::command_handler::RpcCommandHandler;
-use rsban_core::PublicKey;
-use rsban_rpc_messages::{AccountArg, CountResponse};
+use rsban_core::{Amount, PublicKey};
+use rsban_rpc_messages::{CountResponse, DelegatorsCountArgs};
 
 impl RpcCommandHandler {
-    pub(crate) fn delegators_count(&self, args: AccountArg) -> CountResponse {
+    pub(crate) fn delegators_count(&self, args: DelegatorsCountArgs) -> CountResponse {
         let representative: PublicKey = args.account.into();
+        let threshold = args.threshold.unwrap_or(Amount::zero());
         let mut count = 0;
 
         let tx = self.node.ledger.read_txn();
         let mut iter = self.node.store.account.begin(&tx);
 
         while let Some((_, info)) = iter.current() {
-            if info.representative == representative {
+            if info.representative == representative && info.balance >= threshold {
                 count += 1;
             }
 
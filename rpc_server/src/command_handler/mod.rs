@@ -53,6 +53,9 @@ impl RpcCommandHandler {
             RpcCommand::AccountMove(args) => to_value(self.account_move(args)?),
             RpcCommand::AccountsReceivable(args) => to_value(self.accounts_receivable(args)),
             RpcCommand::AccountRemove(args) => to_value(self.account_remove(args)?),
+            RpcCommand::AccountRepresentativeSet(args) => {
+                to_value(self.account_representative_set(args)?)
+            }
             RpcCommand::AccountRepresentative(args) => to_value(self.account_representative(args)?),
             RpcCommand::AccountWeight(args) => to_value(self.account_weight(args)),
             RpcCommand::AccountsRepresentatives(args) => {
@@ -124,6 +127,7 @@ impl RpcCommandHandler {
             RpcCommand::WorkSet(args) => to_value(self.work_set(args)?),
             RpcCommand::WorkValidate(args) => to_value(self.work_validate(args)),
             RpcCommand::Uptime => to_value(self.uptime()),
+            RpcCommand::Health => to_value(self.health()),
             RpcCommand::NanoToRaw(args) => to_value(nano_to_raw(args)?),
             RpcCommand::RawToNano(args) => to_value(raw_to_nano(args)),
             RpcCommand::Ledger(args) => to_value(self.ledger(args)),
@@ -149,7 +153,6 @@ impl RpcCommandHandler {
             RpcCommand::ActiveDifficulty => to_value(self.active_difficulty()),
 
             // Not implemented:
-            RpcCommand::AccountRepresentativeSet(_) => self.not_implemented(),
             RpcCommand::WorkPeers => to_value(self.work_peers()),
             RpcCommand::WorkPeerAdd(args) => to_value(self.work_peer_add(args)),
             RpcCommand::WorkPeersClear => to_value(self.work_peers_clear()),
@@ -0,0 +1,16 @@
+use crate::command_handler::RpcCommandHandler;
+use rsban_rpc_messages::HealthResponse;
+
+impl RpcCommandHandler {
+    pub(crate) fn health(&self) -> HealthResponse {
+        let health = self.node.health();
+        HealthResponse {
+            healthy: health.is_healthy().into(),
+            peer_count: (health.peer_count as u64).into(),
+            unchecked_count: health.unchecked_count.into(),
+            unconfirmed_count: health.unconfirmed_count.into(),
+            bootstrap_in_progress: health.bootstrap_in_progress.into(),
+            recent_errors: health.recent_errors.into(),
+        }
+    }
+}
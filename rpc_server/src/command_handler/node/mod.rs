@@ -7,6 +7,7 @@ mod confirmation_active;
 mod confirmation_history;
 mod confirmation_info;
 mod confirmation_quorum;
+mod health;
 mod keepalive;
 mod node_id;
 mod peers;
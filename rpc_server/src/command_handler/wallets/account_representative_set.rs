@@ -0,0 +1,84 @@
+use crate::command_handler::RpcCommandHandler;
+use anyhow::{anyhow, bail};
+use rsban_core::{Block, BlockDetails, Link, PrivateKey, StateBlockArgs};
+use rsban_node::wallets::WalletsExt;
+use rsban_rpc_messages::{
+    unwrap_bool_or_false, AccountRepresentativeSetArgs, AccountRepresentativeSetResponse,
+};
+use std::sync::{mpsc, Mutex};
+
+impl RpcCommandHandler {
+    pub(crate) fn account_representative_set(
+        &self,
+        args: AccountRepresentativeSetArgs,
+    ) -> anyhow::Result<AccountRepresentativeSetResponse> {
+        let tx = self.node.ledger.read_txn();
+        let info = self.load_account(&tx, &args.account)?;
+        let work: u64 = args.work.unwrap_or_default().into();
+
+        if unwrap_bool_or_false(args.dry_run) {
+            let prv = self
+                .node
+                .wallets
+                .fetch(&args.wallet, &args.account.into())?;
+            let block: Block = StateBlockArgs {
+                key: &PrivateKey::from(prv),
+                previous: args.previous.unwrap_or(info.head),
+                representative: args.representative.into(),
+                balance: info.balance,
+                link: Link::zero(),
+                work,
+            }
+            .into();
+
+            let reject_reason = self
+                .node
+                .ledger
+                .quick_reject_reason(&tx, &block)
+                .map(|status| status.as_str().to_owned());
+
+            return Ok(AccountRepresentativeSetResponse {
+                block: block.hash(),
+                reject_reason,
+            });
+        }
+        drop(tx);
+
+        if work > 0 {
+            let details = BlockDetails::new(info.epoch, false, false, false);
+            if self
+                .node
+                .network_params
+                .work
+                .difficulty(&info.head.into(), work)
+                < self.node.network_params.work.threshold(&details)
+            {
+                bail!("Invalid work")
+            }
+        } else if !self.node.distributed_work.work_generation_enabled() {
+            bail!("Work generation is disabled");
+        }
+
+        // Disable work generation if "work" option is provided
+        let generate_work = work == 0;
+
+        let (result_tx, result_rx) = mpsc::sync_channel(1);
+        let result_tx = Mutex::new(result_tx);
+        self.node.wallets.change_async(
+            args.wallet,
+            args.account,
+            args.representative.into(),
+            Box::new(move |block| {
+                let _ = result_tx.lock().unwrap().send(block.map(|b| b.hash()));
+            }),
+            work,
+            generate_work,
+        )?;
+
+        let hash = result_rx
+            .recv()?
+            .ok_or_else(|| anyhow!("Error generating block"))?;
+
+        Ok(AccountRepresentativeSetResponse::new(hash))
+    }
+}
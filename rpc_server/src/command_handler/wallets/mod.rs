@@ -2,6 +2,7 @@ mod account_create;
 mod account_list;
 mod account_move;
 mod account_remove;
+mod account_representative_set;
 mod accounts_create;
 mod password_change;
 mod password_enter;
@@ -24,6 +24,7 @@ impl RpcCommandHandler {
                         let helper = AccountHistoryHelper {
                             ledger: &self.node.ledger,
                             accounts_to_filter: Vec::new(),
+                            subtypes: Vec::new(),
                             reverse: false,
                             offset: 0,
                             head: None,
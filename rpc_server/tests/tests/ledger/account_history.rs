@@ -1,7 +1,7 @@
 use rsban_core::{Account, Amount, PublicKey, WalletId, DEV_GENESIS_KEY};
 use rsban_ledger::{DEV_GENESIS_ACCOUNT, DEV_GENESIS_HASH, DEV_GENESIS_PUB_KEY};
 use rsban_node::wallets::WalletsExt;
-use rsban_rpc_messages::{AccountHistoryArgs, BlockTypeDto};
+use rsban_rpc_messages::{AccountHistoryArgs, BlockSubTypeDto, BlockTypeDto};
 use test_helpers::{setup_rpc_client_and_server, System};
 
 #[test]
@@ -227,3 +227,81 @@ fn account_history() {
         Some(*DEV_GENESIS_ACCOUNT)
     );
 }
+
+#[test]
+fn account_history_filtered_by_subtype() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let wallet_id = WalletId::zero();
+    node.wallets.create(wallet_id);
+    node.wallets
+        .insert_adhoc2(&wallet_id, &DEV_GENESIS_KEY.raw_key(), false)
+        .unwrap();
+
+    let send = node
+        .wallets
+        .send_action2(
+            &wallet_id,
+            *DEV_GENESIS_ACCOUNT,
+            *DEV_GENESIS_ACCOUNT,
+            node.config.receive_minimum,
+            node.work_generate_dev(*DEV_GENESIS_HASH),
+            false,
+            None,
+        )
+        .unwrap();
+
+    let receive = node
+        .wallets
+        .receive_action2(
+            &wallet_id,
+            send.hash(),
+            *DEV_GENESIS_PUB_KEY,
+            node.config.receive_minimum,
+            *DEV_GENESIS_ACCOUNT,
+            node.work_generate_dev(send.hash()),
+            false,
+        )
+        .unwrap()
+        .unwrap();
+
+    let send2 = node
+        .wallets
+        .send_action2(
+            &wallet_id,
+            *DEV_GENESIS_ACCOUNT,
+            *DEV_GENESIS_ACCOUNT,
+            node.config.receive_minimum,
+            node.work_generate_dev(receive.hash()),
+            false,
+            None,
+        )
+        .unwrap();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let args = AccountHistoryArgs::build_for_account(*DEV_GENESIS_ACCOUNT, 100)
+        .subtypes(vec![BlockSubTypeDto::Send])
+        .finish();
+
+    let history = node
+        .runtime
+        .block_on(async { server.client.account_history(args).await.unwrap() })
+        .history;
+
+    let hashes: Vec<_> = history.iter().map(|e| e.hash).collect();
+    assert_eq!(hashes, [send2.hash(), send.hash()]);
+
+    let args = AccountHistoryArgs::build_for_account(*DEV_GENESIS_ACCOUNT, 100)
+        .subtypes(vec![BlockSubTypeDto::Receive, BlockSubTypeDto::Open])
+        .finish();
+
+    let history = node
+        .runtime
+        .block_on(async { server.client.account_history(args).await.unwrap() })
+        .history;
+
+    let hashes: Vec<_> = history.iter().map(|e| e.hash).collect();
+    assert_eq!(hashes, [receive.hash(), *DEV_GENESIS_HASH]);
+}
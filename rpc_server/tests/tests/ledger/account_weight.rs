@@ -1,5 +1,6 @@
-use rsban_core::Amount;
+use rsban_core::{Amount, PrivateKey, UnsavedBlockLatticeBuilder, DEV_GENESIS_KEY};
 use rsban_ledger::DEV_GENESIS_ACCOUNT;
+use rsban_rpc_messages::AccountWeightArgs;
 use test_helpers::{setup_rpc_client_and_server, System};
 
 #[test]
@@ -19,3 +20,36 @@ fn account_weight() {
 
     assert_eq!(result.weight, Amount::MAX);
 }
+
+#[test]
+fn account_weight_breakdown() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let mut lattice = UnsavedBlockLatticeBuilder::new();
+    let key = PrivateKey::new();
+    let send = lattice.genesis().send(&key, Amount::raw(1000));
+    node.process(send.clone()).unwrap();
+    let open = lattice
+        .account(&key)
+        .receive_and_change(&send, DEV_GENESIS_KEY.public_key());
+    node.process(open).unwrap();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let result = node.runtime.block_on(async {
+        server
+            .client
+            .account_weight(AccountWeightArgs::new(*DEV_GENESIS_ACCOUNT).with_breakdown())
+            .await
+            .unwrap()
+    });
+
+    assert_eq!(result.weight, Amount::MAX);
+    assert_eq!(result.own, Some(Amount::MAX - Amount::raw(1000)));
+    assert_eq!(result.delegated, Some(Amount::raw(1000)));
+    assert_eq!(
+        result.own.unwrap() + result.delegated.unwrap(),
+        result.weight
+    );
+}
@@ -1,4 +1,4 @@
-use rsban_core::BlockHash;
+use rsban_core::{Account, Amount, BlockHash, TestBlockBuilder, DEV_GENESIS_KEY};
 use rsban_ledger::{DEV_GENESIS_ACCOUNT, DEV_GENESIS_HASH};
 use test_helpers::{setup_rpc_client_and_server, System};
 
@@ -18,6 +18,31 @@ fn block_account() {
     });
 
     assert_eq!(result.account, DEV_GENESIS_ACCOUNT.to_owned());
+    assert_eq!(result.confirmed, true.into());
+}
+
+#[test]
+fn block_account_unconfirmed() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let send = TestBlockBuilder::legacy_send()
+        .previous(*DEV_GENESIS_HASH)
+        .destination(Account::zero())
+        .balance(Amount::MAX - Amount::raw(100))
+        .sign((*DEV_GENESIS_KEY).clone())
+        .work(node.work_generate_dev(*DEV_GENESIS_HASH))
+        .build();
+    node.process(send.clone()).unwrap();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.block_account(send.hash()).await.unwrap() });
+
+    assert_eq!(result.account, *DEV_GENESIS_ACCOUNT);
+    assert_eq!(result.confirmed, false.into());
 }
 
 #[test]
@@ -1,4 +1,4 @@
-use rsban_core::BlockHash;
+use rsban_core::{Account, Amount, BlockHash, TestBlockBuilder, DEV_GENESIS_KEY};
 use rsban_ledger::DEV_GENESIS_HASH;
 use test_helpers::{setup_rpc_client_and_server, System};
 
@@ -7,6 +7,29 @@ fn block_confirm() {
     let mut system = System::new();
     let node = system.make_node();
 
+    let send = TestBlockBuilder::legacy_send()
+        .previous(*DEV_GENESIS_HASH)
+        .destination(Account::zero())
+        .balance(Amount::MAX - Amount::raw(100))
+        .sign((*DEV_GENESIS_KEY).clone())
+        .work(node.work_generate_dev(*DEV_GENESIS_HASH))
+        .build();
+    node.process(send.clone()).unwrap();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.block_confirm(send.hash()).await.unwrap() });
+
+    assert_eq!(result.started, true.into());
+}
+
+#[test]
+fn block_confirm_already_confirmed() {
+    let mut system = System::new();
+    let node = system.make_node();
+
     let server = setup_rpc_client_and_server(node.clone(), true);
 
     let result = node.runtime.block_on(async {
@@ -17,7 +40,7 @@ fn block_confirm() {
             .unwrap()
     });
 
-    assert_eq!(result.started, true.into());
+    assert_eq!(result.started, false.into());
 }
 
 #[test]
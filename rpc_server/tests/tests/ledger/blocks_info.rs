@@ -1,3 +1,4 @@
+use rsban_core::{Account, Amount, BlockHash, TestBlockBuilder, DEV_GENESIS_KEY};
 use rsban_ledger::DEV_GENESIS_HASH;
 use test_helpers::{setup_rpc_client_and_server, System};
 
@@ -16,3 +17,31 @@ fn blocks_info() {
             .unwrap()
     });
 }
+
+#[test]
+fn blocks_info_successors() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let send = TestBlockBuilder::legacy_send()
+        .previous(*DEV_GENESIS_HASH)
+        .destination(Account::zero())
+        .balance(Amount::MAX - Amount::raw(100))
+        .sign((*DEV_GENESIS_KEY).clone())
+        .work(node.work_generate_dev(*DEV_GENESIS_HASH))
+        .build();
+    node.process(send.clone()).unwrap();
+
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    let result = node.runtime.block_on(async {
+        server
+            .client
+            .blocks_info(vec![*DEV_GENESIS_HASH, send.hash()])
+            .await
+            .unwrap()
+    });
+
+    assert_eq!(result.blocks[&DEV_GENESIS_HASH].successor, send.hash());
+    assert_eq!(result.blocks[&send.hash()].successor, BlockHash::zero());
+}
@@ -1,4 +1,6 @@
+use rsban_core::{Amount, PrivateKey, UnsavedBlockLatticeBuilder, DEV_GENESIS_KEY};
 use rsban_ledger::DEV_GENESIS_ACCOUNT;
+use rsban_rpc_messages::DelegatorsCountArgs;
 use test_helpers::{setup_rpc_client_and_server, System};
 
 #[test]
@@ -18,3 +20,42 @@ fn delegators_count_rpc_response() {
 
     assert_eq!(result.count, 1.into());
 }
+
+#[test]
+fn delegators_count_with_threshold() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let mut lattice = UnsavedBlockLatticeBuilder::new();
+    for balance in [10, 100] {
+        let key = PrivateKey::new();
+        let send = lattice.genesis().send(&key, Amount::raw(balance));
+        node.process(send.clone()).unwrap();
+        let open = lattice
+            .account(&key)
+            .receive_and_change(&send, DEV_GENESIS_KEY.public_key());
+        node.process(open).unwrap();
+    }
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let (all, above_threshold) = node.runtime.block_on(async {
+        let all = server
+            .client
+            .delegators_count(*DEV_GENESIS_ACCOUNT)
+            .await
+            .unwrap();
+        let above_threshold = server
+            .client
+            .delegators_count(
+                DelegatorsCountArgs::new(*DEV_GENESIS_ACCOUNT)
+                    .with_minimum_balance(Amount::raw(50)),
+            )
+            .await
+            .unwrap();
+        (all, above_threshold)
+    });
+
+    assert_eq!(all.count, 3.into());
+    assert_eq!(above_threshold.count, 2.into());
+}
@@ -65,6 +65,49 @@ fn unopened_with_threshold() {
     assert!(result.accounts.is_empty());
 }
 
+#[test]
+fn unopened_pagination() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let mut lattice = UnsavedBlockLatticeBuilder::new();
+    for i in [10, 20, 30] {
+        let send = lattice.genesis().send(Account::from(i), 1);
+        node.process(send).unwrap();
+    }
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let (first_page, second_page) = node.runtime.block_on(async {
+        let first_page = server
+            .client
+            .unopened(UnopenedArgs {
+                account: Some(Account::from(1)),
+                count: Some(2.into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let last = first_page.accounts.keys().max().unwrap();
+        let second_page = server
+            .client
+            .unopened(UnopenedArgs {
+                account: Some(last.inc().unwrap()),
+                count: Some(2.into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        (first_page, second_page)
+    });
+
+    let mut first: Vec<_> = first_page.accounts.keys().cloned().collect();
+    first.sort();
+    assert_eq!(first, [Account::from(10), Account::from(20)]);
+    let second: Vec<_> = second_page.accounts.keys().cloned().collect();
+    assert_eq!(second, [Account::from(30)]);
+}
+
 #[test]
 fn unopened_fails_without_enable_control() {
     let mut system = System::new();
@@ -0,0 +1,18 @@
+use test_helpers::{setup_rpc_client_and_server, System};
+
+#[test]
+fn health_without_peers() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.health().await.unwrap() });
+
+    assert!(!result.healthy.inner());
+    assert_eq!(result.peer_count, 0.into());
+    assert_eq!(result.unchecked_count, 0.into());
+    assert_eq!(result.unconfirmed_count, 0.into());
+}
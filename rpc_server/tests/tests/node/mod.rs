@@ -5,6 +5,7 @@ mod bootstrap_lazy;
 mod confirmation_active;
 mod confirmation_info;
 mod confirmation_quorum;
+mod health;
 mod node_id;
 mod peers;
 mod populate_backlog;
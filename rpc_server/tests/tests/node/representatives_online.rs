@@ -1,5 +1,5 @@
 use rsban_core::{Amount, WalletId, DEV_GENESIS_KEY};
-use rsban_ledger::DEV_GENESIS_ACCOUNT;
+use rsban_ledger::{DEV_GENESIS_ACCOUNT, DEV_GENESIS_PUB_KEY};
 use rsban_node::wallets::WalletsExt;
 use rsban_rpc_messages::{RepresentativesOnlineArgs, RepresentativesOnlineResponse};
 use std::time::Duration;
@@ -129,3 +129,34 @@ fn representatives_online() {
         "Node2 doesn't have the correct number of online representatives"
     );
 }
+
+#[test]
+fn representatives_online_with_weight() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    node.online_reps
+        .lock()
+        .unwrap()
+        .vote_observed(*DEV_GENESIS_PUB_KEY, node.steady_clock.now());
+
+    let result = node
+        .runtime
+        .block_on(async {
+            server
+                .client
+                .representatives_online(RepresentativesOnlineArgs::builder().weight().build())
+                .await
+        })
+        .unwrap();
+
+    let RepresentativesOnlineResponse::Detailed(result) = result else {
+        panic!("Not a detailed result")
+    };
+    assert_eq!(result.representatives.len(), 1);
+    assert_eq!(
+        result.representatives[&*DEV_GENESIS_ACCOUNT].weight,
+        node.ledger.weight(&DEV_GENESIS_PUB_KEY)
+    );
+}
@@ -0,0 +1,106 @@
+use rsban_core::{Account, BlockHash, WalletId, DEV_GENESIS_KEY};
+use rsban_ledger::DEV_GENESIS_ACCOUNT;
+use rsban_node::wallets::WalletsExt;
+use rsban_rpc_messages::AccountRepresentativeSetArgs;
+use std::time::Duration;
+use test_helpers::{assert_timely_msg, setup_rpc_client_and_server, System};
+
+#[test]
+fn account_representative_set() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let wallet = WalletId::zero();
+    node.wallets.create(wallet);
+    node.wallets
+        .insert_adhoc2(&wallet, &DEV_GENESIS_KEY.raw_key(), false)
+        .unwrap();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let representative = Account::from(123);
+    let result = node.runtime.block_on(async {
+        server
+            .client
+            .account_representative_set(AccountRepresentativeSetArgs::new(
+                wallet,
+                *DEV_GENESIS_ACCOUNT,
+                representative,
+            ))
+            .await
+            .unwrap()
+    });
+
+    assert_eq!(result.reject_reason, None);
+    assert_timely_msg(
+        Duration::from_secs(5),
+        || {
+            let tx = node.ledger.read_txn();
+            node.ledger.get_block(&tx, &result.block).is_some()
+        },
+        "change block not found in ledger",
+    );
+}
+
+#[test]
+fn account_representative_set_dry_run_rejects_invalid_previous() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let wallet = WalletId::zero();
+    node.wallets.create(wallet);
+    node.wallets
+        .insert_adhoc2(&wallet, &DEV_GENESIS_KEY.raw_key(), false)
+        .unwrap();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let args =
+        AccountRepresentativeSetArgs::builder(wallet, *DEV_GENESIS_ACCOUNT, Account::from(123))
+            .dry_run()
+            .previous(BlockHash::from(42))
+            .build();
+
+    let result = node.runtime.block_on(async {
+        server
+            .client
+            .account_representative_set(args)
+            .await
+            .unwrap()
+    });
+
+    assert_eq!(result.reject_reason, Some("Gap previous".to_string()));
+    let tx = node.ledger.read_txn();
+    assert!(node.ledger.get_block(&tx, &result.block).is_none());
+    assert_eq!(node.ledger.block_count(), 1);
+}
+
+#[test]
+fn account_representative_set_dry_run_accepts_valid_change() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let wallet = WalletId::zero();
+    node.wallets.create(wallet);
+    node.wallets
+        .insert_adhoc2(&wallet, &DEV_GENESIS_KEY.raw_key(), false)
+        .unwrap();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let args =
+        AccountRepresentativeSetArgs::builder(wallet, *DEV_GENESIS_ACCOUNT, Account::from(123))
+            .dry_run()
+            .build();
+
+    let result = node.runtime.block_on(async {
+        server
+            .client
+            .account_representative_set(args)
+            .await
+            .unwrap()
+    });
+
+    assert_eq!(result.reject_reason, None);
+    assert_eq!(node.ledger.block_count(), 1);
+}
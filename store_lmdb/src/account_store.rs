@@ -98,6 +98,16 @@ impl LmdbAccountStore {
         })
     }
 
+    /// Iterates all accounts whose `modified` timestamp (in seconds) is at or after the cutoff
+    pub fn modified_since<'txn>(
+        &self,
+        tx: &'txn dyn Transaction,
+        seconds: u64,
+    ) -> impl Iterator<Item = (Account, AccountInfo)> + 'txn {
+        self.iter(tx)
+            .filter(move |(_, info)| info.modified >= seconds)
+    }
+
     pub fn iter_range<'txn>(
         &self,
         tx: &'txn dyn Transaction,
@@ -289,6 +299,28 @@ mod tests {
         assert_eq!(it.is_end(), true);
     }
 
+    #[test]
+    fn modified_since() {
+        let info = |modified| AccountInfo {
+            modified,
+            ..Default::default()
+        };
+        let fixture = Fixture::with_stored_accounts(vec![
+            (Account::from(1), info(100)),
+            (Account::from(2), info(200)),
+            (Account::from(3), info(300)),
+        ]);
+        let txn = fixture.env.tx_begin_read();
+
+        let accounts: Vec<_> = fixture
+            .store
+            .modified_since(&txn, 200)
+            .map(|(account, _)| account)
+            .collect();
+
+        assert_eq!(accounts, vec![Account::from(2), Account::from(3)]);
+    }
+
     #[test]
     fn begin() {
         let account1 = Account::from(1);
@@ -1,6 +1,6 @@
 use crate::{
-    BinaryDbIterator, LmdbDatabase, LmdbEnv, LmdbIteratorImpl, LmdbWriteTransaction, Transaction,
-    BLOCK_TEST_DATABASE,
+    BinaryDbIterator, LmdbDatabase, LmdbEnv, LmdbIterator, LmdbIteratorImpl, LmdbWriteTransaction,
+    Transaction, BLOCK_TEST_DATABASE,
 };
 use lmdb::{DatabaseFlags, WriteFlags};
 use num_traits::FromPrimitive;
@@ -151,6 +151,22 @@ impl LmdbBlockStore {
         LmdbIteratorImpl::new_iterator(transaction, self.database, Some(hash.as_bytes()), true)
     }
 
+    pub fn iter<'txn>(
+        &self,
+        tx: &'txn dyn Transaction,
+    ) -> impl Iterator<Item = (BlockHash, SavedBlock)> + 'txn {
+        let cursor = tx
+            .open_ro_cursor(self.database)
+            .expect("could not read from block store");
+
+        LmdbIterator::new(cursor, |key, value| {
+            let hash = BlockHash::from_bytes(key.try_into().unwrap());
+            let mut stream = BufferReader::new(value);
+            let block = SavedBlock::deserialize(&mut stream).unwrap();
+            (hash, block)
+        })
+    }
+
     pub fn end(&self) -> BlockIterator {
         LmdbIteratorImpl::null_iterator()
     }
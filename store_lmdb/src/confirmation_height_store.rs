@@ -44,6 +44,23 @@ impl LmdbConfirmationHeightStore {
         .unwrap();
     }
 
+    /// Writes the confirmation height only if it is greater than the stored one.
+    /// Returns true if the write happened
+    pub fn put_if_greater(
+        &self,
+        txn: &mut LmdbWriteTransaction,
+        account: &Account,
+        info: &ConfirmationHeightInfo,
+    ) -> bool {
+        if let Some(current) = self.get(txn, account) {
+            if info.height <= current.height {
+                return false;
+            }
+        }
+        self.put(txn, account, info);
+        true
+    }
+
     pub fn get(&self, txn: &dyn Transaction, account: &Account) -> Option<ConfirmationHeightInfo> {
         match txn.get(self.database, account.as_bytes()) {
             Err(lmdb::Error::NotFound) => None,
@@ -199,6 +216,39 @@ mod tests {
         )
     }
 
+    #[test]
+    fn put_if_greater() {
+        let account = Account::from(1);
+        let current = ConfirmationHeightInfo::new(5, BlockHash::from(2));
+
+        let env = LmdbEnv::new_null_with()
+            .database("confirmation_height", LmdbDatabase::new_null(100))
+            .entry(account.as_bytes(), &current.to_bytes())
+            .build()
+            .build();
+
+        let fixture = Fixture::with_env(env);
+        let mut txn = fixture.env.tx_begin_write();
+        let put_tracker = txn.track_puts();
+
+        let lower = ConfirmationHeightInfo::new(4, BlockHash::from(3));
+        assert!(!fixture.store.put_if_greater(&mut txn, &account, &lower));
+        assert!(!fixture.store.put_if_greater(&mut txn, &account, &current));
+        assert!(put_tracker.output().is_empty());
+
+        let higher = ConfirmationHeightInfo::new(6, BlockHash::from(4));
+        assert!(fixture.store.put_if_greater(&mut txn, &account, &higher));
+        assert_eq!(
+            put_tracker.output(),
+            vec![PutEvent {
+                database: LmdbDatabase::new_null(100),
+                key: account.as_bytes().to_vec(),
+                value: higher.to_bytes().to_vec(),
+                flags: WriteFlags::empty(),
+            }]
+        );
+    }
+
     #[test]
     fn load() {
         let account = Account::from(1);
@@ -68,7 +68,20 @@ impl LmdbFinalVoteStore {
         LmdbIteratorImpl::new_iterator(txn, self.database, Some(&key_bytes), true)
     }
 
-    pub fn get(&self, txn: &dyn Transaction, root: Root) -> Vec<BlockHash> {
+    /// Returns the hashes of all final votes for the given root
+    pub fn get_for_root(&self, txn: &dyn Transaction, root: Root) -> Vec<BlockHash> {
+        self.entries_for_root(txn, root)
+            .into_iter()
+            .map(|(_, hash)| hash)
+            .collect()
+    }
+
+    /// Scans all qualified roots which start with the given root
+    fn entries_for_root(
+        &self,
+        txn: &dyn Transaction,
+        root: Root,
+    ) -> Vec<(QualifiedRoot, BlockHash)> {
         let mut result = Vec::new();
         let key_start = QualifiedRoot {
             root,
@@ -81,7 +94,7 @@ impl LmdbFinalVoteStore {
                 break;
             }
 
-            result.push(*v);
+            result.push((k.clone(), *v));
             i.next();
         }
 
@@ -89,26 +102,9 @@ impl LmdbFinalVoteStore {
     }
 
     pub fn del(&self, txn: &mut LmdbWriteTransaction, root: &Root) {
-        let mut final_vote_qualified_roots = Vec::new();
-
-        {
-            let mut it = self.begin_at_root(
-                txn,
-                &QualifiedRoot {
-                    root: *root,
-                    previous: BlockHash::zero(),
-                },
-            );
-            while let Some((k, _)) = it.current() {
-                if k.root != *root {
-                    break;
-                }
-                final_vote_qualified_roots.push(k.clone());
-                it.next();
-            }
-        }
+        let final_vote_qualified_roots = self.entries_for_root(txn, *root);
 
-        for qualified_root in final_vote_qualified_roots {
+        for (qualified_root, _) in final_vote_qualified_roots {
             let root_bytes = qualified_root.to_bytes();
             txn.delete(self.database, &root_bytes, None).unwrap();
         }
@@ -168,11 +164,42 @@ mod tests {
         let fixture = Fixture::with_stored_entries(vec![(root.clone(), hash)]);
         let txn = fixture.env.tx_begin_read();
 
-        let result = fixture.store.get(&txn, root.root);
+        let result = fixture.store.get_for_root(&txn, root.root);
 
         assert_eq!(result, vec![hash])
     }
 
+    #[test]
+    fn get_for_root_returns_only_matching_root() {
+        let root_a = Root::from(1);
+        let root_b = Root::from(2);
+        let entries = vec![
+            (
+                QualifiedRoot::new(root_a, BlockHash::from(10)),
+                BlockHash::from(100),
+            ),
+            (
+                QualifiedRoot::new(root_a, BlockHash::from(11)),
+                BlockHash::from(101),
+            ),
+            (
+                QualifiedRoot::new(root_b, BlockHash::from(12)),
+                BlockHash::from(102),
+            ),
+        ];
+        let fixture = Fixture::with_stored_entries(entries);
+        let txn = fixture.env.tx_begin_read();
+
+        assert_eq!(
+            fixture.store.get_for_root(&txn, root_a),
+            vec![BlockHash::from(100), BlockHash::from(101)]
+        );
+        assert_eq!(
+            fixture.store.get_for_root(&txn, root_b),
+            vec![BlockHash::from(102)]
+        );
+    }
+
     #[test]
     fn delete() {
         let root = QualifiedRoot::new_test_instance();
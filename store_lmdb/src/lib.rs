@@ -35,10 +35,11 @@ pub use pending_store::{ConfiguredPendingDatabaseBuilder, LmdbPendingStore};
 pub use pruned_store::{ConfiguredPrunedDatabaseBuilder, LmdbPrunedStore};
 pub use rep_weight_store::*;
 use rsban_nullable_lmdb::{
-    InactiveTransaction, LmdbDatabase, LmdbEnvironment, RoCursor, RoTransaction, RwTransaction,
+    DatabaseStat, InactiveTransaction, LmdbDatabase, LmdbEnvironment, RoCursor, RoTransaction,
+    RwTransaction,
 };
-pub use store::{create_backup_file, LedgerCache, LmdbStore};
-pub use version_store::LmdbVersionStore;
+pub use store::{create_backup_file, IntegrityReport, LedgerCache, LmdbStore, TableStats};
+pub use version_store::{LmdbVersionStore, MigrationRecord};
 pub use wallet_store::{Fans, KeyType, LmdbWalletStore, WalletValue};
 
 use primitive_types::U256;
@@ -47,7 +48,10 @@ use std::{
     any::Any,
     cmp::{max, min},
     mem,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::{Duration, Instant},
 };
 
@@ -72,6 +76,7 @@ pub trait Transaction {
     }
     fn open_ro_cursor(&self, database: LmdbDatabase) -> lmdb::Result<RoCursor>;
     fn count(&self, database: LmdbDatabase) -> u64;
+    fn stat(&self, database: LmdbDatabase) -> lmdb::Result<DatabaseStat>;
 }
 
 pub trait TransactionTracker: Send + Sync {
@@ -200,6 +205,10 @@ impl Transaction for LmdbReadTransaction {
     fn count(&self, database: LmdbDatabase) -> u64 {
         self.txn().count(database)
     }
+
+    fn stat(&self, database: LmdbDatabase) -> lmdb::Result<DatabaseStat> {
+        self.txn().stat(database)
+    }
 }
 
 enum RwTxnState {
@@ -394,6 +403,10 @@ impl Transaction for LmdbWriteTransaction {
         self.rw_txn().count(database)
     }
 
+    fn stat(&self, database: LmdbDatabase) -> lmdb::Result<DatabaseStat> {
+        self.rw_txn().stat(database)
+    }
+
     fn is_refresh_needed(&self) -> bool {
         self.is_refresh_needed_with(Duration::from_millis(500))
     }
@@ -412,12 +425,34 @@ impl Transaction for LmdbWriteTransaction {
 }
 
 pub fn parallel_traversal(action: &(impl Fn(U256, U256, bool) + Send + Sync)) {
+    parallel_traversal_cancellable(Arc::new(AtomicBool::new(false)), action);
+}
+
+/// Like `parallel_traversal`, but ranges whose thread hasn't started its
+/// action yet are skipped once `cancel` is set
+pub fn parallel_traversal_cancellable(
+    cancel: Arc<AtomicBool>,
+    action: &(impl Fn(U256, U256, bool) + Send + Sync),
+) {
+    parallel_traversal_impl(&cancel, action, |_| {});
+}
+
+/// `before_spawn` is called with the thread index before each range thread is spawned
+fn parallel_traversal_impl(
+    cancel: &AtomicBool,
+    action: &(impl Fn(U256, U256, bool) + Send + Sync),
+    before_spawn: impl Fn(usize),
+) {
     // Between 10 and 40 threads, scales well even in low power systems as long as actions are I/O bound
     let thread_count = max(10, min(40, 11 * get_cpu_count()));
     let split = U256::max_value() / thread_count;
 
     std::thread::scope(|s| {
         for thread in 0..thread_count {
+            before_spawn(thread);
+            if cancel.load(Ordering::SeqCst) {
+                break;
+            }
             let start = split * thread;
             let end = split * (thread + 1);
             let is_last = thread == thread_count - 1;
@@ -425,7 +460,9 @@ pub fn parallel_traversal(action: &(impl Fn(U256, U256, bool) + Send + Sync)) {
             std::thread::Builder::new()
                 .name("DB par traversl".to_owned())
                 .spawn_scoped(s, move || {
-                    action(start, end, is_last);
+                    if !cancel.load(Ordering::SeqCst) {
+                        action(start, end, is_last);
+                    }
                 })
                 .unwrap();
         }
@@ -447,6 +484,7 @@ pub const PEERS_TEST_DATABASE: LmdbDatabase = LmdbDatabase::new_null(8);
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::sync::{atomic::AtomicUsize, Mutex};
 
     #[test]
     fn tracks_deletes() {
@@ -472,4 +510,42 @@ mod test {
 
         assert_eq!(clear_tracker.output(), vec![database])
     }
+
+    #[test]
+    fn cancelled_traversal_does_no_work() {
+        let cancel = Arc::new(AtomicBool::new(true));
+        let calls = AtomicUsize::new(0);
+        parallel_traversal_cancellable(cancel, &|_, _, _| {
+            calls.fetch_add(1, Ordering::SeqCst);
+        });
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn cancel_during_traversal() {
+        let cancel = AtomicBool::new(false);
+        let started = Mutex::new(Vec::new());
+        parallel_traversal_impl(
+            &cancel,
+            &|start, _, _| {
+                started.lock().unwrap().push(start);
+            },
+            |thread| {
+                if thread == 3 {
+                    cancel.store(true, Ordering::SeqCst);
+                }
+            },
+        );
+
+        // Only the ranges spawned before the cancellation may have run
+        let thread_count = max(10, min(40, 11 * get_cpu_count()));
+        let first_skipped = U256::max_value() / thread_count * 3;
+        let started = started.into_inner().unwrap();
+        assert!(
+            started.len() <= 3,
+            "{} ranges were traversed",
+            started.len()
+        );
+        assert!(started.iter().all(|start| *start < first_skipped));
+    }
 }
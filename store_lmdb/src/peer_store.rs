@@ -41,6 +41,7 @@ impl LmdbPeerStore {
         self.put_listener.track()
     }
 
+    /// Inserts the peer or updates its last seen timestamp
     pub fn put(&self, txn: &mut LmdbWriteTransaction, endpoint: SocketAddrV6, time: SystemTime) {
         self.put_listener.emit((endpoint.clone(), time));
         txn.put(
@@ -88,6 +89,16 @@ impl LmdbPeerStore {
             )
         }))
     }
+
+    /// Returns all peers that were seen at or after the cutoff, most recently seen first
+    pub fn peers_seen_since(&self, txn: &dyn Transaction, cutoff: SystemTime) -> Vec<SocketAddrV6> {
+        let mut peers: Vec<_> = self
+            .iter(txn)
+            .filter(|(_, last_seen)| *last_seen >= cutoff)
+            .collect();
+        peers.sort_by(|a, b| b.1.cmp(&a.1));
+        peers.into_iter().map(|(endpoint, _)| endpoint).collect()
+    }
 }
 
 pub struct PeerIterator<'txn>(LmdbIterator<'txn, EndpointBytes, TimeBytes>);
@@ -280,6 +291,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn peers_seen_since() {
+        let old = UNIX_EPOCH + Duration::from_secs(1000);
+        let recent = UNIX_EPOCH + Duration::from_secs(5000);
+        let fixture = Fixture::with_stored_times(vec![(TEST_PEER_A, old), (TEST_PEER_B, recent)]);
+        let txn = fixture.env.tx_begin_read();
+
+        assert_eq!(
+            fixture
+                .store
+                .peers_seen_since(&txn, UNIX_EPOCH + Duration::from_secs(2000)),
+            vec![TEST_PEER_B]
+        );
+        assert_eq!(
+            fixture.store.peers_seen_since(&txn, old),
+            vec![TEST_PEER_B, TEST_PEER_A]
+        );
+    }
+
     #[test]
     fn track_puts() {
         let fixture = Fixture::new();
@@ -334,6 +364,16 @@ mod tests {
             Self::with_env(env.build().build())
         }
 
+        fn with_stored_times(entries: Vec<(SocketAddrV6, SystemTime)>) -> Self {
+            let mut env = LmdbEnv::new_null_with().database("peers", LmdbDatabase::new_null(42));
+
+            for (endpoint, time) in entries {
+                env = env.entry(&EndpointBytes::from(endpoint), &TimeBytes::from(time));
+            }
+
+            Self::with_env(env.build().build())
+        }
+
         fn with_env(env: LmdbEnv) -> Self {
             let env = Arc::new(env);
             Self {
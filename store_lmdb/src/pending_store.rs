@@ -3,6 +3,7 @@ use crate::{
     PENDING_TEST_DATABASE,
 };
 use lmdb::{DatabaseFlags, WriteFlags};
+use lmdb_sys::MDB_SET_RANGE;
 use rsban_core::{
     utils::{BufferReader, Deserialize},
     Account, BlockHash, PendingInfo, PendingKey,
@@ -88,6 +89,29 @@ impl LmdbPendingStore {
         }
     }
 
+    /// Looks up all keys with a single cursor. The result has one entry per key, in the same order
+    pub fn get_many(&self, txn: &dyn Transaction, keys: &[PendingKey]) -> Vec<Option<PendingInfo>> {
+        let cursor = txn
+            .open_ro_cursor(self.database)
+            .expect("could not read from pending store");
+
+        keys.iter()
+            .map(|key| {
+                let key_bytes = key.to_bytes();
+                match cursor.get(Some(&key_bytes), None, MDB_SET_RANGE) {
+                    Ok((Some(found), bytes)) if found == key_bytes.as_slice() => {
+                        let mut stream = BufferReader::new(bytes);
+                        PendingInfo::deserialize(&mut stream).ok()
+                    }
+                    Ok(_) | Err(lmdb::Error::NotFound) => None,
+                    Err(e) => {
+                        panic!("Could not load pending info: {:?}", e);
+                    }
+                }
+            })
+            .collect()
+    }
+
     pub fn begin<'txn>(&self, txn: &'txn dyn Transaction) -> PendingIterator<'txn> {
         LmdbIteratorImpl::new_iterator(txn, self.database, None, true)
     }
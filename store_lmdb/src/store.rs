@@ -2,14 +2,15 @@ use crate::{
     EnvOptions, LmdbAccountStore, LmdbBlockStore, LmdbConfirmationHeightStore, LmdbDatabase,
     LmdbEnv, LmdbFinalVoteStore, LmdbOnlineWeightStore, LmdbPeerStore, LmdbPendingStore,
     LmdbPrunedStore, LmdbReadTransaction, LmdbRepWeightStore, LmdbVersionStore,
-    LmdbWriteTransaction, NullTransactionTracker, TransactionTracker, STORE_VERSION_CURRENT,
-    STORE_VERSION_MINIMUM,
+    LmdbWriteTransaction, NullTransactionTracker, Transaction, TransactionTracker,
+    STORE_VERSION_CURRENT, STORE_VERSION_MINIMUM,
 };
 use lmdb::{DatabaseFlags, WriteFlags};
 use lmdb_sys::{MDB_CP_COMPACT, MDB_SUCCESS};
 use rsban_core::utils::seconds_since_epoch;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashMap,
     ffi::CString,
     path::{Path, PathBuf},
     sync::{
@@ -180,6 +181,85 @@ impl LmdbStore {
         })
     }
 
+    /// Walks all blocks and checks that the successor and height stored in the
+    /// sideband are consistent with the neighboring blocks. Nothing gets modified.
+    pub fn verify_integrity(&self, txn: &dyn Transaction) -> IntegrityReport {
+        let mut report = IntegrityReport::default();
+        for (hash, block) in self.block.iter(txn) {
+            report.blocks_checked += 1;
+
+            if let Some(successor_hash) = block.successor() {
+                match self.block.get(txn, &successor_hash) {
+                    Some(successor) => {
+                        if successor.previous() != hash {
+                            report.successor_mismatches += 1;
+                        }
+                        if successor.height() != block.height() + 1 {
+                            report.height_mismatches += 1;
+                        }
+                    }
+                    None => report.missing_successors += 1,
+                }
+            }
+
+            let previous_hash = block.previous();
+            if previous_hash.is_zero() {
+                if block.height() != 1 {
+                    report.height_mismatches += 1;
+                }
+            } else {
+                match self.block.get(txn, &previous_hash) {
+                    Some(previous) => {
+                        // A cleared or wrong successor in the previous block
+                        if previous.successor() != Some(hash) {
+                            report.successor_mismatches += 1;
+                        }
+                    }
+                    None => {
+                        if !self.pruned.exists(txn, &previous_hash) {
+                            report.missing_previous += 1;
+                        }
+                    }
+                }
+            }
+        }
+        report
+    }
+
+    /// Returns entry count and page statistics for each table
+    pub fn table_sizes(
+        &self,
+        txn: &dyn Transaction,
+    ) -> anyhow::Result<HashMap<&'static str, TableStats>> {
+        let tables = [
+            ("accounts", self.account.database()),
+            ("blocks", self.block.database()),
+            ("pending", self.pending.database()),
+            ("pruned", self.pruned.database()),
+            ("confirmation_height", self.confirmation_height.database()),
+            ("final_votes", self.final_vote.database()),
+            ("online_weight", self.online_weight.database()),
+            ("peers", self.peer.database()),
+            ("rep_weights", self.rep_weight.database()),
+        ];
+        let mut result = HashMap::new();
+        for (name, database) in tables {
+            let stat = txn.stat(database)?;
+            result.insert(
+                name,
+                TableStats {
+                    page_size: stat.page_size,
+                    entries: stat.entries,
+                    depth: stat.depth,
+                    branch_pages: stat.branch_pages,
+                    leaf_pages: stat.leaf_pages,
+                    overflow_pages: stat.overflow_pages,
+                },
+            );
+        }
+        Ok(result)
+    }
+
     pub fn vendor(&self) -> String {
         // fake version! TODO: read version
         format!("lmdb-rkv {}.{}.{}", 0, 14, 0)
@@ -337,6 +417,47 @@ pub struct MemoryStats {
     pub page_size: u32,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableStats {
+    pub page_size: u32,
+    pub entries: usize,
+    pub depth: u32,
+    pub branch_pages: usize,
+    pub leaf_pages: usize,
+    pub overflow_pages: usize,
+}
+
+impl TableStats {
+    /// Number of bytes used by all pages of the table
+    pub fn size_in_bytes(&self) -> u64 {
+        let pages = self.branch_pages + self.leaf_pages + self.overflow_pages;
+        pages as u64 * self.page_size as u64
+    }
+}
+
+/// Result of `LmdbStore::verify_integrity`
+#[derive(Default, PartialEq, Eq, Debug, Clone)]
+pub struct IntegrityReport {
+    pub blocks_checked: u64,
+    /// Blocks whose successor doesn't exist
+    pub missing_successors: u64,
+    /// Blocks whose previous block neither exists nor is pruned
+    pub missing_previous: u64,
+    /// Successor and previous links that don't point at each other
+    pub successor_mismatches: u64,
+    /// Heights that don't increase by one along the chain
+    pub height_mismatches: u64,
+}
+
+impl IntegrityReport {
+    pub fn is_ok(&self) -> bool {
+        self.missing_successors == 0
+            && self.missing_previous == 0
+            && self.successor_mismatches == 0
+            && self.height_mismatches == 0
+    }
+}
+
 /// Takes a filepath, appends '_backup_<timestamp>' to the end (but before any extension) and saves that file in the same directory
 pub fn create_backup_file(env: &LmdbEnv) -> anyhow::Result<()> {
     let source_path = env.file_path()?;
@@ -396,6 +517,7 @@ fn backup_file_path(source_path: &Path) -> anyhow::Result<PathBuf> {
 mod tests {
     use super::*;
     use crate::TestDbFile;
+    use rsban_core::{BlockHash, SavedAccountChain};
 
     #[test]
     fn create_store() -> anyhow::Result<()> {
@@ -428,6 +550,85 @@ mod tests {
         assert_eq!(store.version.get(&txn), Some(STORE_VERSION_MINIMUM));
     }
 
+    #[test]
+    fn verify_integrity_of_consistent_chain() {
+        let file = TestDbFile::random();
+        let store = LmdbStore::open(&file.path).build().unwrap();
+        let chain = create_chain();
+        let mut txn = store.tx_begin_write();
+        for block in chain.blocks() {
+            store
+                .block
+                .raw_put(&mut txn, &block.serialize_with_sideband(), &block.hash());
+        }
+
+        let report = store.verify_integrity(&txn);
+
+        assert!(report.is_ok());
+        assert_eq!(report.blocks_checked, 3);
+    }
+
+    #[test]
+    fn detect_corrupted_successor() {
+        let file = TestDbFile::random();
+        let store = LmdbStore::open(&file.path).build().unwrap();
+        let chain = create_chain();
+        let mut txn = store.tx_begin_write();
+        for block in chain.blocks() {
+            store
+                .block
+                .raw_put(&mut txn, &block.serialize_with_sideband(), &block.hash());
+        }
+        let mut corrupted = chain.block(2).clone();
+        let mut sideband = corrupted.sideband().clone();
+        sideband.successor = BlockHash::from(999);
+        corrupted.set_sideband(sideband);
+        store.block.raw_put(
+            &mut txn,
+            &corrupted.serialize_with_sideband(),
+            &corrupted.hash(),
+        );
+
+        let report = store.verify_integrity(&txn);
+
+        assert_eq!(
+            report,
+            IntegrityReport {
+                blocks_checked: 3,
+                missing_successors: 1,
+                successor_mismatches: 1,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn table_sizes() {
+        let file = TestDbFile::random();
+        let store = LmdbStore::open(&file.path).build().unwrap();
+        let chain = create_chain();
+        let mut txn = store.tx_begin_write();
+        let block = chain.block(1);
+        store
+            .block
+            .raw_put(&mut txn, &block.serialize_with_sideband(), &block.hash());
+
+        let sizes = store.table_sizes(&txn).unwrap();
+
+        assert_eq!(sizes["blocks"].entries, 1);
+        assert!(sizes["blocks"].leaf_pages > 0);
+        assert!(sizes["blocks"].size_in_bytes() >= sizes["blocks"].page_size as u64);
+        assert_eq!(sizes["accounts"].entries, 0);
+        assert_eq!(sizes["pending"].entries, 0);
+    }
+
+    fn create_chain() -> SavedAccountChain {
+        let mut chain = SavedAccountChain::new_opened_chain();
+        chain.add_legacy_send();
+        chain.add_legacy_send();
+        chain
+    }
+
     fn assert_upgrade_fails(path: &Path, error_msg: &str) {
         match LmdbStore::open(path).build() {
             Ok(_) => panic!("store should not be created!"),
@@ -1,7 +1,12 @@
 use crate::{LmdbDatabase, LmdbEnv, LmdbWriteTransaction, Transaction, STORE_VERSION_CURRENT};
 use core::panic;
 use lmdb::{DatabaseFlags, WriteFlags};
-use std::{path::Path, sync::Arc};
+use lmdb_sys::{MDB_NEXT, MDB_SET_RANGE};
+use std::{
+    path::Path,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 pub struct LmdbVersionStore {
     _env: Arc<LmdbEnv>,
@@ -10,6 +15,14 @@ pub struct LmdbVersionStore {
     db_handle: LmdbDatabase,
 }
 
+/// A database upgrade from one store version to another
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationRecord {
+    pub from: i32,
+    pub to: i32,
+    pub timestamp: SystemTime,
+}
+
 pub struct UpgradeInfo {
     pub is_fresh_db: bool,
     pub is_fully_upgraded: bool,
@@ -69,6 +82,72 @@ impl LmdbVersionStore {
         let db = self.db_handle();
         load_version(txn, db)
     }
+
+    /// Sets the version to `to` and appends the upgrade to the migration history
+    pub fn migrate(&self, txn: &mut LmdbWriteTransaction, from: i32, to: i32, now: SystemTime) {
+        let sequence = self.history(txn).len() as u64;
+        let record = MigrationRecord {
+            from,
+            to,
+            timestamp: now,
+        };
+        txn.put(
+            self.db_handle,
+            &history_key(sequence),
+            &history_value(&record),
+            WriteFlags::empty(),
+        )
+        .unwrap();
+        self.put(txn, to);
+    }
+
+    /// All migrations that were applied to this database, oldest first
+    pub fn history(&self, txn: &dyn Transaction) -> Vec<MigrationRecord> {
+        let mut result = Vec::new();
+        let cursor = txn
+            .open_ro_cursor(self.db_handle)
+            .expect("Could not read migration history");
+        let mut entry = cursor.get(Some(&history_key(0)), None, MDB_SET_RANGE);
+        while let Ok((Some(key), value)) = entry {
+            if key[0] != HISTORY_KEY_PREFIX {
+                break;
+            }
+            result.push(parse_history_value(value));
+            entry = cursor.get(None, None, MDB_NEXT);
+        }
+        result
+    }
+}
+
+const HISTORY_KEY_PREFIX: u8 = 1;
+
+fn history_key(sequence: u64) -> [u8; 32] {
+    let mut key = [0; 32];
+    key[0] = HISTORY_KEY_PREFIX;
+    key[24..].copy_from_slice(&sequence.to_be_bytes());
+    key
+}
+
+fn history_value(record: &MigrationRecord) -> [u8; 16] {
+    let seconds = record
+        .timestamp
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let mut value = [0; 16];
+    value[..4].copy_from_slice(&record.from.to_be_bytes());
+    value[4..8].copy_from_slice(&record.to.to_be_bytes());
+    value[8..].copy_from_slice(&seconds.to_be_bytes());
+    value
+}
+
+fn parse_history_value(value: &[u8]) -> MigrationRecord {
+    MigrationRecord {
+        from: i32::from_be_bytes(value[..4].try_into().unwrap()),
+        to: i32::from_be_bytes(value[4..8].try_into().unwrap()),
+        timestamp: UNIX_EPOCH
+            + Duration::from_secs(u64::from_be_bytes(value[8..16].try_into().unwrap())),
+    }
 }
 
 fn load_version(txn: &dyn Transaction, db: LmdbDatabase) -> Option<i32> {
@@ -89,3 +168,49 @@ fn value_bytes(version: i32) -> [u8; 32] {
 fn version_key() -> [u8; 32] {
     value_bytes(1)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestDbFile;
+
+    #[test]
+    fn empty_history() {
+        let file = TestDbFile::random();
+        let env = Arc::new(LmdbEnv::new(&file.path).unwrap());
+        let store = LmdbVersionStore::new(env.clone()).unwrap();
+        let txn = env.tx_begin_read();
+        assert_eq!(store.history(&txn), Vec::new());
+    }
+
+    #[test]
+    fn record_migrations_in_order() {
+        let file = TestDbFile::random();
+        let env = Arc::new(LmdbEnv::new(&file.path).unwrap());
+        let store = LmdbVersionStore::new(env.clone()).unwrap();
+        let mut txn = env.tx_begin_write();
+        store.put(&mut txn, 21);
+        let time1 = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let time2 = UNIX_EPOCH + Duration::from_secs(2_000_000);
+
+        store.migrate(&mut txn, 21, 22, time1);
+        store.migrate(&mut txn, 22, 23, time2);
+
+        assert_eq!(store.get(&txn), Some(23));
+        assert_eq!(
+            store.history(&txn),
+            vec![
+                MigrationRecord {
+                    from: 21,
+                    to: 22,
+                    timestamp: time1
+                },
+                MigrationRecord {
+                    from: 22,
+                    to: 23,
+                    timestamp: time2
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use rsban_network::ChannelSnapshot;
+use rsban_node::Node;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum TrafficColumn {
+    PeerAddr,
+    Mode,
+    ProtocolVersion,
+    BytesSent,
+    BytesReceived,
+    LimiterDrops,
+}
+
+/// All channels of the node with the number of bytes sent and received,
+/// sorted by a user selectable column
+pub(crate) struct ChannelTraffic {
+    channels: Vec<ChannelSnapshot>,
+    sort_column: TrafficColumn,
+    descending: bool,
+}
+
+impl ChannelTraffic {
+    pub(crate) fn new() -> Self {
+        Self {
+            channels: Vec::new(),
+            sort_column: TrafficColumn::PeerAddr,
+            descending: false,
+        }
+    }
+
+    pub(crate) fn update(&mut self, node: &Node) {
+        let snapshot = node.network_info.read().unwrap().channel_snapshot();
+        self.set_channels(snapshot);
+    }
+
+    pub(crate) fn set_channels(&mut self, channels: Vec<ChannelSnapshot>) {
+        self.channels = channels;
+        self.sort();
+    }
+
+    pub(crate) fn channels(&self) -> &[ChannelSnapshot] {
+        &self.channels
+    }
+
+    pub(crate) fn sort_column(&self) -> TrafficColumn {
+        self.sort_column
+    }
+
+    pub(crate) fn descending(&self) -> bool {
+        self.descending
+    }
+
+    /// Sorts by the given column. Selecting the current sort column again
+    /// toggles the sort direction
+    pub(crate) fn sort_by(&mut self, column: TrafficColumn) {
+        if self.sort_column == column {
+            self.descending = !self.descending;
+        } else {
+            self.sort_column = column;
+            self.descending = false;
+        }
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        match self.sort_column {
+            TrafficColumn::PeerAddr => self.channels.sort_by_key(|c| c.peer_addr),
+            TrafficColumn::Mode => self.channels.sort_by_key(|c| c.mode as u8),
+            TrafficColumn::ProtocolVersion => self.channels.sort_by_key(|c| c.protocol_version),
+            TrafficColumn::BytesSent => self.channels.sort_by_key(|c| c.bytes_sent),
+            TrafficColumn::BytesReceived => self.channels.sort_by_key(|c| c.bytes_received),
+            TrafficColumn::LimiterDrops => self.channels.sort_by_key(|c| c.limiter_drops),
+        }
+        if self.descending {
+            self.channels.reverse();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsban_network::{ChannelDirection, ChannelId, ChannelMode};
+    use std::net::{Ipv6Addr, SocketAddrV6};
+
+    #[test]
+    fn sort_by_bytes_sent() {
+        let mut traffic = ChannelTraffic::new();
+        traffic.set_channels(vec![
+            test_channel(1, 500),
+            test_channel(2, 100),
+            test_channel(3, 300),
+        ]);
+
+        traffic.sort_by(TrafficColumn::BytesSent);
+        assert_eq!(bytes_sent(&traffic), [100, 300, 500]);
+
+        traffic.sort_by(TrafficColumn::BytesSent);
+        assert!(traffic.descending());
+        assert_eq!(bytes_sent(&traffic), [500, 300, 100]);
+    }
+
+    #[test]
+    fn keep_sort_order_when_channels_change() {
+        let mut traffic = ChannelTraffic::new();
+        traffic.sort_by(TrafficColumn::BytesSent);
+
+        traffic.set_channels(vec![test_channel(1, 20), test_channel(2, 10)]);
+
+        assert_eq!(bytes_sent(&traffic), [10, 20]);
+    }
+
+    fn bytes_sent(traffic: &ChannelTraffic) -> Vec<u64> {
+        traffic.channels().iter().map(|c| c.bytes_sent).collect()
+    }
+
+    fn test_channel(id: usize, bytes_sent: u64) -> ChannelSnapshot {
+        ChannelSnapshot {
+            channel_id: ChannelId::from(id),
+            peer_addr: SocketAddrV6::new(Ipv6Addr::LOCALHOST, 1000 + id as u16, 0, 0),
+            mode: ChannelMode::Realtime,
+            direction: ChannelDirection::Outbound,
+            protocol_version: 20,
+            bytes_sent,
+            bytes_received: 0,
+            average_rtt: None,
+            throttled: false,
+            limiter_drops: 0,
+        }
+    }
+}
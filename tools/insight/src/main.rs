@@ -1,9 +1,12 @@
+mod channel_traffic;
 mod channels;
 mod ledger_stats;
 mod message_collection;
+mod message_diff;
 mod message_rate_calculator;
 mod message_recorder;
 mod node_runner;
+mod node_stats;
 mod nullable_runtime;
 mod rate_calculator;
 mod view_models;
@@ -1,3 +1,4 @@
+use crate::message_diff::MessageDiff;
 use chrono::{DateTime, TimeZone, Utc};
 use rsban_core::{Account, BlockHash};
 use rsban_messages::{AscPullAckType, AscPullReqType, HashType, Message, MessageType};
@@ -182,6 +183,17 @@ impl MessageCollection {
         self.filtered.len()
     }
 
+    /// Compares two messages of the same type. Returns `None` if an index
+    /// is out of range or the message types differ
+    pub fn diff(&self, first: usize, second: usize) -> Option<MessageDiff> {
+        let first = &self.filtered.get(first)?.message;
+        let second = &self.filtered.get(second)?.message;
+        if first.message_type() != second.message_type() {
+            return None;
+        }
+        Some(MessageDiff::new(first, second))
+    }
+
     pub fn add(&mut self, message: RecordedMessage) {
         if self.filter.include(&message) {
             self.filtered.push(message.clone());
@@ -253,6 +265,7 @@ impl MessageCollection {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rsban_messages::Keepalive;
 
     #[test]
     fn empty() {
@@ -316,4 +329,22 @@ mod tests {
         assert!(collection.get(1).is_some());
         assert!(collection.get(2).is_none());
     }
+
+    #[test]
+    fn diff_requires_same_message_type() {
+        let mut collection = MessageCollection::default();
+        collection.add(RecordedMessage::new_test_instance());
+        collection.add(RecordedMessage {
+            message: Message::Keepalive(Keepalive::new_test_instance()),
+            ..RecordedMessage::new_test_instance()
+        });
+        collection.add(RecordedMessage {
+            message: Message::Keepalive(Keepalive::default()),
+            ..RecordedMessage::new_test_instance()
+        });
+
+        assert!(collection.diff(0, 1).is_none());
+        assert!(collection.diff(1, 3).is_none());
+        assert!(collection.diff(1, 2).unwrap().has_changes());
+    }
 }
@@ -0,0 +1,122 @@
+use rsban_messages::Message;
+use std::fmt::Display;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub(crate) enum DiffLine {
+    Unchanged(String),
+    Removed(String),
+    Added(String),
+}
+
+impl Display for DiffLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiffLine::Unchanged(line) => write!(f, "  {line}"),
+            DiffLine::Removed(line) => write!(f, "- {line}"),
+            DiffLine::Added(line) => write!(f, "+ {line}"),
+        }
+    }
+}
+
+/// Line based diff of the pretty printed debug output of two messages
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub(crate) struct MessageDiff {
+    pub lines: Vec<DiffLine>,
+}
+
+impl MessageDiff {
+    pub fn new(old: &Message, new: &Message) -> Self {
+        let old = format!("{old:#?}");
+        let new = format!("{new:#?}");
+        let old: Vec<&str> = old.lines().collect();
+        let new: Vec<&str> = new.lines().collect();
+        Self {
+            lines: diff_lines(&old, &new),
+        }
+    }
+
+    pub fn has_changes(&self) -> bool {
+        self.changed_lines().next().is_some()
+    }
+
+    pub fn changed_lines(&self) -> impl Iterator<Item = &DiffLine> {
+        self.lines
+            .iter()
+            .filter(|l| !matches!(l, DiffLine::Unchanged(_)))
+    }
+}
+
+impl Display for MessageDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Calculates the longest common subsequence of both texts and marks
+/// all lines that are not part of it as removed or added
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            result.push(DiffLine::Unchanged(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            result.push(DiffLine::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            result.push(DiffLine::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+    result.extend(old[i..].iter().map(|l| DiffLine::Removed(l.to_string())));
+    result.extend(new[j..].iter().map(|l| DiffLine::Added(l.to_string())));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsban_messages::Keepalive;
+    use std::net::{Ipv6Addr, SocketAddrV6};
+
+    #[test]
+    fn equal_messages() {
+        let message = Message::Keepalive(Keepalive::new_test_instance());
+        let diff = MessageDiff::new(&message, &message);
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn one_changed_field() {
+        let old = Keepalive::new_test_instance();
+        let mut new = old.clone();
+        new.peers[1] = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 9999, 0, 0);
+
+        let diff = MessageDiff::new(&Message::Keepalive(old), &Message::Keepalive(new));
+
+        let changed: Vec<_> = diff.changed_lines().cloned().collect();
+        assert_eq!(
+            changed,
+            vec![
+                DiffLine::Removed("            [::ffff:1:2:3:5]:2222,".to_string()),
+                DiffLine::Added("            [::1]:9999,".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use rsban_network::ChannelMode;
+use rsban_node::{
+    stats::{CounterKey, DetailType, Direction, StatType},
+    Node,
+};
+use rsban_nullable_clock::Timestamp;
+use std::{collections::BTreeMap, time::Duration};
+
+/// Selected node counters, refreshed from `Stats::snapshot` in a fixed interval
+pub(crate) struct NodeStats {
+    pub messages_in: u64,
+    pub messages_out: u64,
+    pub blocks_processed: u64,
+    pub active_channels: usize,
+    refresh_interval: Duration,
+    last_refresh: Option<Timestamp>,
+}
+
+impl NodeStats {
+    pub(crate) const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+    pub(crate) fn new() -> Self {
+        Self {
+            messages_in: 0,
+            messages_out: 0,
+            blocks_processed: 0,
+            active_channels: 0,
+            refresh_interval: Self::DEFAULT_REFRESH_INTERVAL,
+            last_refresh: None,
+        }
+    }
+
+    pub(crate) fn refresh_due(&self, now: Timestamp) -> bool {
+        match self.last_refresh {
+            Some(last) => now - last >= self.refresh_interval,
+            None => true,
+        }
+    }
+
+    pub(crate) fn update(&mut self, node: &Node, now: Timestamp) {
+        if !self.refresh_due(now) {
+            return;
+        }
+        let snapshot = node.stats.snapshot();
+        let active_channels = node
+            .network_info
+            .read()
+            .unwrap()
+            .count_by_mode(ChannelMode::Realtime);
+        self.apply_snapshot(&snapshot, active_channels, now);
+    }
+
+    pub(crate) fn apply_snapshot(
+        &mut self,
+        snapshot: &BTreeMap<CounterKey, u64>,
+        active_channels: usize,
+        now: Timestamp,
+    ) {
+        self.messages_in = sum_details(snapshot, StatType::Message, Direction::In);
+        self.messages_out = sum_details(snapshot, StatType::Message, Direction::Out);
+        self.blocks_processed =
+            sum_details(snapshot, StatType::BlockprocessorResult, Direction::In);
+        self.active_channels = active_channels;
+        self.last_refresh = Some(now);
+    }
+}
+
+/// Sums up all details of a stat type. The aggregated "all" entry is skipped,
+/// so that it isn't counted twice
+fn sum_details(snapshot: &BTreeMap<CounterKey, u64>, stat_type: StatType, dir: Direction) -> u64 {
+    snapshot
+        .iter()
+        .filter(|(key, _)| {
+            key.stat_type == stat_type && key.dir == dir && key.detail != DetailType::All
+        })
+        .map(|(_, value)| *value)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refresh_interval() {
+        let mut stats = NodeStats::new();
+        let now = Timestamp::new_test_instance();
+        assert!(stats.refresh_due(now));
+
+        stats.apply_snapshot(&BTreeMap::new(), 0, now);
+        assert!(!stats.refresh_due(now + Duration::from_millis(500)));
+        assert!(stats.refresh_due(now + NodeStats::DEFAULT_REFRESH_INTERVAL));
+    }
+
+    #[test]
+    fn sum_message_counters() {
+        let mut stats = NodeStats::new();
+        let snapshot = BTreeMap::from([
+            (
+                CounterKey::new(StatType::Message, DetailType::Keepalive, Direction::In),
+                3,
+            ),
+            (
+                CounterKey::new(StatType::Message, DetailType::Publish, Direction::In),
+                4,
+            ),
+            (
+                CounterKey::new(StatType::Message, DetailType::Publish, Direction::Out),
+                5,
+            ),
+            (
+                CounterKey::new(StatType::Message, DetailType::All, Direction::Out),
+                5,
+            ),
+        ]);
+
+        stats.apply_snapshot(&snapshot, 2, Timestamp::new_test_instance());
+
+        assert_eq!(stats.messages_in, 7);
+        assert_eq!(stats.messages_out, 5);
+        assert_eq!(stats.blocks_processed, 0);
+        assert_eq!(stats.active_channels, 2);
+    }
+}
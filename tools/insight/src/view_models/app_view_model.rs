@@ -1,10 +1,12 @@
 use super::{
-    ChannelsViewModel, LedgerStatsViewModel, MessageStatsViewModel, MessageTableViewModel,
-    NodeRunnerViewModel, QueueGroupViewModel, TabBarViewModel,
+    ChannelTrafficViewModel, ChannelsViewModel, LedgerStatsViewModel, MessageStatsViewModel,
+    MessageTableViewModel, NodeRunnerViewModel, NodeStatsViewModel, QueueGroupViewModel,
+    TabBarViewModel,
 };
 use crate::{
-    channels::Channels, ledger_stats::LedgerStats, message_collection::MessageCollection,
-    message_recorder::MessageRecorder, node_runner::NodeRunner, nullable_runtime::NullableRuntime,
+    channel_traffic::ChannelTraffic, channels::Channels, ledger_stats::LedgerStats,
+    message_collection::MessageCollection, message_recorder::MessageRecorder,
+    node_runner::NodeRunner, node_stats::NodeStats, nullable_runtime::NullableRuntime,
     view_models::QueueViewModel,
 };
 use rsban_node::{
@@ -25,7 +27,9 @@ pub(crate) struct AppViewModel {
     pub message_table: MessageTableViewModel,
     pub tabs: TabBarViewModel,
     ledger_stats: LedgerStats,
+    node_stats: NodeStats,
     channels: Channels,
+    channel_traffic: ChannelTraffic,
     clock: Arc<SteadyClock>,
     last_update: Option<Timestamp>,
     pub aec_info: ActiveElectionsInfo,
@@ -46,8 +50,10 @@ impl AppViewModel {
             tabs: TabBarViewModel::new(),
             msg_recorder,
             channels: Channels::new(messages),
+            channel_traffic: ChannelTraffic::new(),
             clock,
             ledger_stats: LedgerStats::new(),
+            node_stats: NodeStats::new(),
             last_update: None,
             aec_info: Default::default(),
             confirming_set: Default::default(),
@@ -70,6 +76,8 @@ impl AppViewModel {
 
         if let Some(node) = self.node_runner.node() {
             self.ledger_stats.update(&node, now);
+            self.node_stats.update(&node, now);
+            self.channel_traffic.update(&node);
             let channels = node.network_info.read().unwrap().list_realtime_channels(0);
             let telemetries = node.telemetry.get_all_telemetries();
             let (peered_reps, min_rep_weight) = {
@@ -103,10 +111,18 @@ impl AppViewModel {
         LedgerStatsViewModel::new(&self.ledger_stats)
     }
 
+    pub(crate) fn node_stats(&self) -> NodeStatsViewModel {
+        NodeStatsViewModel::new(&self.node_stats)
+    }
+
     pub(crate) fn channels(&mut self) -> ChannelsViewModel {
         ChannelsViewModel::new(&mut self.channels)
     }
 
+    pub(crate) fn channel_traffic(&mut self) -> ChannelTrafficViewModel {
+        ChannelTrafficViewModel::new(&mut self.channel_traffic)
+    }
+
     pub(crate) fn queue_groups(&self) -> Vec<QueueGroupViewModel> {
         vec![
             QueueGroupViewModel {
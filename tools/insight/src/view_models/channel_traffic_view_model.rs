@@ -0,0 +1,84 @@
+use crate::channel_traffic::{ChannelTraffic, TrafficColumn};
+use num_format::{Locale, ToFormattedString};
+
+pub(crate) struct ChannelTrafficViewModel<'a>(&'a mut ChannelTraffic);
+
+impl<'a> ChannelTrafficViewModel<'a> {
+    pub(crate) fn new(traffic: &'a mut ChannelTraffic) -> Self {
+        Self(traffic)
+    }
+
+    pub(crate) fn heading(&self) -> String {
+        format!("Channel Traffic ({})", self.0.channels().len())
+    }
+
+    pub(crate) fn columns(&self) -> Vec<TrafficColumnViewModel> {
+        [
+            (TrafficColumn::PeerAddr, "Remote Addr"),
+            (TrafficColumn::Mode, "Mode"),
+            (TrafficColumn::ProtocolVersion, "Protocol"),
+            (TrafficColumn::BytesSent, "Sent"),
+            (TrafficColumn::BytesReceived, "Received"),
+            (TrafficColumn::LimiterDrops, "Limiter Drops"),
+        ]
+        .into_iter()
+        .map(|(column, label)| TrafficColumnViewModel {
+            column,
+            label: self.column_label(column, label),
+        })
+        .collect()
+    }
+
+    fn column_label(&self, column: TrafficColumn, label: &str) -> String {
+        if self.0.sort_column() != column {
+            label.to_string()
+        } else if self.0.descending() {
+            format!("{label} ⏷")
+        } else {
+            format!("{label} ⏶")
+        }
+    }
+
+    pub(crate) fn sort_by(&mut self, column: TrafficColumn) {
+        self.0.sort_by(column);
+    }
+
+    pub(crate) fn rows(&self) -> Vec<ChannelTrafficRowViewModel> {
+        self.0
+            .channels()
+            .iter()
+            .map(|c| ChannelTrafficRowViewModel {
+                peer_addr: c.peer_addr.to_string(),
+                mode: c.mode.as_str(),
+                protocol_version: c.protocol_version.to_string(),
+                bytes_sent: c.bytes_sent.to_formatted_string(&Locale::en),
+                bytes_received: c.bytes_received.to_formatted_string(&Locale::en),
+                limiter_drops: limiter_drops_text(c.limiter_drops, c.throttled),
+            })
+            .collect()
+    }
+}
+
+pub(crate) struct TrafficColumnViewModel {
+    pub column: TrafficColumn,
+    pub label: String,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub(crate) struct ChannelTrafficRowViewModel {
+    pub peer_addr: String,
+    pub mode: &'static str,
+    pub protocol_version: String,
+    pub bytes_sent: String,
+    pub bytes_received: String,
+    pub limiter_drops: String,
+}
+
+fn limiter_drops_text(drops: u64, throttled: bool) -> String {
+    let drops = drops.to_formatted_string(&Locale::en);
+    if throttled {
+        format!("{drops} (throttled)")
+    } else {
+        drops
+    }
+}
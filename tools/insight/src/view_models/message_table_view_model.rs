@@ -1,5 +1,8 @@
 use super::{MessageViewModel, PaletteColor};
-use crate::message_collection::{MessageCollection, RecordedMessage};
+use crate::{
+    message_collection::{MessageCollection, RecordedMessage},
+    message_diff::MessageDiff,
+};
 use rsban_core::{Account, BlockHash};
 use rsban_messages::{Message, MessageType};
 use rsban_network::ChannelDirection;
@@ -16,6 +19,7 @@ pub(crate) struct RowViewModel {
 pub(crate) struct MessageTableViewModel {
     selected: Option<MessageViewModel>,
     selected_index: Option<usize>,
+    compare_index: Option<usize>,
     messages: Arc<RwLock<MessageCollection>>,
     pub message_types: Vec<MessageTypeOptionViewModel>,
     pub hash_filter: String,
@@ -30,6 +34,7 @@ impl MessageTableViewModel {
             messages,
             selected: None,
             selected_index: None,
+            compare_index: None,
             message_types: Vec::new(),
             account_filter: String::new(),
             account_error: false,
@@ -73,6 +78,21 @@ impl MessageTableViewModel {
         self.selected_index = Some(index);
     }
 
+    /// Remembers the selected message, so that the next selected message
+    /// can be compared with it
+    pub(crate) fn set_compare_base(&mut self) {
+        self.compare_index = self.selected_index;
+    }
+
+    pub(crate) fn diff_with_compare_base(&self) -> Option<MessageDiff> {
+        let base = self.compare_index?;
+        let selected = self.selected_index?;
+        if base == selected {
+            return None;
+        }
+        self.messages.read().unwrap().diff(base, selected)
+    }
+
     pub(crate) fn update_type_filter(&self) {
         self.messages.write().unwrap().filter_message_types(
             self.message_types
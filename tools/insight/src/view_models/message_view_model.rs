@@ -1,4 +1,5 @@
 use crate::message_collection::RecordedMessage;
+use rsban_messages::Message;
 use rsban_network::ChannelDirection;
 
 #[derive(Clone)]
@@ -21,7 +22,10 @@ impl From<RecordedMessage> for MessageViewModel {
             },
             date: value.date.to_string(),
             message_type: format!("{:?}", value.message.message_type()),
-            message: format!("{:#?}", value.message),
+            message: match &value.message {
+                Message::Keepalive(keepalive) => format!("Keepalive peers:{keepalive}"),
+                message => format!("{:#?}", message),
+            },
         }
     }
 }
@@ -1,21 +1,25 @@
 mod app_view_model;
+mod channel_traffic_view_model;
 mod channels_view_model;
 mod ledger_stats_view_model;
 mod message_stats_view_model;
 mod message_table_view_model;
 mod message_view_model;
 mod node_runner_view_model;
+mod node_stats_view_model;
 mod palette;
 mod queue_group_view_model;
 mod tab_bar_view_model;
 
 pub(crate) use app_view_model::*;
+pub(crate) use channel_traffic_view_model::*;
 pub(crate) use channels_view_model::*;
 pub(crate) use ledger_stats_view_model::*;
 pub(crate) use message_stats_view_model::*;
 pub(crate) use message_table_view_model::*;
 pub(crate) use message_view_model::*;
 pub(crate) use node_runner_view_model::*;
+pub(crate) use node_stats_view_model::*;
 pub(crate) use palette::PaletteColor;
 pub(crate) use queue_group_view_model::*;
 pub(crate) use tab_bar_view_model::*;
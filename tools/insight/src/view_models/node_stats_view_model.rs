@@ -0,0 +1,90 @@
+use crate::node_stats::NodeStats;
+use num_format::{Locale, ToFormattedString};
+
+pub(crate) struct NodeStatsViewModel<'a>(&'a NodeStats);
+
+impl<'a> NodeStatsViewModel<'a> {
+    pub(crate) fn new(stats: &'a NodeStats) -> Self {
+        Self(stats)
+    }
+
+    pub(crate) fn rows(&self) -> Vec<StatRowViewModel> {
+        vec![
+            StatRowViewModel::new("Messages in", self.0.messages_in),
+            StatRowViewModel::new("Messages out", self.0.messages_out),
+            StatRowViewModel::new("Blocks processed", self.0.blocks_processed),
+            StatRowViewModel::new("Active channels", self.0.active_channels as u64),
+        ]
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub(crate) struct StatRowViewModel {
+    pub label: &'static str,
+    pub value: String,
+}
+
+impl StatRowViewModel {
+    fn new(label: &'static str, value: u64) -> Self {
+        Self {
+            label,
+            value: value.to_formatted_string(&Locale::en),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsban_node::stats::{CounterKey, DetailType, Direction, StatType};
+    use rsban_nullable_clock::Timestamp;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn map_snapshot_to_rows() {
+        let mut stats = NodeStats::new();
+        let snapshot = BTreeMap::from([
+            (
+                CounterKey::new(StatType::Message, DetailType::Publish, Direction::In),
+                1500,
+            ),
+            (
+                CounterKey::new(StatType::Message, DetailType::Publish, Direction::Out),
+                20,
+            ),
+            (
+                CounterKey::new(
+                    StatType::BlockprocessorResult,
+                    DetailType::Progress,
+                    Direction::In,
+                ),
+                7,
+            ),
+        ]);
+        stats.apply_snapshot(&snapshot, 3, Timestamp::new_test_instance());
+
+        let model = NodeStatsViewModel::new(&stats);
+
+        assert_eq!(
+            model.rows(),
+            vec![
+                StatRowViewModel {
+                    label: "Messages in",
+                    value: "1,500".to_string()
+                },
+                StatRowViewModel {
+                    label: "Messages out",
+                    value: "20".to_string()
+                },
+                StatRowViewModel {
+                    label: "Blocks processed",
+                    value: "7".to_string()
+                },
+                StatRowViewModel {
+                    label: "Active channels",
+                    value: "3".to_string()
+                },
+            ]
+        );
+    }
+}
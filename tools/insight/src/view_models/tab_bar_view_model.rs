@@ -2,6 +2,7 @@
 pub(crate) enum Tab {
     Messages,
     Peers,
+    Traffic,
     Queues,
 }
 
@@ -10,12 +11,13 @@ impl Tab {
         match self {
             Tab::Messages => "Messages",
             Tab::Peers => "Peers",
+            Tab::Traffic => "Traffic",
             Tab::Queues => "Queues",
         }
     }
 }
 
-pub static TAB_ORDER: [Tab; 3] = [Tab::Peers, Tab::Messages, Tab::Queues];
+pub static TAB_ORDER: [Tab; 4] = [Tab::Peers, Tab::Traffic, Tab::Messages, Tab::Queues];
 
 pub(crate) struct TabBarViewModel {
     pub selected: Tab,
@@ -1,8 +1,9 @@
 use super::{
-    queue_group_view::show_queue_group, show_peers, LedgerStatsView, MessageRecorderControlsView,
-    MessageStatsView, MessageTabView, NodeRunnerView, TabBarView,
+    queue_group_view::show_queue_group, show_channel_traffic, show_node_stats, show_peers,
+    LedgerStatsView, MessageRecorderControlsView, MessageStatsView, MessageTabView, NodeRunnerView,
+    TabBarView,
 };
-use crate::view_models::{AppViewModel, QueueGroupViewModel, Tab};
+use crate::view_models::{AppViewModel, NodeStatsViewModel, QueueGroupViewModel, Tab};
 use eframe::egui::{
     self, global_theme_preference_switch, warn_if_debug_build, CentralPanel, TopBottomPanel,
 };
@@ -60,8 +61,9 @@ impl eframe::App for AppView {
 
         match self.model.tabs.selected_tab() {
             Tab::Peers => show_peers(ctx, self.model.channels()),
+            Tab::Traffic => show_channel_traffic(ctx, self.model.channel_traffic()),
             Tab::Messages => MessageTabView::new(&mut self.model).show(ctx),
-            Tab::Queues => show_queues(ctx, self.model.queue_groups()),
+            Tab::Queues => show_queues(ctx, self.model.queue_groups(), self.model.node_stats()),
         }
 
         // Repaint to show the continuously increasing current block and message counters
@@ -69,11 +71,16 @@ impl eframe::App for AppView {
     }
 }
 
-fn show_queues(ctx: &egui::Context, groups: Vec<QueueGroupViewModel>) {
+fn show_queues(
+    ctx: &egui::Context,
+    groups: Vec<QueueGroupViewModel>,
+    node_stats: NodeStatsViewModel,
+) {
     CentralPanel::default().show(ctx, |ui| {
         for group in groups {
             show_queue_group(ui, group);
             ui.add_space(10.0);
         }
+        show_node_stats(ui, node_stats);
     });
 }
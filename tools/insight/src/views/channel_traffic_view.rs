@@ -0,0 +1,64 @@
+use crate::view_models::ChannelTrafficViewModel;
+use eframe::egui::{self, Align, CentralPanel, Label, Layout};
+use egui_extras::{Column, TableBuilder};
+
+pub(crate) fn show_channel_traffic(ctx: &egui::Context, mut model: ChannelTrafficViewModel) {
+    CentralPanel::default().show(ctx, |ui| {
+        ui.heading(model.heading());
+        let columns = model.columns();
+        let rows = model.rows();
+        let mut clicked = None;
+        TableBuilder::new(ui)
+            .striped(true)
+            .resizable(false)
+            .auto_shrink(false)
+            .cell_layout(Layout::left_to_right(Align::Center))
+            .column(Column::exact(300.0)) // addr
+            .column(Column::exact(100.0)) // mode
+            .column(Column::exact(80.0)) // protocol
+            .column(Column::exact(120.0)) // sent
+            .column(Column::exact(120.0)) // received
+            .column(Column::exact(150.0)) // limiter drops
+            .header(20.0, |mut header| {
+                for column in columns {
+                    header.col(|ui| {
+                        if ui.button(column.label).clicked() {
+                            clicked = Some(column.column);
+                        }
+                    });
+                }
+            })
+            .body(|body| {
+                body.rows(20.0, rows.len(), |mut row| {
+                    let row_model = &rows[row.index()];
+                    row.col(|ui| {
+                        ui.add(Label::new(&row_model.peer_addr).selectable(false));
+                    });
+                    row.col(|ui| {
+                        ui.add(Label::new(row_model.mode).selectable(false));
+                    });
+                    row.col(|ui| {
+                        ui.add(Label::new(&row_model.protocol_version).selectable(false));
+                    });
+                    row.col(|ui| {
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            ui.add(Label::new(&row_model.bytes_sent).selectable(false));
+                        });
+                    });
+                    row.col(|ui| {
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            ui.add(Label::new(&row_model.bytes_received).selectable(false));
+                        });
+                    });
+                    row.col(|ui| {
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            ui.add(Label::new(&row_model.limiter_drops).selectable(false));
+                        });
+                    });
+                });
+            });
+        if let Some(column) = clicked {
+            model.sort_by(column);
+        }
+    });
+}
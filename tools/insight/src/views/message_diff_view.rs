@@ -0,0 +1,30 @@
+use crate::message_diff::{DiffLine, MessageDiff};
+use eframe::egui::{Color32, RichText, ScrollArea, Ui};
+
+pub(crate) struct MessageDiffView<'a> {
+    model: &'a MessageDiff,
+}
+
+impl<'a> MessageDiffView<'a> {
+    pub(crate) fn new(model: &'a MessageDiff) -> Self {
+        Self { model }
+    }
+
+    pub(crate) fn view(&self, ui: &mut Ui) {
+        ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            if !self.model.has_changes() {
+                ui.label("Messages are identical");
+                ui.add_space(20.0);
+            }
+            for line in &self.model.lines {
+                let text = RichText::new(line.to_string()).monospace();
+                let text = match line {
+                    DiffLine::Unchanged(_) => text,
+                    DiffLine::Removed(_) => text.color(Color32::RED),
+                    DiffLine::Added(_) => text.color(Color32::GREEN),
+                };
+                ui.label(text);
+            }
+        });
+    }
+}
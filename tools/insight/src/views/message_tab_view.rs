@@ -1,4 +1,4 @@
-use super::{channels_view::ChannelsView, MessageTableView, MessageView};
+use super::{channels_view::ChannelsView, MessageDiffView, MessageTableView, MessageView};
 use crate::view_models::AppViewModel;
 use eframe::egui::{self, CentralPanel, SidePanel};
 
@@ -39,7 +39,13 @@ impl<'a> MessageTabView<'a> {
         CentralPanel::default().show(ctx, |ui| {
             ui.heading("Message details");
             if let Some(details) = self.model.message_table.selected_message() {
-                MessageView::new(&details).view(ui);
+                if ui.button("Compare with next selection").clicked() {
+                    self.model.message_table.set_compare_base();
+                }
+                match self.model.message_table.diff_with_compare_base() {
+                    Some(diff) => MessageDiffView::new(&diff).view(ui),
+                    None => MessageView::new(&details).view(ui),
+                }
             }
         });
     }
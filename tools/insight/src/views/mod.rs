@@ -1,24 +1,30 @@
 mod app_view;
 mod badge;
+mod channel_traffic_view;
 mod channels_view;
 mod ledger_stats_view;
+mod message_diff_view;
 mod message_recorder_controls_view;
 mod message_stats_view;
 mod message_tab_view;
 mod message_table_view;
 mod message_view;
 mod node_runner_view;
+mod node_stats_view;
 mod peers_view;
 mod queue_group_view;
 mod tab_bar_view;
 
 pub(crate) use app_view::*;
+pub(crate) use channel_traffic_view::*;
 pub(crate) use ledger_stats_view::*;
+pub(crate) use message_diff_view::*;
 pub(crate) use message_recorder_controls_view::*;
 pub(crate) use message_stats_view::*;
 pub(crate) use message_tab_view::*;
 pub(crate) use message_table_view::*;
 pub(crate) use message_view::*;
 pub(crate) use node_runner_view::*;
+pub(crate) use node_stats_view::*;
 pub(crate) use peers_view::*;
 pub(crate) use tab_bar_view::*;
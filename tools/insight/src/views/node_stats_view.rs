@@ -0,0 +1,27 @@
+use crate::view_models::NodeStatsViewModel;
+use eframe::egui::{Align, Layout, Ui};
+use egui_extras::{Column, TableBuilder};
+
+pub(crate) fn show_node_stats(ui: &mut Ui, model: NodeStatsViewModel) {
+    ui.heading("Node Stats");
+    TableBuilder::new(ui)
+        .striped(true)
+        .resizable(false)
+        .cell_layout(Layout::left_to_right(Align::Center))
+        .column(Column::exact(150.0))
+        .column(Column::exact(120.0))
+        .body(|mut body| {
+            for row in model.rows() {
+                body.row(20.0, |mut table_row| {
+                    table_row.col(|ui| {
+                        ui.label(row.label);
+                    });
+                    table_row.col(|ui| {
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            ui.label(row.value);
+                        });
+                    });
+                });
+            }
+        });
+}
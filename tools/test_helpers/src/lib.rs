@@ -156,8 +156,11 @@ impl System {
         node
     }
 
-    fn new_node(&self, config: NodeConfig, flags: NodeFlags) -> Arc<Node> {
+    fn new_node(&self, mut config: NodeConfig, flags: NodeFlags) -> Arc<Node> {
         let path = unique_path().expect("Could not get a unique path");
+        if config.stat_config.instance_label.is_none() {
+            config.stat_config.instance_label = Some(format!("node{}", self.nodes.len()));
+        }
         let node = NodeBuilder::new(self.network_params.network.current_network)
             .runtime(self.runtime.tokio.handle().clone())
             .data_path(path)
@@ -30,6 +30,8 @@ pub enum Topic {
     Telemetry,
     /// New block arrival message
     NewUnconfirmedBlock,
+    /// Periodic stat counter deltas
+    Stats,
     /// Auxiliary length, not a valid topic, must be the last enum
     Length,
 }
@@ -61,6 +63,8 @@ pub struct OutgoingMessageEnvelope {
     pub time: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 impl OutgoingMessageEnvelope {
@@ -72,6 +76,7 @@ impl OutgoingMessageEnvelope {
             hash: None,
             time: milliseconds_since_epoch().to_string(),
             message: Some(serde_json::to_value(message).expect("could not serialize message")),
+            error: None,
         }
     }
 
@@ -83,6 +88,20 @@ impl OutgoingMessageEnvelope {
             hash: None,
             time: milliseconds_since_epoch().to_string(),
             message: None,
+            error: None,
+        }
+    }
+
+    /// Reply to a client request that could not be processed
+    pub fn new_error(id: Option<String>, error: String) -> Self {
+        Self {
+            id,
+            topic: None,
+            ack: None,
+            hash: None,
+            time: milliseconds_since_epoch().to_string(),
+            message: None,
+            error: Some(error),
         }
     }
 }
@@ -179,6 +198,7 @@ pub fn to_topic(topic: impl AsRef<str>) -> Topic {
         "bootstrap" => Topic::Bootstrap,
         "telemetry" => Topic::Telemetry,
         "new_unconfirmed_block" => Topic::NewUnconfirmedBlock,
+        "stats" => Topic::Stats,
         _ => Topic::Invalid,
     }
 }
@@ -1,3 +1,4 @@
+use crate::RecentConfirmations;
 use rsban_core::Account;
 use rsban_node::wallets::Wallets;
 use serde::Deserialize;
@@ -15,6 +16,8 @@ pub struct ConfirmationOptions {
     pub all_local_accounts: bool,
     pub confirmation_types: u8,
     pub accounts: HashSet<String>,
+    /// Number of recent confirmations to replay on subscribe
+    pub include_recent: usize,
     wallets: Arc<Wallets>,
 }
 
@@ -27,6 +30,7 @@ pub struct ConfirmationJsonOptions {
     pub confirmation_type: Option<String>,
     pub all_local_accounts: Option<bool>,
     pub accounts: Option<Vec<String>>,
+    pub include_recent: Option<usize>,
 }
 
 impl ConfirmationOptions {
@@ -46,6 +50,7 @@ impl ConfirmationOptions {
             all_local_accounts: false,
             confirmation_types: Self::TYPE_ALL,
             accounts: HashSet::new(),
+            include_recent: 0,
             wallets,
         };
         // Non-account filtering options
@@ -54,6 +59,10 @@ impl ConfirmationOptions {
         result.include_election_info_with_votes =
             options_a.include_election_info_with_votes.unwrap_or(false);
         result.include_sideband_info = options_a.include_sideband_info.unwrap_or(false);
+        result.include_recent = options_a
+            .include_recent
+            .unwrap_or(0)
+            .min(RecentConfirmations::MAX_SIZE);
 
         let type_l = options_a
             .confirmation_type
@@ -1,6 +1,7 @@
 mod confirmation_options;
 mod listener;
 mod options;
+mod recent_confirmations;
 mod vote_options;
 mod websocket_server;
 mod websocket_session;
@@ -8,6 +9,7 @@ mod websocket_session;
 pub use confirmation_options::*;
 pub use listener::*;
 pub use options::*;
+pub use recent_confirmations::*;
 pub use vote_options::*;
 pub use websocket_server::*;
 pub use websocket_session::*;
@@ -1,35 +1,53 @@
-use super::{ConfirmationJsonOptions, ConfirmationOptions, Options, WebsocketSessionEntry};
+use super::{
+    ConfirmationJsonOptions, ConfirmationOptions, Options, QueuedMessage, RecentConfirmation,
+    RecentConfirmations, WebsocketSessionEntry,
+};
 use crate::WebsocketSession;
 use rsban_core::{Account, Amount, BlockSideband, MaybeSavedBlock, VoteWithWeightInfo};
-use rsban_node::{consensus::ElectionStatus, wallets::Wallets};
+use rsban_node::{consensus::ElectionStatus, stats::Stats, wallets::Wallets};
 use rsban_websocket_messages::{OutgoingMessageEnvelope, Topic};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
 use std::{
     borrow::Cow,
-    net::SocketAddr,
+    collections::HashMap,
+    net::{Ipv4Addr, SocketAddr},
     sync::{
         atomic::{AtomicUsize, Ordering},
         Arc, Condvar, Mutex, Weak,
     },
-    time::UNIX_EPOCH,
+    time::{Duration, UNIX_EPOCH},
 };
+#[cfg(unix)]
+use tokio::net::UnixListener;
 use tokio::{
-    net::{TcpListener, TcpStream},
+    io::{AsyncRead, AsyncWrite},
+    net::TcpListener,
     sync::{mpsc, oneshot},
 };
-use tokio_tungstenite::tungstenite::protocol::{frame::coding::CloseCode, CloseFrame};
+use tokio_tungstenite::tungstenite::protocol::{
+    frame::coding::CloseCode, CloseFrame, WebSocketConfig,
+};
 use tracing::{info, warn};
 
 pub struct WebsocketListener {
     endpoint: Mutex<SocketAddr>,
     tx_stop: Mutex<Option<oneshot::Sender<()>>>,
     wallets: Arc<Wallets>,
-    topic_subscriber_count: Arc<[AtomicUsize; 11]>,
+    topic_subscriber_count: Arc<[AtomicUsize; 12]>,
     sessions: Arc<Mutex<Vec<Weak<WebsocketSessionEntry>>>>,
     tokio: tokio::runtime::Handle,
     bound: Mutex<bool>,
     bound_condition: Condvar,
+    /// Queued messages of these topics are dropped if they couldn't be sent in time
+    message_ttls: Mutex<HashMap<Topic, Duration>>,
+    recent_confirmations: Arc<RecentConfirmations>,
+    max_message_size: AtomicUsize,
+    stats: Arc<Stats>,
+    #[cfg(unix)]
+    unix_path: Option<PathBuf>,
 }
 
 impl WebsocketListener {
@@ -43,6 +61,29 @@ impl WebsocketListener {
             tokio,
             bound: Mutex::new(false),
             bound_condition: Condvar::new(),
+            message_ttls: Mutex::new(HashMap::new()),
+            recent_confirmations: Arc::new(RecentConfirmations::new()),
+            max_message_size: AtomicUsize::new(WebsocketSession::DEFAULT_MAX_MESSAGE_SIZE),
+            stats: Arc::new(Stats::default()),
+            #[cfg(unix)]
+            unix_path: None,
+        }
+    }
+
+    /// Listen on a Unix domain socket instead of a TCP endpoint
+    #[cfg(unix)]
+    pub fn new_unix(
+        path: impl AsRef<Path>,
+        wallets: Arc<Wallets>,
+        tokio: tokio::runtime::Handle,
+    ) -> Self {
+        Self {
+            unix_path: Some(path.as_ref().to_owned()),
+            ..Self::new(
+                SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0),
+                wallets,
+                tokio,
+            )
         }
     }
 
@@ -54,12 +95,64 @@ impl WebsocketListener {
         self.topic_subscriber_count[topic as usize].load(Ordering::SeqCst)
     }
 
+    /// The subscribed topics of each connected session
+    pub fn subscriptions(&self) -> Vec<(SocketAddr, Vec<Topic>)> {
+        self.sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|s| s.upgrade())
+            .map(|s| (s.remote_endpoint(), s.topics()))
+            .collect()
+    }
+
+    /// Drop queued messages of the given topic which couldn't be sent within `ttl`,
+    /// for example because the client is too slow
+    pub fn set_message_ttl(&self, topic: Topic, ttl: Duration) {
+        self.message_ttls.lock().unwrap().insert(topic, ttl);
+    }
+
+    /// Number of confirmations that can be replayed to new subscribers
+    pub fn recent_confirmation_count(&self) -> usize {
+        self.recent_confirmations.len()
+    }
+
+    pub fn set_stats(&mut self, stats: Arc<Stats>) {
+        self.stats = stats;
+    }
+
+    /// Sessions get closed when a client sends a message larger than `size` bytes.
+    /// The message is rejected while it is being received, so it never gets buffered completely
+    pub fn set_max_message_size(&self, size: usize) {
+        self.max_message_size.store(size, Ordering::Relaxed);
+    }
+
+    fn websocket_config(&self) -> WebSocketConfig {
+        let max_message_size = self.max_message_size.load(Ordering::Relaxed);
+        WebSocketConfig {
+            max_message_size: Some(max_message_size),
+            max_frame_size: Some(max_message_size.min(WebsocketSession::DEFAULT_MAX_FRAME_SIZE)),
+            ..Default::default()
+        }
+    }
+
+    fn message_ttl(&self, topic: Option<Topic>) -> Option<Duration> {
+        let topic = topic?;
+        self.message_ttls.lock().unwrap().get(&topic).cloned()
+    }
+
     fn set_bound(&self) {
         *self.bound.lock().unwrap() = true;
         self.bound_condition.notify_one();
     }
 
     async fn run(&self) {
+        #[cfg(unix)]
+        if let Some(path) = &self.unix_path {
+            self.run_unix(path).await;
+            return;
+        }
+
         let endpoint = self.endpoint.lock().unwrap().clone();
         let listener = match TcpListener::bind(endpoint).await {
             Ok(s) => s,
@@ -83,6 +176,33 @@ impl WebsocketListener {
         }
     }
 
+    #[cfg(unix)]
+    async fn run_unix(&self, path: &Path) {
+        if let Err(e) = remove_stale_socket(path) {
+            self.set_bound();
+            warn!("Listen failed: {:?}", e);
+            return;
+        }
+        let listener = match UnixListener::bind(path) {
+            Ok(s) => s,
+            Err(e) => {
+                self.set_bound();
+                warn!("Listen failed: {:?}", e);
+                return;
+            }
+        };
+        self.set_bound();
+        info!("Websocket listener started on {}", path.display());
+
+        let (tx_stop, rx_stop) = oneshot::channel::<()>();
+        *self.tx_stop.lock().unwrap() = Some(tx_stop);
+
+        tokio::select! {
+            _ = rx_stop =>{},
+           _ = self.accept_unix(listener) =>{}
+        }
+    }
+
     /// Close all websocket sessions and stop listening for new connections
     pub async fn stop_async(&self) {
         let tx = self.tx_stop.lock().unwrap().take();
@@ -104,15 +224,18 @@ impl WebsocketListener {
 
     /// Broadcast \p message to all session subscribing to the message topic.
     pub fn broadcast(&self, message: &OutgoingMessageEnvelope) {
+        let ttl = self.message_ttl(message.topic);
         let sessions = self.sessions.lock().unwrap();
         for session in sessions.iter() {
             if let Some(session) = session.upgrade() {
-                let _ = session.blocking_write(message);
+                let _ = session.blocking_write(message, ttl);
             }
         }
     }
 
     /// Broadcast block confirmation. The content of the message depends on subscription options (such as "include_block")
+    /// The confirmation is remembered, so that it can be replayed to new subscribers.
+    /// Sessions which subscribe at the same time get it either replayed or broadcasted, but never both
     pub fn broadcast_confirmation(
         &self,
         block_a: &MaybeSavedBlock,
@@ -122,87 +245,164 @@ impl WebsocketListener {
         election_status_a: &ElectionStatus,
         election_votes_a: &Vec<VoteWithWeightInfo>,
     ) {
+        let confirmation = RecentConfirmation {
+            block: block_a.clone(),
+            account: *account_a,
+            amount: *amount_a,
+            subtype: subtype.to_string(),
+            status: election_status_a.clone(),
+        };
+        let subscribers = self
+            .recent_confirmations
+            .push_with(confirmation, || self.confirmation_subscribers());
+
+        if subscribers.is_empty() {
+            return;
+        }
+
+        let default_opts = ConfirmationOptions::new(
+            Arc::clone(&self.wallets),
+            ConfirmationJsonOptions::default(),
+        );
+
         let mut msg_with_block = None;
         let mut msg_without_block = None;
-        let sessions = self.sessions.lock().unwrap();
-        for session in sessions.iter() {
-            if let Some(session) = session.upgrade() {
-                let subs = session.subscriptions.lock().unwrap();
-                if let Some(options) = subs.get(&Topic::Confirmation) {
-                    let default_opts = ConfirmationOptions::new(
-                        Arc::clone(&self.wallets),
-                        ConfirmationJsonOptions::default(),
-                    );
-                    let conf_opts = if let Options::Confirmation(i) = options {
-                        i
-                    } else {
-                        &default_opts
-                    };
-
-                    let include_block = conf_opts.include_block;
-
-                    if include_block && msg_with_block.is_none() {
-                        msg_with_block = Some(block_confirmed_message(
-                            block_a,
-                            account_a,
-                            amount_a,
-                            subtype.to_string(),
-                            include_block,
-                            election_status_a,
-                            election_votes_a,
-                            conf_opts,
-                        ));
-                    } else if !include_block && msg_without_block.is_none() {
-                        msg_without_block = Some(block_confirmed_message(
-                            block_a,
-                            account_a,
-                            amount_a,
-                            subtype.to_string(),
-                            include_block,
-                            election_status_a,
-                            election_votes_a,
-                            conf_opts,
-                        ));
-                    }
-                    drop(subs);
-                    let _ = session.blocking_write(if include_block {
-                        msg_with_block.as_ref().unwrap()
-                    } else {
-                        msg_without_block.as_ref().unwrap()
-                    });
-                }
+        let ttl = self.message_ttl(Some(Topic::Confirmation));
+        for (session, options) in subscribers {
+            let conf_opts = if let Options::Confirmation(i) = &options {
+                i
+            } else {
+                &default_opts
+            };
+
+            let include_block = conf_opts.include_block;
+
+            if include_block && msg_with_block.is_none() {
+                msg_with_block = Some(block_confirmed_message(
+                    block_a,
+                    account_a,
+                    amount_a,
+                    subtype.to_string(),
+                    include_block,
+                    election_status_a,
+                    election_votes_a,
+                    conf_opts,
+                ));
+            } else if !include_block && msg_without_block.is_none() {
+                msg_without_block = Some(block_confirmed_message(
+                    block_a,
+                    account_a,
+                    amount_a,
+                    subtype.to_string(),
+                    include_block,
+                    election_status_a,
+                    election_votes_a,
+                    conf_opts,
+                ));
             }
+            let _ = session.blocking_write(
+                if include_block {
+                    msg_with_block.as_ref().unwrap()
+                } else {
+                    msg_without_block.as_ref().unwrap()
+                },
+                ttl,
+            );
+        }
+    }
+
+    /// Sessions which are subscribed to confirmations, with their subscription options
+    fn confirmation_subscribers(&self) -> Vec<(Arc<WebsocketSessionEntry>, Options)> {
+        if !self.any_subscriber(Topic::Confirmation) {
+            return Vec::new();
         }
+
+        self.sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|s| s.upgrade())
+            .filter_map(|session| {
+                let options = session
+                    .subscriptions
+                    .lock()
+                    .unwrap()
+                    .get(&Topic::Confirmation)
+                    .cloned()?;
+                Some((session, options))
+            })
+            .collect()
     }
 
     async fn accept(&self, listener: TcpListener) {
         loop {
             match listener.accept().await {
-                Ok((stream, remote_endpoint)) => {
-                    let wallets = Arc::clone(&self.wallets);
-                    let sub_count = Arc::clone(&self.topic_subscriber_count);
-                    let (tx_send, rx_send) = mpsc::channel::<OutgoingMessageEnvelope>(1024);
-                    let sessions = Arc::clone(&self.sessions);
-                    tokio::spawn(async move {
-                        if let Err(e) = accept_connection(
-                            stream,
-                            wallets,
-                            sub_count,
-                            remote_endpoint,
-                            tx_send,
-                            rx_send,
-                            sessions,
-                        )
-                        .await
-                        {
-                            warn!("listener failed: {:?}", e)
-                        }
-                    });
+                Ok((stream, remote_endpoint)) => self.spawn_session(stream, remote_endpoint),
+                Err(e) => warn!("Accept failed: {:?}", e),
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    async fn accept_unix(&self, listener: UnixListener) {
+        loop {
+            match listener.accept().await {
+                // Unix domain sockets have no remote IP endpoint
+                Ok((stream, _)) => {
+                    self.spawn_session(stream, SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0))
                 }
                 Err(e) => warn!("Accept failed: {:?}", e),
             }
         }
     }
+
+    fn spawn_session<S>(&self, stream: S, remote_endpoint: SocketAddr)
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let wallets = Arc::clone(&self.wallets);
+        let sub_count = Arc::clone(&self.topic_subscriber_count);
+        let (tx_send, rx_send) = mpsc::channel::<QueuedMessage>(1024);
+        let sessions = Arc::clone(&self.sessions);
+        let recent_confirmations = Arc::clone(&self.recent_confirmations);
+        let ws_config = self.websocket_config();
+        let stats = Arc::clone(&self.stats);
+        tokio::spawn(async move {
+            if let Err(e) = accept_connection(
+                stream,
+                wallets,
+                sub_count,
+                remote_endpoint,
+                tx_send,
+                rx_send,
+                sessions,
+                recent_confirmations,
+                ws_config,
+                stats,
+            )
+            .await
+            {
+                warn!("listener failed: {:?}", e)
+            }
+        });
+    }
+}
+
+/// Removes the socket file of a previous run. Anything else at `path` is left
+/// untouched and causes an error
+#[cfg(unix)]
+fn remove_stale_socket(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => std::fs::remove_file(path),
+        Ok(_) => Err(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            format!("{} exists and is not a socket", path.display()),
+        )),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
 }
 
 pub trait WebsocketListenerExt {
@@ -229,20 +429,31 @@ impl WebsocketListenerExt for Arc<WebsocketListener> {
     }
 }
 
-async fn accept_connection(
-    stream: TcpStream,
+async fn accept_connection<S>(
+    stream: S,
     wallets: Arc<Wallets>,
-    topic_subscriber_count: Arc<[AtomicUsize; 11]>,
+    topic_subscriber_count: Arc<[AtomicUsize; 12]>,
     remote_endpoint: SocketAddr,
-    tx_send: mpsc::Sender<OutgoingMessageEnvelope>,
-    mut rx_send: mpsc::Receiver<OutgoingMessageEnvelope>,
+    tx_send: mpsc::Sender<QueuedMessage>,
+    mut rx_send: mpsc::Receiver<QueuedMessage>,
     sessions: Arc<Mutex<Vec<Weak<WebsocketSessionEntry>>>>,
-) -> anyhow::Result<()> {
+    recent_confirmations: Arc<RecentConfirmations>,
+    ws_config: WebSocketConfig,
+    stats: Arc<Stats>,
+) -> anyhow::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
     // Create the session and initiate websocket handshake
-    let mut ws_stream = tokio_tungstenite::accept_async(stream).await?;
+    let mut ws_stream =
+        tokio_tungstenite::accept_async_with_config(stream, Some(ws_config)).await?;
 
     let (tx_close, rx_close) = oneshot::channel::<()>();
-    let entry = Arc::new(WebsocketSessionEntry::new(tx_send, tx_close));
+    let entry = Arc::new(WebsocketSessionEntry::new(
+        remote_endpoint,
+        tx_send,
+        tx_close,
+    ));
 
     {
         let mut sessions = sessions.lock().unwrap();
@@ -250,7 +461,14 @@ async fn accept_connection(
         sessions.push(Arc::downgrade(&entry));
     }
 
-    let session = WebsocketSession::new(wallets, topic_subscriber_count, remote_endpoint, entry);
+    let session = WebsocketSession::new(
+        wallets,
+        topic_subscriber_count,
+        remote_endpoint,
+        entry,
+        recent_confirmations,
+        stats,
+    );
 
     tokio::select! {
         _ = rx_close =>{
@@ -269,7 +487,7 @@ async fn accept_connection(
     Ok(())
 }
 
-fn block_confirmed_message(
+pub(crate) fn block_confirmed_message(
     block: &MaybeSavedBlock,
     account: &Account,
     amount: &Amount,
@@ -0,0 +1,124 @@
+use rsban_core::{Account, Amount, MaybeSavedBlock};
+use rsban_node::consensus::ElectionStatus;
+use std::{collections::VecDeque, sync::Mutex};
+
+/// A confirmed block as it was reported by the active elections. The message
+/// is only serialized when it gets replayed
+#[derive(Clone)]
+pub struct RecentConfirmation {
+    pub block: MaybeSavedBlock,
+    pub account: Account,
+    pub amount: Amount,
+    pub subtype: String,
+    pub status: ElectionStatus,
+}
+
+/// Ring buffer of the most recent confirmations. They get replayed to
+/// clients which subscribe with the "include_recent" option
+pub struct RecentConfirmations {
+    messages: Mutex<VecDeque<RecentConfirmation>>,
+}
+
+impl RecentConfirmations {
+    /// Upper limit for the number of replayed confirmations
+    pub const MAX_SIZE: usize = 64;
+
+    pub fn new() -> Self {
+        Self {
+            messages: Mutex::new(VecDeque::with_capacity(Self::MAX_SIZE)),
+        }
+    }
+
+    pub fn push(&self, message: RecentConfirmation) {
+        self.push_with(message, || {});
+    }
+
+    /// Pushes the message and calls `f` before the buffer gets unlocked,
+    /// so that no snapshot can be taken in between
+    pub fn push_with<R>(&self, message: RecentConfirmation, f: impl FnOnce() -> R) -> R {
+        let mut messages = self.messages.lock().unwrap();
+        if messages.len() >= Self::MAX_SIZE {
+            messages.pop_front();
+        }
+        messages.push_back(message);
+        f()
+    }
+
+    pub fn len(&self) -> usize {
+        self.messages.lock().unwrap().len()
+    }
+
+    /// Returns up to `count` of the most recent messages, oldest first
+    pub fn latest(&self, count: usize) -> Vec<RecentConfirmation> {
+        self.latest_with(count, || {})
+    }
+
+    /// Same as `latest`, but calls `f` before the buffer gets unlocked,
+    /// so that no message can be pushed in between
+    pub fn latest_with(&self, count: usize, f: impl FnOnce()) -> Vec<RecentConfirmation> {
+        let messages = self.messages.lock().unwrap();
+        let skip = messages.len().saturating_sub(count);
+        let result = messages.iter().skip(skip).cloned().collect();
+        f();
+        result
+    }
+}
+
+impl Default for RecentConfirmations {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsban_core::Block;
+
+    #[test]
+    fn empty() {
+        let recent = RecentConfirmations::new();
+        assert_eq!(recent.len(), 0);
+        assert!(recent.latest(10).is_empty());
+    }
+
+    #[test]
+    fn return_latest_oldest_first() {
+        let recent = RecentConfirmations::new();
+        for i in 0..5 {
+            recent.push(test_message(i));
+        }
+
+        assert_eq!(ids(recent.latest(2)), [3, 4]);
+        assert_eq!(ids(recent.latest(100)), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn drop_oldest_when_full() {
+        let recent = RecentConfirmations::new();
+        for i in 0..RecentConfirmations::MAX_SIZE + 1 {
+            recent.push(test_message(i));
+        }
+
+        assert_eq!(recent.len(), RecentConfirmations::MAX_SIZE);
+        assert_eq!(ids(recent.latest(1)), [RecentConfirmations::MAX_SIZE]);
+        assert_eq!(ids(recent.latest(usize::MAX))[0], 1);
+    }
+
+    fn test_message(i: usize) -> RecentConfirmation {
+        RecentConfirmation {
+            block: MaybeSavedBlock::Unsaved(Block::new_test_instance()),
+            account: Account::from(1),
+            amount: Amount::raw(i as u128),
+            subtype: String::new(),
+            status: ElectionStatus::default(),
+        }
+    }
+
+    fn ids(messages: Vec<RecentConfirmation>) -> Vec<usize> {
+        messages
+            .iter()
+            .map(|m| m.amount.number() as usize)
+            .collect()
+    }
+}
@@ -9,15 +9,17 @@ use rsban_node::{
     consensus::{
         ActiveElections, ElectionStatus, ElectionStatusType, ProcessLiveDispatcher, VoteProcessor,
     },
+    stats::{CounterKey, Stats},
     wallets::Wallets,
     Telemetry,
 };
 use rsban_websocket_messages::{new_block_arrived_message, OutgoingMessageEnvelope, Topic};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     net::{IpAddr, SocketAddr, SocketAddrV6},
-    sync::Arc,
-    time::UNIX_EPOCH,
+    sync::{Arc, Weak},
+    time::{Duration, UNIX_EPOCH},
 };
 use tracing::error;
 
@@ -30,6 +32,7 @@ pub fn create_websocket_server(
     vote_processor: &VoteProcessor,
     process_live_dispatcher: &ProcessLiveDispatcher,
     bootstrap_initiator: &BootstrapInitiator,
+    stats: Arc<Stats>,
 ) -> Option<Arc<WebsocketListener>> {
     if !config.enabled {
         return None;
@@ -40,8 +43,24 @@ pub fn create_websocket_server(
         return None;
     };
 
+    if config.stats_interval.is_zero() {
+        error!("websocket stats_interval must not be zero");
+        return None;
+    }
+
+    if config.max_message_size == 0 {
+        error!("websocket max_message_size must not be zero");
+        return None;
+    }
+
     let endpoint = SocketAddr::new(address, config.port);
-    let server = Arc::new(WebsocketListener::new(endpoint, wallets, tokio.clone()));
+    let mut server = WebsocketListener::new(endpoint, wallets, tokio.clone());
+    server.set_stats(Arc::clone(&stats));
+    server.set_max_message_size(config.max_message_size);
+    if !config.confirmation_ttl.is_zero() {
+        server.set_message_ttl(Topic::Confirmation, config.confirmation_ttl);
+    }
+    let server = Arc::new(server);
 
     let server_w = Arc::downgrade(&server);
     active_elections.on_election_ended(Box::new(
@@ -54,24 +73,22 @@ pub fn create_websocket_server(
             if let Some(server) = server_w.upgrade() {
                 debug_assert!(status.election_status_type != ElectionStatusType::Ongoing);
 
-                if server.any_subscriber(Topic::Confirmation) {
-                    let block = status.winner.as_ref().unwrap();
-                    let subtype = if is_state_send {
-                        "send"
-                    } else if block.block_type() == BlockType::State {
-                        if block.is_change() {
-                            "change"
-                        } else if is_state_epoch {
-                            "epoch"
-                        } else {
-                            "receive"
-                        }
+                let block = status.winner.as_ref().unwrap();
+                let subtype = if is_state_send {
+                    "send"
+                } else if block.block_type() == BlockType::State {
+                    if block.is_change() {
+                        "change"
+                    } else if is_state_epoch {
+                        "epoch"
                     } else {
-                        ""
-                    };
+                        "receive"
+                    }
+                } else {
+                    ""
+                };
 
-                    server.broadcast_confirmation(block, &account, &amount, subtype, status, votes);
-                }
+                server.broadcast_confirmation(block, &account, &amount, subtype, status, votes);
             }
         },
     ));
@@ -143,9 +160,66 @@ pub fn create_websocket_server(
         }
     }));
 
+    tokio.spawn(broadcast_stats(
+        Arc::downgrade(&server),
+        stats,
+        config.stats_interval,
+    ));
+
     Some(server)
 }
 
+/// Periodically pushes the counters which changed since the last tick to the
+/// subscribers of the stats topic. Runs until the listener is dropped
+async fn broadcast_stats(server: Weak<WebsocketListener>, stats: Arc<Stats>, interval: Duration) {
+    let mut mark = stats.mark();
+    loop {
+        tokio::time::sleep(interval).await;
+        let Some(server) = server.upgrade() else {
+            break;
+        };
+
+        let deltas = stats.since_and_mark(&mut mark);
+
+        if server.any_subscriber(Topic::Stats) && !deltas.is_empty() {
+            let message = stats_delta(&deltas);
+            // Broadcasting blocks on the session queues
+            let _ = tokio::task::spawn_blocking(move || server.broadcast(&message)).await;
+        }
+    }
+}
+
+fn stats_delta(deltas: &BTreeMap<CounterKey, u64>) -> OutgoingMessageEnvelope {
+    OutgoingMessageEnvelope::new(
+        Topic::Stats,
+        StatsDelta {
+            entries: deltas
+                .iter()
+                .map(|(key, value)| StatsDeltaEntry {
+                    stat_type: key.stat_type.as_str().to_owned(),
+                    detail: key.detail.as_str().to_owned(),
+                    dir: key.dir.as_str().to_owned(),
+                    value: value.to_string(),
+                })
+                .collect(),
+        },
+    )
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StatsDelta {
+    pub entries: Vec<StatsDeltaEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StatsDeltaEntry {
+    #[serde(rename = "type")]
+    pub stat_type: String,
+    pub detail: String,
+    pub dir: String,
+    pub value: String,
+}
+
 fn telemetry_received(data: &TelemetryData, endpoint: SocketAddrV6) -> OutgoingMessageEnvelope {
     OutgoingMessageEnvelope::new(
         Topic::Telemetry,
@@ -1,6 +1,13 @@
-use super::{ConfirmationJsonOptions, ConfirmationOptions, Options, VoteJsonOptions, VoteOptions};
+use super::{
+    ConfirmationJsonOptions, ConfirmationOptions, Options, RecentConfirmation, RecentConfirmations,
+    VoteJsonOptions, VoteOptions,
+};
+use crate::listener::block_confirmed_message;
 use futures_util::{SinkExt, StreamExt};
-use rsban_node::wallets::Wallets;
+use rsban_node::{
+    stats::{DetailType, Direction, StatType, Stats},
+    wallets::Wallets,
+};
 use rsban_websocket_messages::{to_topic, IncomingMessage, OutgoingMessageEnvelope, Topic};
 use std::{
     collections::HashMap,
@@ -9,43 +16,113 @@ use std::{
         atomic::{AtomicUsize, Ordering},
         Arc, Mutex,
     },
+    time::{Duration, Instant},
+};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    sync::{mpsc, oneshot},
 };
-use tokio::sync::{mpsc, oneshot};
 use tracing::{info, trace, warn};
 
+/// A message in the send queue of a session
+pub struct QueuedMessage {
+    pub envelope: OutgoingMessageEnvelope,
+    pub enqueued: Instant,
+    /// The message gets dropped if it wasn't sent within this time
+    pub ttl: Option<Duration>,
+}
+
+impl QueuedMessage {
+    pub fn new(envelope: OutgoingMessageEnvelope, ttl: Option<Duration>) -> Self {
+        Self {
+            envelope,
+            enqueued: Instant::now(),
+            ttl,
+        }
+    }
+
+    pub fn is_expired(&self, now: Instant) -> bool {
+        self.ttl
+            .is_some_and(|ttl| now.saturating_duration_since(self.enqueued) > ttl)
+    }
+}
+
 pub struct WebsocketSessionEntry {
     /// Map of subscriptions -> options registered by this session.
     pub subscriptions: Mutex<HashMap<Topic, Options>>,
-    send_queue_tx: mpsc::Sender<OutgoingMessageEnvelope>,
+    remote_endpoint: SocketAddr,
+    send_queue_tx: mpsc::Sender<QueuedMessage>,
     tx_close: Mutex<Option<oneshot::Sender<()>>>,
 }
 
 impl WebsocketSessionEntry {
     pub fn new(
-        send_queue_tx: mpsc::Sender<OutgoingMessageEnvelope>,
+        remote_endpoint: SocketAddr,
+        send_queue_tx: mpsc::Sender<QueuedMessage>,
         tx_close: oneshot::Sender<()>,
     ) -> Self {
         Self {
             subscriptions: Mutex::new(HashMap::new()),
+            remote_endpoint,
             send_queue_tx,
             tx_close: Mutex::new(Some(tx_close)),
         }
     }
 
-    pub fn blocking_write(&self, envelope: &OutgoingMessageEnvelope) -> anyhow::Result<()> {
+    pub fn blocking_write(
+        &self,
+        envelope: &OutgoingMessageEnvelope,
+        ttl: Option<Duration>,
+    ) -> anyhow::Result<()> {
         if !self.should_filter(&envelope) {
-            self.send_queue_tx.blocking_send(envelope.clone())?;
+            self.send_queue_tx
+                .blocking_send(QueuedMessage::new(envelope.clone(), ttl))?;
         }
         Ok(())
     }
 
     pub async fn write(&self, envelope: &OutgoingMessageEnvelope) -> anyhow::Result<()> {
         if !self.should_filter(&envelope) {
-            self.send_queue_tx.send(envelope.clone()).await?
+            self.send_queue_tx
+                .send(QueuedMessage::new(envelope.clone(), None))
+                .await?
         }
         Ok(())
     }
 
+    pub fn remote_endpoint(&self) -> SocketAddr {
+        self.remote_endpoint
+    }
+
+    /// Currently subscribed topics, in the order of their declaration
+    pub fn topics(&self) -> Vec<Topic> {
+        let mut topics: Vec<_> = self.subscriptions.lock().unwrap().keys().cloned().collect();
+        topics.sort_by_key(|t| *t as usize);
+        topics
+    }
+
+    /// Returns the next queued message that is not expired yet.
+    /// Expired messages are dropped
+    pub async fn recv_unexpired(
+        &self,
+        send_queue: &mut mpsc::Receiver<QueuedMessage>,
+        stats: &Stats,
+    ) -> Option<OutgoingMessageEnvelope> {
+        while let Some(queued) = send_queue.recv().await {
+            if queued.is_expired(Instant::now()) {
+                trace!(topic = ?queued.envelope.topic, "dropping expired websocket message");
+                stats.inc_dir(
+                    StatType::Websocket,
+                    DetailType::MessageExpired,
+                    Direction::Out,
+                );
+            } else {
+                return Some(queued.envelope);
+            }
+        }
+        None
+    }
+
     pub fn close(&self) {
         let close = self.tx_close.lock().unwrap().take();
         if let Some(close) = close {
@@ -54,7 +131,7 @@ impl WebsocketSessionEntry {
     }
 
     fn should_filter(&self, envelope: &OutgoingMessageEnvelope) -> bool {
-        if envelope.ack.is_some() {
+        if envelope.ack.is_some() || envelope.error.is_some() {
             return false;
         }
 
@@ -78,16 +155,24 @@ impl WebsocketSessionEntry {
 pub struct WebsocketSession {
     entry: Arc<WebsocketSessionEntry>,
     wallets: Arc<Wallets>,
-    topic_subscriber_count: Arc<[AtomicUsize; 11]>,
+    topic_subscriber_count: Arc<[AtomicUsize; 12]>,
     remote_endpoint: SocketAddr,
+    recent_confirmations: Arc<RecentConfirmations>,
+    stats: Arc<Stats>,
 }
 
 impl WebsocketSession {
+    /// Same as the defaults of tungstenite
+    pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 64 << 20;
+    pub const DEFAULT_MAX_FRAME_SIZE: usize = 16 << 20;
+
     pub fn new(
         wallets: Arc<Wallets>,
-        topic_subscriber_count: Arc<[AtomicUsize; 11]>,
+        topic_subscriber_count: Arc<[AtomicUsize; 12]>,
         remote_endpoint: SocketAddr,
         entry: Arc<WebsocketSessionEntry>,
+        recent_confirmations: Arc<RecentConfirmations>,
+        stats: Arc<Stats>,
     ) -> Self {
         trace!(remote = %remote_endpoint, "new websocket session created");
         Self {
@@ -95,22 +180,43 @@ impl WebsocketSession {
             wallets,
             topic_subscriber_count,
             remote_endpoint,
+            recent_confirmations,
+            stats,
         }
     }
 
-    pub async fn run(
+    pub async fn run<S>(
         self,
-        stream: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
-        send_queue: &mut mpsc::Receiver<OutgoingMessageEnvelope>,
-    ) -> anyhow::Result<()> {
+        stream: &mut tokio_tungstenite::WebSocketStream<S>,
+        send_queue: &mut mpsc::Receiver<QueuedMessage>,
+    ) -> anyhow::Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
         loop {
             tokio::select! {
                 Some(msg) = stream.next() =>{
-                    if !self.process(msg?).await {
+                    let msg = match msg {
+                        Ok(msg) => msg,
+                        Err(tokio_tungstenite::tungstenite::Error::Capacity(e)) => {
+                            warn!(
+                                remote = %self.remote_endpoint,
+                                "Closing websocket session because of oversized message: {}", e
+                            );
+                            self.stats.inc_dir(
+                                StatType::Websocket,
+                                DetailType::MessageSizeTooBig,
+                                Direction::In,
+                            );
+                            break;
+                        }
+                        Err(e) => return Err(e.into()),
+                    };
+                    if !self.process(msg).await {
                         break;
                     }
                 }
-                Some(msg) = send_queue.recv() =>{
+                Some(msg) = self.entry.recv_unexpired(send_queue, &self.stats) =>{
                     let message_text = serde_json::to_string_pretty(&msg).unwrap();
                     trace!(message = message_text, "sending websocket message");
                     // write queued messages
@@ -149,7 +255,11 @@ impl WebsocketSession {
                         text = msg_text,
                         "Could not deserialize JSON message: {:?}", e
                     );
-                    return false;
+                    // The client sent garbage, but the connection itself is fine
+                    return self
+                        .send_error(None, format!("Invalid message: {}", e))
+                        .await
+                        .is_ok();
                 }
             };
 
@@ -168,33 +278,43 @@ impl WebsocketSession {
         let mut action_succeeded = false;
         let mut ack = message.ack;
         let mut reply_action = message.action.unwrap_or("");
+        let mut replay = None;
         if message.action == Some("subscribe") && topic != Topic::Invalid {
-            let mut subs = self.entry.subscriptions.lock().unwrap();
-            let options = match topic {
-                Topic::Confirmation => {
-                    if let Some(options_value) = message.options {
-                        Options::Confirmation(ConfirmationOptions::new(
-                            Arc::clone(&self.wallets),
-                            serde_json::from_value::<ConfirmationJsonOptions>(options_value)?,
-                        ))
-                    } else {
-                        Options::Other
-                    }
+            let options = match self.parse_options(topic, message.options) {
+                Ok(options) => options,
+                Err(e) => {
+                    return self
+                        .send_error(
+                            message.id.map(|s| s.to_string()),
+                            format!("Invalid options: {}", e),
+                        )
+                        .await;
                 }
-                Topic::Vote => {
-                    if let Some(options_value) = message.options {
-                        Options::Vote(VoteOptions::new(serde_json::from_value::<VoteJsonOptions>(
-                            options_value,
-                        )?))
-                    } else {
-                        Options::Other
-                    }
+            };
+            let replay_options = match &options {
+                Options::Confirmation(confirmation_options)
+                    if confirmation_options.include_recent > 0 =>
+                {
+                    Some(confirmation_options.clone())
+                }
+                _ => None,
+            };
+            let subscribe = || {
+                let mut subs = self.entry.subscriptions.lock().unwrap();
+                let inserted = subs.insert(topic, options).is_none();
+                if inserted {
+                    self.topic_subscriber_count[topic as usize].fetch_add(1, Ordering::SeqCst);
                 }
-                _ => Options::Other,
             };
-            let inserted = subs.insert(topic, options).is_none();
-            if inserted {
-                self.topic_subscriber_count[topic as usize].fetch_add(1, Ordering::SeqCst);
+            if let Some(replay_options) = replay_options {
+                // Confirmations which happen concurrently are either in the snapshot
+                // or get broadcasted to the new subscription, but not both
+                let recent = self
+                    .recent_confirmations
+                    .latest_with(replay_options.include_recent, subscribe);
+                replay = Some((replay_options, recent));
+            } else {
+                subscribe();
             }
             action_succeeded = true;
         } else if message.action == Some("update") {
@@ -228,6 +348,76 @@ impl WebsocketSession {
                 ))
                 .await?;
         }
+        if let Some((options, recent)) = replay {
+            self.replay_recent_confirmations(&options, recent).await?;
+        }
+        Ok(())
+    }
+
+    fn parse_options(
+        &self,
+        topic: Topic,
+        options: Option<serde_json::Value>,
+    ) -> serde_json::Result<Options> {
+        let Some(options_value) = options else {
+            return Ok(Options::Other);
+        };
+        let options = match topic {
+            Topic::Confirmation => Options::Confirmation(ConfirmationOptions::new(
+                Arc::clone(&self.wallets),
+                serde_json::from_value::<ConfirmationJsonOptions>(options_value)?,
+            )),
+            Topic::Vote => {
+                let vote_options = serde_json::from_value::<VoteJsonOptions>(options_value)?;
+                Options::Vote(VoteOptions::new(vote_options))
+            }
+            _ => Options::Other,
+        };
+        Ok(options)
+    }
+
+    async fn send_error(&self, id: Option<String>, error: String) -> anyhow::Result<()> {
+        self.entry
+            .write(&OutgoingMessageEnvelope::new_error(id, error))
+            .await
+    }
+
+    /// Sends the most recent confirmations to a new subscriber. They contain the block
+    /// if requested, but no election or sideband info
+    async fn replay_recent_confirmations(
+        &self,
+        options: &ConfirmationOptions,
+        recent: Vec<RecentConfirmation>,
+    ) -> anyhow::Result<()> {
+        let default_opts = ConfirmationOptions::new(
+            Arc::clone(&self.wallets),
+            ConfirmationJsonOptions::default(),
+        );
+        for confirmation in recent {
+            let mut envelope = block_confirmed_message(
+                &confirmation.block,
+                &confirmation.account,
+                &confirmation.amount,
+                confirmation.subtype,
+                true,
+                &confirmation.status,
+                &[],
+                &default_opts,
+            );
+            // The account filter needs the block, so it is removed after filtering
+            if self.entry.should_filter(&envelope) {
+                continue;
+            }
+            if !options.include_block {
+                if let Some(serde_json::Value::Object(message)) = &mut envelope.message {
+                    message.remove("block");
+                }
+            }
+            self.entry
+                .send_queue_tx
+                .send(QueuedMessage::new(envelope, None))
+                .await?;
+        }
         Ok(())
     }
 }
@@ -241,3 +431,51 @@ impl Drop for WebsocketSession {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[tokio::test]
+    async fn drop_expired_message() {
+        let (tx_send, mut rx_send) = mpsc::channel(16);
+        let (tx_close, _rx_close) = oneshot::channel();
+        let entry = WebsocketSessionEntry::new(
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+            tx_send.clone(),
+            tx_close,
+        );
+
+        let expired = QueuedMessage {
+            envelope: OutgoingMessageEnvelope::new(Topic::Confirmation, "old"),
+            enqueued: Instant::now() - Duration::from_secs(10),
+            ttl: Some(Duration::from_secs(5)),
+        };
+        let fresh = QueuedMessage::new(
+            OutgoingMessageEnvelope::new(Topic::Confirmation, "new"),
+            Some(Duration::from_secs(5)),
+        );
+        tx_send.send(expired).await.unwrap();
+        tx_send.send(fresh).await.unwrap();
+
+        let stats = Stats::default();
+        let received = entry.recv_unexpired(&mut rx_send, &stats).await.unwrap();
+
+        assert_eq!(received.message, Some(serde_json::Value::from("new")));
+        assert_eq!(
+            stats.count(
+                StatType::Websocket,
+                DetailType::MessageExpired,
+                Direction::Out
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn message_without_ttl_never_expires() {
+        let message = QueuedMessage::new(OutgoingMessageEnvelope::new(Topic::Vote, "vote"), None);
+        assert!(!message.is_expired(Instant::now() + Duration::from_secs(3600)));
+    }
+}
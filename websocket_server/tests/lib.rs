@@ -9,12 +9,13 @@ use rsban_messages::{Message, Publish};
 use rsban_node::{
     bootstrap::{BootstrapInitiatorExt, BootstrapStarted},
     config::{NetworkConstants, NodeConfig, WebsocketConfig},
+    stats::{DetailType, Direction, StatType},
     Node,
 };
 use rsban_websocket_messages::{OutgoingMessageEnvelope, Topic};
 use rsban_websocket_server::{
-    create_websocket_server, vote_received, BlockConfirmed, TelemetryReceived, VoteReceived,
-    WebsocketListener, WebsocketListenerExt,
+    create_websocket_server, vote_received, BlockConfirmed, StatsDelta, TelemetryReceived,
+    VoteReceived, WebsocketListener, WebsocketListenerExt,
 };
 use std::{sync::Arc, time::Duration};
 use test_helpers::{assert_timely, get_available_port, make_fake_channel, System};
@@ -209,6 +210,89 @@ fn confirmation() {
     });
 }
 
+// Confirms a block before subscribing and expects it to be replayed on subscribe
+#[test]
+fn confirmation_include_recent() {
+    let mut system = System::new();
+    let (node1, websocket) = create_node_with_websocket(&mut system);
+    node1.insert_into_wallet(&DEV_GENESIS_KEY);
+
+    let mut lattice = UnsavedBlockLatticeBuilder::new();
+    let key = PrivateKey::new();
+    let send_amount = node1.online_reps.lock().unwrap().quorum_delta() + Amount::raw(1);
+    let send = lattice.genesis().send(&key, send_amount);
+    let hash = send.hash();
+    node1.process_active(send);
+    assert_timely(Duration::from_secs(5), || {
+        websocket.recent_confirmation_count() == 1
+    });
+
+    node1.runtime.block_on(async {
+        let mut ws_stream = connect_websocket(&node1).await;
+        ws_stream
+            .send(tungstenite::Message::Text(
+                r#"{"action": "subscribe", "topic": "confirmation", "ack": true, "options": {"include_recent": 10}}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        //await ack
+        ws_stream.next().await.unwrap().unwrap();
+
+        let Ok(response) = timeout(Duration::from_secs(5), ws_stream.next()).await else {
+            panic!("timeout");
+        };
+        let response = response.unwrap().unwrap();
+        let response_msg: OutgoingMessageEnvelope =
+            serde_json::from_str(response.to_text().unwrap()).unwrap();
+        assert_eq!(response_msg.topic, Some(Topic::Confirmation));
+        let message: BlockConfirmed =
+            serde_json::from_value(response_msg.message.unwrap()).unwrap();
+        assert_eq!(message.hash, hash.to_string());
+    });
+}
+
+// Replayed confirmations are filtered by account before the block gets removed
+#[test]
+fn confirmation_include_recent_with_account_filter_and_without_block() {
+    let mut system = System::new();
+    let (node1, websocket) = create_node_with_websocket(&mut system);
+    node1.insert_into_wallet(&DEV_GENESIS_KEY);
+
+    let mut lattice = UnsavedBlockLatticeBuilder::new();
+    let key = PrivateKey::new();
+    let send_amount = node1.online_reps.lock().unwrap().quorum_delta() + Amount::raw(1);
+    let send = lattice.genesis().send(&key, send_amount);
+    let hash = send.hash();
+    node1.process_active(send);
+    assert_timely(Duration::from_secs(5), || {
+        websocket.recent_confirmation_count() == 1
+    });
+
+    node1.runtime.block_on(async {
+        let mut ws_stream = connect_websocket(&node1).await;
+        ws_stream
+            .send(tungstenite::Message::Text(format!(
+                r#"{{"action": "subscribe", "topic": "confirmation", "ack": true, "options": {{"include_recent": 10, "include_block": false, "accounts": ["{}"]}} }}"#,
+                DEV_GENESIS_ACCOUNT.encode_account()
+            )))
+            .await
+            .unwrap();
+        //await ack
+        ws_stream.next().await.unwrap().unwrap();
+
+        let Ok(response) = timeout(Duration::from_secs(5), ws_stream.next()).await else {
+            panic!("timeout");
+        };
+        let response = response.unwrap().unwrap();
+        let response_msg: OutgoingMessageEnvelope =
+            serde_json::from_str(response.to_text().unwrap()).unwrap();
+        let message: BlockConfirmed =
+            serde_json::from_value(response_msg.message.unwrap()).unwrap();
+        assert_eq!(message.hash, hash.to_string());
+        assert!(message.block.is_none());
+    });
+}
+
 // Tests the filtering options of block confirmations
 #[test]
 fn confirmation_options() {
@@ -266,6 +350,8 @@ fn confirmation_options() {
 		// Make sure tally and time are non-zero.
         assert_ne!(election_info.tally, "0");
         assert_ne!(election_info.time, "0");
+        assert_eq!(election_info.voters, "1");
+        assert!(election_info.duration.parse::<u64>().is_ok());
         assert!(election_info.votes.is_none());
 
         ws_stream
@@ -629,6 +715,115 @@ fn ws_keepalive() {
     });
 }
 
+#[test]
+fn close_session_on_oversized_message() {
+    let mut system = System::new();
+    let (node1, websocket) = create_node_with_websocket(&mut system);
+    websocket.set_max_message_size(100);
+    node1.runtime.block_on(async {
+        let mut ws_stream = connect_websocket(&node1).await;
+        let oversized = format!(r#"{{"action": "ping", "id": "{}"}}"#, "x".repeat(200));
+        ws_stream
+            .send(tungstenite::Message::Text(oversized))
+            .await
+            .unwrap();
+
+        let Ok(response) = timeout(Duration::from_secs(5), ws_stream.next()).await else {
+            panic!("timeout");
+        };
+        // No pong, the connection just gets closed
+        assert!(!matches!(response, Some(Ok(tungstenite::Message::Text(_)))));
+    });
+    assert_timely(Duration::from_secs(5), || {
+        node1.stats.count(
+            StatType::Websocket,
+            DetailType::MessageSizeTooBig,
+            Direction::In,
+        ) == 1
+    });
+}
+
+// A client that doesn't read its messages should not get stale confirmations later
+#[test]
+fn skip_expired_confirmations_for_stalled_client() {
+    const CONFIRMATION_COUNT: usize = 256;
+    let mut system = System::new();
+    let (node1, websocket) = create_node_with_websocket(&mut system);
+    websocket.set_message_ttl(Topic::Confirmation, Duration::from_millis(100));
+    let mut ws_stream = node1.runtime.block_on(async {
+        let mut ws_stream = connect_websocket(&node1).await;
+        ws_stream
+            .send(tungstenite::Message::Text(
+                r#"{"action": "subscribe", "topic": "confirmation", "ack": true}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        //await ack
+        ws_stream.next().await.unwrap().unwrap();
+        ws_stream
+    });
+
+    // The socket buffers fill up while the client is stalled, so the
+    // remaining confirmations expire in the send queue of the session
+    let payload = "x".repeat(128 * 1024);
+    for _ in 0..CONFIRMATION_COUNT {
+        websocket.broadcast(&OutgoingMessageEnvelope::new(Topic::Confirmation, &payload));
+    }
+    std::thread::sleep(Duration::from_secs(1));
+
+    let received = node1.runtime.block_on(async {
+        let mut received = 0;
+        while let Ok(Some(Ok(_))) = timeout(Duration::from_secs(1), ws_stream.next()).await {
+            received += 1;
+        }
+        received
+    });
+
+    let expired = node1.stats.count(
+        StatType::Websocket,
+        DetailType::MessageExpired,
+        Direction::Out,
+    ) as usize;
+    assert!(expired > 0);
+    assert_eq!(received + expired, CONFIRMATION_COUNT);
+}
+
+#[test]
+fn error_ack_for_malformed_options() {
+    let mut system = System::new();
+    let (node1, _websocket) = create_node_with_websocket(&mut system);
+    node1.runtime.block_on(async {
+        let mut ws_stream = connect_websocket(&node1).await;
+        ws_stream
+            .send(tungstenite::Message::Text(
+                r#"{"action": "subscribe", "topic": "confirmation", "ack": true, "id": "123", "options": {"include_block": []}}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let tungstenite::Message::Text(response) = ws_stream.next().await.unwrap().unwrap() else {
+            panic!("not a text message");
+        };
+        let response_json: OutgoingMessageEnvelope = serde_json::from_str(&response).unwrap();
+        assert_eq!(response_json.id, Some("123".to_string()));
+        assert!(response_json.error.is_some());
+        assert_eq!(response_json.ack, None);
+
+        // The session is still open
+        ws_stream
+            .send(tungstenite::Message::Text(
+                r#"{"action": "ping", "id": "456"}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        let tungstenite::Message::Text(response) = ws_stream.next().await.unwrap().unwrap() else {
+            panic!("not a text message");
+        };
+        let response_json: OutgoingMessageEnvelope = serde_json::from_str(&response).unwrap();
+        assert_eq!(response_json.ack, Some("pong".to_string()));
+    });
+}
+
 #[test]
 // Tests sending telemetry
 fn telemetry() {
@@ -708,12 +903,192 @@ fn new_unconfirmed_block() {
     });
 }
 
+/// Subscribers of new_unconfirmed_block get the arrival of a block but not its confirmation
+#[test]
+fn new_unconfirmed_block_without_confirmation() {
+    let mut system = System::new();
+    let (node1, websocket) = create_node_with_websocket(&mut system);
+    node1.runtime.block_on(async {
+        let mut ws_stream = connect_websocket(&node1).await;
+        ws_stream
+            .send(tungstenite::Message::Text(
+                r#"{"action": "subscribe", "topic": "new_unconfirmed_block", "ack": true}"#
+                    .to_string(),
+            ))
+            .await
+            .unwrap();
+        //await ack
+        ws_stream.next().await.unwrap().unwrap();
+        assert_eq!(websocket.subscriber_count(Topic::NewUnconfirmedBlock), 1);
+        assert_eq!(websocket.subscriber_count(Topic::Confirmation), 0);
+
+        node1.insert_into_wallet(&DEV_GENESIS_KEY);
+        let mut lattice = UnsavedBlockLatticeBuilder::new();
+        let key = PrivateKey::new();
+        let send_amount = node1.online_reps.lock().unwrap().quorum_delta() + Amount::raw(1);
+        let send = lattice.genesis().send(&key, send_amount);
+        node1.process_active(send.clone());
+
+        let tungstenite::Message::Text(response) = ws_stream.next().await.unwrap().unwrap() else {
+            panic!("not a text message");
+        };
+        let response_json: OutgoingMessageEnvelope = serde_json::from_str(&response).unwrap();
+        assert_eq!(response_json.topic, Some(Topic::NewUnconfirmedBlock));
+
+        assert_timely(Duration::from_secs(5), || {
+            node1.block_confirmed(&send.hash())
+        });
+        timeout(Duration::from_secs(1), ws_stream.next())
+            .await
+            .unwrap_err();
+    });
+}
+
+#[test]
+fn stats() {
+    let mut system = System::new();
+    let (node1, _websocket) = create_node_with_websocket(&mut system);
+    node1.runtime.block_on(async {
+        let mut ws_stream = connect_websocket(&node1).await;
+        ws_stream
+            .send(tungstenite::Message::Text(
+                r#"{"action": "subscribe", "topic": "stats", "ack": true}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        //await ack
+        ws_stream.next().await.unwrap().unwrap();
+
+        node1.stats.inc(StatType::Ledger, DetailType::Send);
+
+        let response = timeout(Duration::from_secs(5), ws_stream.next())
+            .await
+            .expect("no stats message received")
+            .unwrap()
+            .unwrap();
+        let response_json: OutgoingMessageEnvelope =
+            serde_json::from_str(response.to_text().unwrap()).unwrap();
+        assert_eq!(response_json.topic, Some(Topic::Stats));
+
+        let delta: StatsDelta = serde_json::from_value(response_json.message.unwrap()).unwrap();
+        assert!(!delta.entries.is_empty());
+    });
+}
+
+#[test]
+fn list_subscriptions_per_session() {
+    let mut system = System::new();
+    let (node1, websocket) = create_node_with_websocket(&mut system);
+
+    node1.runtime.block_on(async {
+        let mut ws_confirmation = connect_websocket(&node1).await;
+        ws_confirmation
+            .send(tungstenite::Message::Text(
+                r#"{"action": "subscribe", "topic": "confirmation", "ack": true}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        ws_confirmation.next().await.unwrap().unwrap();
+
+        let mut ws_vote = connect_websocket(&node1).await;
+        for topic in ["vote", "telemetry"] {
+            ws_vote
+                .send(tungstenite::Message::Text(format!(
+                    r#"{{"action": "subscribe", "topic": "{}", "ack": true}}"#,
+                    topic
+                )))
+                .await
+                .unwrap();
+            ws_vote.next().await.unwrap().unwrap();
+        }
+
+        let local_port = |ws: &WebSocketStream<MaybeTlsStream<TcpStream>>| match ws.get_ref() {
+            MaybeTlsStream::Plain(s) => s.local_addr().unwrap().port(),
+            _ => unreachable!(),
+        };
+
+        let mut subscriptions: Vec<_> = websocket
+            .subscriptions()
+            .into_iter()
+            .map(|(endpoint, topics)| (endpoint.port(), topics))
+            .collect();
+        subscriptions.sort_by_key(|(_, topics)| topics.len());
+
+        assert_eq!(
+            subscriptions,
+            [
+                (local_port(&ws_confirmation), vec![Topic::Confirmation]),
+                (local_port(&ws_vote), vec![Topic::Vote, Topic::Telemetry]),
+            ]
+        );
+    });
+}
+
+#[cfg(unix)]
+#[test]
+fn unix_domain_socket() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let path = std::env::temp_dir().join(format!("websocket_{}.sock", get_available_port()));
+    let websocket = Arc::new(WebsocketListener::new_unix(
+        &path,
+        node.wallets.clone(),
+        node.runtime.clone(),
+    ));
+    websocket.start();
+
+    node.runtime.block_on(async {
+        let stream = tokio::net::UnixStream::connect(&path).await.unwrap();
+        let (mut ws_stream, _) = tokio_tungstenite::client_async("ws://localhost/", stream)
+            .await
+            .expect("Failed to connect");
+        ws_stream
+            .send(tungstenite::Message::Text(
+                r#"{"action": "subscribe", "topic": "confirmation", "ack": true}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let Ok(response) = timeout(Duration::from_secs(5), ws_stream.next()).await else {
+            panic!("timeout");
+        };
+        let response = response.unwrap().unwrap();
+        let response_msg: OutgoingMessageEnvelope =
+            serde_json::from_str(response.to_text().unwrap()).unwrap();
+        assert_eq!(response_msg.ack.as_deref(), Some("subscribe"));
+    });
+
+    websocket.stop();
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(unix)]
+#[test]
+fn unix_domain_socket_does_not_replace_other_files() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let path = std::env::temp_dir().join(format!("websocket_{}.sock", get_available_port()));
+    std::fs::write(&path, "not a socket").unwrap();
+    let websocket = Arc::new(WebsocketListener::new_unix(
+        &path,
+        node.wallets.clone(),
+        node.runtime.clone(),
+    ));
+    websocket.start();
+
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "not a socket");
+
+    websocket.stop();
+    let _ = std::fs::remove_file(&path);
+}
+
 fn create_node_with_websocket(system: &mut System) -> (Arc<Node>, Arc<WebsocketListener>) {
     let websocket_port = get_available_port();
     let config = NodeConfig {
         websocket_config: WebsocketConfig {
             enabled: true,
             port: websocket_port,
+            stats_interval: Duration::from_millis(100),
             ..WebsocketConfig::new(&NetworkConstants::default_for(Networks::BananoDevNetwork))
         },
         ..System::default_config()
@@ -724,6 +1099,9 @@ fn create_node_with_websocket(system: &mut System) -> (Arc<Node>, Arc<WebsocketL
             enabled: node.config.websocket_config.enabled,
             port: node.config.websocket_config.port,
             address: node.config.websocket_config.address.clone(),
+            stats_interval: node.config.websocket_config.stats_interval,
+            confirmation_ttl: node.config.websocket_config.confirmation_ttl,
+            max_message_size: node.config.websocket_config.max_message_size,
         },
         node.wallets.clone(),
         node.runtime.clone(),
@@ -732,6 +1110,7 @@ fn create_node_with_websocket(system: &mut System) -> (Arc<Node>, Arc<WebsocketL
         &node.vote_processor,
         &node.process_live_dispatcher,
         &node.bootstrap_initiator,
+        node.stats.clone(),
     )
     .unwrap();
 